@@ -1,16 +1,76 @@
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::SeqCst};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use ciborium::Value as CborValue;
 use serde_json::{Value, json};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::live::LiveStream;
 use crate::{Engine, RecordId, RecordRange, Result, SessionState, SurrealError, Table};
 
+/// How long [`SurrealClient::shutdown`] waits for in-flight requests when the
+/// client has no request timeout.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Records fetched per query by [`SurrealClient::export_table_ndjson`].
+const EXPORT_PAGE_SIZE: u64 = 1000;
+
 pub struct SurrealClient {
     engine: Arc<tokio::sync::Mutex<Box<dyn Engine>>>,
-    session: SessionState,
+    session: Arc<RwLock<SessionState>>,
     incremental_id: Arc<std::sync::atomic::AtomicU64>,
     debug: bool,
+    request_timeout: Option<Duration>,
+    drain: Arc<Drain>,
+}
+
+/// Shutdown bookkeeping shared by every clone of a client: whether new
+/// requests are still accepted, and how many are currently running.
+#[derive(Default)]
+struct Drain {
+    closing: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: tokio::sync::Notify,
+}
+
+impl Drain {
+    /// Count a request as in flight, or refuse it once shutdown has begun.
+    fn begin(&self) -> Result<InFlight<'_>> {
+        // Count first so `shutdown` never sees zero while this request slips in.
+        self.in_flight.fetch_add(1, SeqCst);
+        let guard = InFlight(self);
+        if self.closing.load(SeqCst) {
+            return Err(SurrealError::Connection(
+                "client is shutting down".to_string(),
+            ));
+        }
+        Ok(guard)
+    }
+
+    /// Wait until no request is in flight.
+    async fn wait_idle(&self) {
+        loop {
+            // Created before the check, so a wakeup in between isn't lost.
+            let idle = self.idle.notified();
+            if self.in_flight.load(SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// Marks one request as in flight until dropped.
+struct InFlight<'a>(&'a Drain);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
 }
 
 impl Clone for SurrealClient {
@@ -21,6 +81,8 @@ impl Clone for SurrealClient {
             session: self.session.clone(),
             incremental_id: self.incremental_id.clone(),
             debug: self.debug,
+            request_timeout: self.request_timeout,
+            drain: self.drain.clone(),
         }
     }
 }
@@ -37,9 +99,11 @@ impl SurrealClient {
 
         Self {
             engine: Arc::new(tokio::sync::Mutex::new(engine)),
-            session,
+            session: Arc::new(RwLock::new(session)),
             incremental_id: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             debug: false,
+            request_timeout: None,
+            drain: Arc::new(Drain::default()),
         }
     }
 
@@ -54,53 +118,116 @@ impl SurrealClient {
         self.debug
     }
 
+    /// Fail any RPC that takes longer than `timeout` with
+    /// [`SurrealError::Timeout`]. The limit covers waiting for the shared
+    /// engine as well as the server's reply.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout applied to each RPC, if any
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Send one JSON RPC under the client's request timeout
+    async fn send(&self, method: &str, params: Value) -> Result<Value> {
+        self.send_within(method, params, self.request_timeout).await
+    }
+
+    async fn send_within(
+        &self,
+        method: &str,
+        params: Value,
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        let _in_flight = self.drain.begin()?;
+        within(method, timeout, async {
+            let mut engine = self.engine.lock().await;
+            engine.send_message(method, params).await
+        })
+        .await
+    }
+
+    async fn send_cbor_within(
+        &self,
+        method: &str,
+        params: CborValue,
+        timeout: Option<Duration>,
+    ) -> Result<CborValue> {
+        let _in_flight = self.drain.begin()?;
+        within(method, timeout, async {
+            let mut engine = self.engine.lock().await;
+            engine.send_message_cbor(method, params).await
+        })
+        .await
+    }
+
+    /// Namespace, database and variables this client and its clones work
+    /// with, as of now.
+    pub fn session(&self) -> SessionState {
+        self.session_mut(|session| session.clone())
+    }
+
+    fn session_mut<R>(&self, f: impl FnOnce(&mut SessionState) -> R) -> R {
+        // The state is plain data, so a panic elsewhere can't leave it torn
+        let mut session = self.session.write().unwrap_or_else(|e| e.into_inner());
+        f(&mut session)
+    }
+
+    /// Switch the connection to another namespace and database with a `use`
+    /// RPC, without reconnecting. The scope belongs to the connection, so
+    /// later queries from this client and its clones run against it, and
+    /// their [`session`](Self::session) reports it.
+    pub async fn use_ns_db(&self, namespace: &str, database: &str) -> Result<()> {
+        self.send("use", json!([namespace, database])).await?;
+
+        self.session_mut(|session| {
+            session.set_target(Some(namespace.to_string()), Some(database.to_string()))
+        });
+        Ok(())
+    }
+
     /// Set a parameter for the session
     pub async fn let_var(&mut self, key: &str, value: Value) -> Result<()> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([key, value]);
 
-        engine.send_message("let", params).await?;
+        self.send("let", params).await?;
 
         // Store the variable in the session
-        self.session.set_param(key.to_string(), value);
+        self.session_mut(|session| session.set_param(key.to_string(), value));
 
         Ok(())
     }
 
     /// Unset a parameter from the session
     pub async fn unset(&mut self, key: &str) -> Result<()> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([key]);
 
-        engine.send_message("unset", params).await?;
+        self.send("unset", params).await?;
 
         // Remove the variable from the session
-        self.session.unset_param(key);
+        self.session_mut(|session| session.unset_param(key));
         Ok(())
     }
 
     /// Create a record in the database
     pub async fn create(&self, resource: &str, data: Option<Value>) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(data) = data {
             json!([resource, data])
         } else {
             json!([resource])
         };
 
-        engine.send_message("create", params).await
+        self.send("create", params).await
     }
 
     /// Select records from the database
     pub async fn select(&self, resource: &str) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([resource]);
 
-        engine.send_message("select", params).await
+        self.send("select", params).await
     }
 
     /// Select all records from a table
@@ -120,15 +247,13 @@ impl SurrealClient {
 
     /// Update records in the database
     pub async fn update(&self, resource: &str, data: Option<Value>) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(data) = data {
             json!([resource, data])
         } else {
             json!([resource])
         };
 
-        engine.send_message("update", params).await
+        self.send("update", params).await
     }
 
     /// Update a specific record by ID
@@ -143,15 +268,13 @@ impl SurrealClient {
 
     /// Upsert (insert or update) records in the database
     pub async fn upsert(&self, resource: &str, data: Option<Value>) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(data) = data {
             json!([resource, data])
         } else {
             json!([resource])
         };
 
-        engine.send_message("upsert", params).await
+        self.send("upsert", params).await
     }
 
     /// Upsert a specific record by ID
@@ -161,11 +284,9 @@ impl SurrealClient {
 
     /// Merge data into records in the database
     pub async fn merge(&self, resource: &str, data: Value) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([resource, data]);
 
-        engine.send_message("merge", params).await
+        self.send("merge", params).await
     }
 
     /// Merge data into a specific record by ID
@@ -181,20 +302,16 @@ impl SurrealClient {
     /// Apply JSON patches to records
     /// Apply patches to records in the database
     pub async fn patch(&self, resource: &str, patches: Vec<Value>) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([resource, patches]);
 
-        engine.send_message("patch", params).await
+        self.send("patch", params).await
     }
 
     /// Delete records from the database
     pub async fn delete(&self, resource: &str) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([resource]);
 
-        engine.send_message("delete", params).await
+        self.send("delete", params).await
     }
 
     /// Delete a specific record by ID
@@ -210,11 +327,9 @@ impl SurrealClient {
     /// Insert records into the database
     /// Insert data into a table
     pub async fn insert(&self, table: &str, data: Value) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([table, data]);
 
-        engine.send_message("insert", params).await
+        self.send("insert", params).await
     }
 
     /// Insert multiple records
@@ -231,15 +346,13 @@ impl SurrealClient {
         to: &str,
         data: Option<Value>,
     ) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(data) = data {
             json!([from, relation, to, data])
         } else {
             json!([from, relation, to])
         };
 
-        engine.send_message("relate", params).await
+        self.send("relate", params).await
     }
 
     /// Create a relation between specific records
@@ -261,19 +374,72 @@ impl SurrealClient {
 
     /// Run a stored function
     pub async fn run(&self, func: &str, args: Option<Value>) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(args) = args {
             json!([func, args])
         } else {
             json!([func])
         };
 
-        engine.send_message("run", params).await
+        self.send("run", params).await
     }
 
     /// Execute a custom SurrealQL query
     pub async fn query(&self, sql: &str, variables: Option<Value>) -> Result<Value> {
+        self.query_within(sql, variables, self.request_timeout)
+            .await
+    }
+
+    /// Like [`query`](Self::query), also returning the server-reported
+    /// execution time and status of the statements, e.g. to log slow queries.
+    pub async fn query_with_meta(
+        &self,
+        sql: &str,
+        variables: Option<Value>,
+    ) -> Result<(Value, crate::QueryMeta)> {
+        let response = self.query(sql, variables).await?;
+        let meta = crate::QueryMeta::from_response(&response);
+        Ok((response, meta))
+    }
+
+    /// Run a `DEFINE` statement or a [`Migration`](crate::define::Migration)
+    /// of several. Fails with [`SurrealError::Query`] naming the first
+    /// statement the server rejected.
+    pub async fn define(&self, statement: &impl crate::define::DefineStatement) -> Result<()> {
+        let sql = statement.to_surql();
+        let response = self.query(&sql, None).await?;
+        let failed = response.as_array().and_then(|results| {
+            results
+                .iter()
+                .zip(sql.split(";\n"))
+                .find(|(result, _)| result.get("status").and_then(|s| s.as_str()) == Some("ERR"))
+        });
+        if let Some((result, statement)) = failed {
+            return Err(SurrealError::Query(format!(
+                "{} failed: {}",
+                statement,
+                result.get("result").unwrap_or(&Value::Null)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Execute a custom SurrealQL query, overriding the client's request
+    /// timeout for this call only
+    pub async fn query_with_timeout(
+        &self,
+        sql: &str,
+        variables: Option<Value>,
+        timeout: Duration,
+    ) -> Result<Value> {
+        self.query_within(sql, variables, Some(timeout)).await
+    }
+
+    async fn query_within(
+        &self,
+        sql: &str,
+        variables: Option<Value>,
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
         if self.debug {
             if let Some(ref vars) = variables {
                 println!("🔍 SQL: {}", sql);
@@ -286,15 +452,13 @@ impl SurrealClient {
             }
         }
 
-        let mut engine = self.engine.lock().await;
-
         let params = if let Some(vars) = variables {
             json!([sql, vars])
         } else {
             json!([sql])
         };
 
-        let response = engine.send_message("query", params).await?;
+        let response = self.send_within("query", params, timeout).await?;
 
         if self.debug {
             // Check if response contains status field to determine icon
@@ -330,20 +494,25 @@ impl SurrealClient {
 
     /// Get information about the current session
     pub async fn info(&self) -> Result<Value> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([]);
 
-        engine.send_message("info", params).await
+        self.send("info", params).await
+    }
+
+    /// Liveness check for readiness probes: round-trips a `ping` RPC and
+    /// returns the latency. Leaves the session untouched; errors if the
+    /// connection is dead or shut down.
+    pub async fn ping(&self) -> Result<Duration> {
+        let started = Instant::now();
+        self.send("ping", json!([])).await?;
+        Ok(started.elapsed())
     }
 
     /// Get the version of the SurrealDB instance
     pub async fn version(&self) -> Result<String> {
-        let mut engine = self.engine.lock().await;
-
         let params = json!([]);
 
-        let response = engine.send_message("version", params).await?;
+        let response = self.send("version", params).await?;
 
         match response {
             Value::String(version) => Ok(version),
@@ -353,12 +522,78 @@ impl SurrealClient {
         }
     }
 
-    /// Close the connection
+    /// Close the connection; same as [`shutdown`](Self::shutdown)
     pub async fn close(self) -> Result<()> {
-        // Note: engine is moved here since we're taking ownership
-        // The session will be dropped automatically
-        // Engine trait doesn't have close method in minimal implementation
-        Ok(())
+        self.shutdown().await
+    }
+
+    /// Shut the connection down without losing responses.
+    ///
+    /// Stops accepting new requests on this client and all its clones (they
+    /// fail with [`SurrealError::Connection`]), waits for requests already
+    /// issued to finish, then closes the engine — the WebSocket engine sends
+    /// a close frame. The wait is bounded by the request timeout, or 5
+    /// seconds if none is set.
+    pub async fn shutdown(self) -> Result<()> {
+        let timeout = self.request_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT);
+        self.shutdown_with_timeout(timeout).await
+    }
+
+    /// [`shutdown`](Self::shutdown) with an explicit limit on draining.
+    ///
+    /// If requests are still running when `timeout` expires, fails with
+    /// [`SurrealError::Timeout`] and leaves the connection to close when the
+    /// last client handle is dropped.
+    pub async fn shutdown_with_timeout(self, timeout: Duration) -> Result<()> {
+        self.drain.closing.store(true, SeqCst);
+
+        tokio::time::timeout(timeout, self.drain.wait_idle())
+            .await
+            .map_err(|_| {
+                SurrealError::Timeout(format!(
+                    "in-flight requests did not complete within {:?}",
+                    timeout
+                ))
+            })?;
+
+        let mut engine = self.engine.lock().await;
+        engine.close().await
+    }
+
+    /// Stream every record of `table` to `writer` as newline-delimited JSON,
+    /// one record per line, and return how many were written.
+    ///
+    /// Records are read in pages of 1000 using `ORDER BY id LIMIT … START …`,
+    /// so only one page is held in memory at a time. Records written to the
+    /// table during the export may be missed or repeated.
+    pub async fn export_table_ndjson(
+        &self,
+        table: &str,
+        mut writer: impl AsyncWrite + Unpin,
+    ) -> Result<u64> {
+        let mut count = 0;
+        loop {
+            let response = self
+                .query(
+                    "SELECT * FROM type::table($table) ORDER BY id LIMIT $limit START $start",
+                    Some(json!({"table": table, "limit": EXPORT_PAGE_SIZE, "start": count})),
+                )
+                .await?;
+            let records = export_page(response)?;
+            let fetched = records.len() as u64;
+
+            for record in records {
+                let mut line = serde_json::to_vec(&record)?;
+                line.push(b'\n');
+                writer.write_all(&line).await?;
+            }
+            count += fetched;
+            if fetched < EXPORT_PAGE_SIZE {
+                break;
+            }
+        }
+        writer.flush().await?;
+        Ok(count)
     }
 
     /// Import database content (HTTP only)
@@ -413,20 +648,24 @@ impl SurrealClient {
     /// with the stream's [`query_id`](LiveStream::query_id) to release the
     /// server-side query early.
     pub async fn live(&self, resource: &str) -> Result<LiveStream> {
-        let mut engine = self.engine.lock().await;
-
-        let params = CborValue::Array(vec![CborValue::Text(resource.to_string())]);
-        let response = engine.send_message_cbor("live", params).await?;
-
-        let query_id = cbor_uuid_string(&response).ok_or_else(|| {
-            SurrealError::Protocol(format!(
-                "live query did not return a usable id: {:?}",
-                response
-            ))
-        })?;
-
-        let rx = engine.register_live(&query_id).await?;
-        Ok(LiveStream { query_id, rx })
+        let _in_flight = self.drain.begin()?;
+        within("live", self.request_timeout, async {
+            let mut engine = self.engine.lock().await;
+
+            let params = CborValue::Array(vec![CborValue::Text(resource.to_string())]);
+            let response = engine.send_message_cbor("live", params).await?;
+
+            let query_id = cbor_uuid_string(&response).ok_or_else(|| {
+                SurrealError::Protocol(format!(
+                    "live query did not return a usable id: {:?}",
+                    response
+                ))
+            })?;
+
+            let rx = engine.register_live(&query_id).await?;
+            Ok(LiveStream { query_id, rx })
+        })
+        .await
     }
 
     /// Stop a live query by its id (the [`LiveStream::query_id`]).
@@ -434,24 +673,47 @@ impl SurrealClient {
     /// Sends the `kill` RPC and drops the local subscriber so no further
     /// notifications are delivered for that id.
     pub async fn kill(&self, query_id: &str) -> Result<()> {
-        let mut engine = self.engine.lock().await;
-        let params = CborValue::Array(vec![CborValue::Text(query_id.to_string())]);
-        engine.send_message_cbor("kill", params).await?;
-        engine.unregister_live(query_id).await;
-        Ok(())
+        let _in_flight = self.drain.begin()?;
+        within("kill", self.request_timeout, async {
+            let mut engine = self.engine.lock().await;
+            let params = CborValue::Array(vec![CborValue::Text(query_id.to_string())]);
+            engine.send_message_cbor("kill", params).await?;
+            engine.unregister_live(query_id).await;
+            Ok(())
+        })
+        .await
     }
 
     /// Execute a custom SurrealQL query with CBOR parameters
     pub async fn query_cbor(&self, sql: &str, variables: CborValue) -> Result<CborValue> {
-        let mut engine = self.engine.lock().await;
+        self.query_cbor_within(sql, variables, self.request_timeout)
+            .await
+    }
 
+    /// Execute a SurrealQL query with CBOR parameters, overriding the client's
+    /// request timeout for this call only
+    pub async fn query_cbor_with_timeout(
+        &self,
+        sql: &str,
+        variables: CborValue,
+        timeout: Duration,
+    ) -> Result<CborValue> {
+        self.query_cbor_within(sql, variables, Some(timeout)).await
+    }
+
+    async fn query_cbor_within(
+        &self,
+        sql: &str,
+        variables: CborValue,
+        timeout: Option<Duration>,
+    ) -> Result<CborValue> {
         if self.debug {
             println!("SQL: {}", sql);
             println!("Params: {:?}", variables);
         }
 
         let params = CborValue::Array(vec![CborValue::Text(sql.to_string()), variables]);
-        let response = engine.send_message_cbor("query", params).await?;
+        let response = self.send_cbor_within("query", params, timeout).await?;
 
         if self.debug {
             println!("✅ CBOR Response: {:?}", response);
@@ -461,6 +723,21 @@ impl SurrealClient {
     }
 }
 
+/// Run an RPC future, failing with [`SurrealError::Timeout`] if it doesn't
+/// finish within `timeout`.
+async fn within<T>(
+    method: &str,
+    timeout: Option<Duration>,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match timeout {
+        None => fut.await,
+        Some(limit) => tokio::time::timeout(limit, fut).await.map_err(|_| {
+            SurrealError::Timeout(format!("{} did not complete within {:?}", method, limit))
+        })?,
+    }
+}
+
 /// Render the `live` RPC result (a CBOR UUID) into the same string form the
 /// engine derives from notification frame ids, so a live subscription matches
 /// its notifications. SurrealDB encodes UUIDs as tag 37 over a 16-byte string;
@@ -479,6 +756,32 @@ fn cbor_uuid_string(v: &CborValue) -> Option<String> {
     }
 }
 
+/// Records from the single-statement query response of an export page.
+fn export_page(response: Value) -> Result<Vec<Value>> {
+    let statement = match response {
+        Value::Array(mut statements) if !statements.is_empty() => statements.swap_remove(0),
+        other => {
+            return Err(SurrealError::Protocol(format!(
+                "Unexpected export query response: {}",
+                other
+            )));
+        }
+    };
+    if statement.get("status").and_then(|s| s.as_str()) == Some("ERR") {
+        return Err(SurrealError::Protocol(format!(
+            "Export query failed: {}",
+            statement.get("result").unwrap_or(&Value::Null)
+        )));
+    }
+    match statement.get("result") {
+        Some(Value::Array(records)) => Ok(records.clone()),
+        _ => Err(SurrealError::Protocol(format!(
+            "Export query did not return records: {}",
+            statement
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -502,6 +805,119 @@ mod tests {
         }
     }
 
+    /// Mock engine that answers every RPC after a fixed delay
+    struct SlowEngine(Duration);
+
+    #[async_trait::async_trait]
+    impl Engine for SlowEngine {
+        async fn send_message_cbor(
+            &mut self,
+            _method: &str,
+            _params: CborValue,
+        ) -> Result<CborValue> {
+            tokio::time::sleep(self.0).await;
+            Ok(CborValue::Text("slow_response".to_string()))
+        }
+    }
+
+    /// Mock engine whose connection has gone away
+    struct DeadEngine;
+
+    #[async_trait::async_trait]
+    impl Engine for DeadEngine {
+        async fn send_message_cbor(
+            &mut self,
+            _method: &str,
+            _params: CborValue,
+        ) -> Result<CborValue> {
+            Err(SurrealError::Connection("connection reset".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ping_measures_round_trip() {
+        let client = SurrealClient::new(
+            Box::new(SlowEngine(Duration::from_millis(20))),
+            Some("ns".to_string()),
+            Some("db".to_string()),
+        );
+        let latency = client.ping().await.unwrap();
+        assert!(latency >= Duration::from_millis(20), "got {:?}", latency);
+        assert_eq!(client.session().namespace.as_deref(), Some("ns"));
+
+        let dead = SurrealClient::new(Box::new(DeadEngine), None, None);
+        let err = dead.ping().await.unwrap_err();
+        assert!(matches!(err, SurrealError::Connection(_)), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_exceeded() {
+        let client =
+            SurrealClient::new(Box::new(SlowEngine(Duration::from_millis(200))), None, None)
+                .with_request_timeout(Duration::from_millis(20));
+
+        let err = client.query("RETURN 1", None).await.unwrap_err();
+        assert!(matches!(err, SurrealError::Timeout(_)), "got {:?}", err);
+
+        let err = client.select("users").await.unwrap_err();
+        assert!(matches!(err, SurrealError::Timeout(_)), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_request_within_timeout() {
+        let client =
+            SurrealClient::new(Box::new(SlowEngine(Duration::from_millis(10))), None, None)
+                .with_request_timeout(Duration::from_secs(5));
+
+        let result = client.query("RETURN 1", None).await.unwrap();
+        assert_eq!(result, json!("slow_response"));
+    }
+
+    #[tokio::test]
+    async fn test_query_with_timeout_overrides() {
+        let client =
+            SurrealClient::new(Box::new(SlowEngine(Duration::from_millis(200))), None, None)
+                .with_request_timeout(Duration::from_secs(5));
+
+        let err = client
+            .query_with_timeout("RETURN 1", None, Duration::from_millis(20))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SurrealError::Timeout(_)), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_request() {
+        let client =
+            SurrealClient::new(Box::new(SlowEngine(Duration::from_millis(50))), None, None);
+
+        let issued = client.clone();
+        let request = tokio::spawn(async move { issued.query("RETURN 1", None).await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let late = client.clone();
+        client.shutdown().await.unwrap();
+        assert_eq!(request.await.unwrap().unwrap(), json!("slow_response"));
+
+        let err = late.query("RETURN 2", None).await.unwrap_err();
+        assert!(matches!(err, SurrealError::Connection(_)), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_times_out_on_stuck_request() {
+        let client = SurrealClient::new(Box::new(SlowEngine(Duration::from_secs(5))), None, None);
+
+        let issued = client.clone();
+        tokio::spawn(async move { issued.query("RETURN 1", None).await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let err = client
+            .shutdown_with_timeout(Duration::from_millis(20))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SurrealError::Timeout(_)), "got {:?}", err);
+    }
+
     #[tokio::test]
     async fn test_surrealdb_creation() {
         let engine = Box::new(MockEngine);
@@ -737,4 +1153,152 @@ mod tests {
         let result = client.query(performance_query, None).await.unwrap();
         assert_eq!(result, Value::String("mock_response".to_string()));
     }
+
+    /// Stands in for a server connection: keeps the scope set by `use` and
+    /// records each query with the scope it ran in.
+    struct ScopedEngine {
+        scope: Value,
+        queries: Arc<std::sync::Mutex<Vec<(String, Value)>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Engine for ScopedEngine {
+        async fn send_message_cbor(
+            &mut self,
+            method: &str,
+            params: CborValue,
+        ) -> Result<CborValue> {
+            let params = crate::cbor_convert::cbor_to_json(params);
+            match method {
+                "use" => self.scope = params,
+                "query" => self
+                    .queries
+                    .lock()
+                    .unwrap()
+                    .push((params[0].as_str().unwrap().to_string(), self.scope.clone())),
+                _ => {}
+            }
+            Ok(CborValue::Null)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_use_ns_db_switches_scope() {
+        let queries = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let engine = ScopedEngine {
+            scope: json!(["shop", "eu"]),
+            queries: queries.clone(),
+        };
+        let client = SurrealClient::new(
+            Box::new(engine),
+            Some("shop".to_string()),
+            Some("eu".to_string()),
+        );
+        let clone = client.clone();
+
+        client.query("SELECT * FROM orders", None).await.unwrap();
+        client.use_ns_db("shop", "us").await.unwrap();
+        client.query("SELECT * FROM orders", None).await.unwrap();
+        clone.query("SELECT * FROM stock", None).await.unwrap();
+
+        let us = (Some(&"shop".to_string()), Some(&"us".to_string()));
+        assert_eq!(client.session().get_target(), us);
+        assert_eq!(clone.session().get_target(), us);
+        assert_eq!(
+            *queries.lock().unwrap(),
+            vec![
+                ("SELECT * FROM orders".to_string(), json!(["shop", "eu"])),
+                ("SELECT * FROM orders".to_string(), json!(["shop", "us"])),
+                ("SELECT * FROM stock".to_string(), json!(["shop", "us"])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_define_runs_migration() {
+        use crate::define::{DefineField, DefineIndex, DefineTable, Migration};
+
+        let migration = Migration::new()
+            .define(DefineTable::new("user").schemafull())
+            .define(DefineField::new("email", "user").ty("string"))
+            .define(
+                DefineIndex::new("user_email", "user")
+                    .fields(["email"])
+                    .unique(),
+            );
+        let sql = "DEFINE TABLE user SCHEMAFULL;\nDEFINE FIELD email ON TABLE user TYPE string;\nDEFINE INDEX user_email ON TABLE user FIELDS email UNIQUE";
+        let ok = json!({"status": "OK", "result": null});
+
+        let client = crate::SurrealMockBuilder::new()
+            .with_exact_response("query", json!([sql]), json!([ok, ok, ok]))
+            .build();
+        client.define(&migration).await.unwrap();
+
+        let client = crate::SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([sql]),
+                json!([ok, ok, {"status": "ERR", "result": "index already exists"}]),
+            )
+            .build();
+        let err = client.define(&migration).await.unwrap_err().to_string();
+        assert!(
+            err.starts_with("Query error: DEFINE INDEX user_email"),
+            "{}",
+            err
+        );
+        assert!(err.ends_with("\"index already exists\""), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_query_with_meta() {
+        let client = crate::SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!(["SELECT * FROM product"]),
+                json!([{"status": "OK", "time": "12.5ms", "result": [{"name": "Scone"}]}]),
+            )
+            .build();
+
+        let (response, meta) = client
+            .query_with_meta("SELECT * FROM product", None)
+            .await
+            .unwrap();
+        assert_eq!(response[0]["result"][0]["name"], "Scone");
+        assert_eq!(meta.execution_time, Duration::from_micros(12_500));
+        assert!(meta.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_export_table_ndjson() {
+        let client = crate::SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "SELECT * FROM type::table($table) ORDER BY id LIMIT $limit START $start",
+                    {"table": "product", "limit": 1000, "start": 0}
+                ]),
+                json!([{"status": "OK", "result": [
+                    {"id": "product:1", "name": "Scone"},
+                    {"id": "product:2", "name": "Bagel"},
+                    {"id": "product:3", "name": "Rye"}
+                ]}]),
+            )
+            .build();
+
+        let mut out = Vec::new();
+        let count = client
+            .export_table_ndjson("product", &mut out)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+
+        let lines: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], json!({"id": "product:3", "name": "Rye"}));
+    }
 }
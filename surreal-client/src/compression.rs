@@ -0,0 +1,133 @@
+//! Compressed WebSocket payloads
+//!
+//! With [`SurrealConnection::with_compression`](crate::SurrealConnection::with_compression)
+//! the CBOR engine inflates binary frames that arrive gzip- or
+//! zlib-compressed before decoding them. Frames are recognised by their
+//! header (`1f 8b` for gzip, a zlib header for deflate), neither of which
+//! can start a CBOR RPC response, so uncompressed frames from the same
+//! server still decode as before.
+//!
+//! There is no handshake to negotiate this: tungstenite doesn't implement
+//! the `permessage-deflate` extension, so the setting only tells the client
+//! to accept what a compressing server or proxy sends.
+
+use std::borrow::Cow;
+use std::io::Read;
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+
+use crate::{Result, SurrealError};
+
+/// Largest inflated message accepted when no
+/// [`max_message_size`](crate::SurrealConnection::with_max_message_size) is
+/// set; matches tungstenite's default message limit.
+pub(crate) const DEFAULT_MAX_INFLATED: usize = 64 << 20;
+
+/// Compression of binary WebSocket frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Frames are plain CBOR
+    #[default]
+    None,
+    /// Frames may be gzip-compressed
+    Gzip,
+    /// Frames may be zlib-wrapped deflate
+    Deflate,
+}
+
+impl Compression {
+    /// Inflate `frame` if it is compressed in this format, refusing to grow
+    /// it beyond `max` bytes. Other frames are returned as they are.
+    pub fn inflate(self, frame: &[u8], max: usize) -> Result<Cow<'_, [u8]>> {
+        let format = match self {
+            Compression::Gzip if frame.starts_with(&[0x1f, 0x8b]) => "gzip",
+            Compression::Deflate if is_zlib_header(frame) => "deflate",
+            _ => return Ok(Cow::Borrowed(frame)),
+        };
+
+        let decoder: Box<dyn Read + '_> = match self {
+            Compression::Gzip => Box::new(GzDecoder::new(frame)),
+            _ => Box::new(ZlibDecoder::new(frame)),
+        };
+        let mut inflated = Vec::new();
+        decoder
+            .take(max as u64 + 1)
+            .read_to_end(&mut inflated)
+            .map_err(|e| {
+                SurrealError::Protocol(format!("{} decompression failed: {}", format, e))
+            })?;
+        if inflated.len() > max {
+            return Err(SurrealError::MessageTooLarge {
+                size: inflated.len(),
+                max,
+            });
+        }
+        Ok(Cow::Owned(inflated))
+    }
+}
+
+/// A zlib stream starts with a deflate CMF byte (method 8, window bits at
+/// most 7) and a 16-bit header that is a multiple of 31. CBOR maps and
+/// arrays start at `0x80`, so they never match.
+fn is_zlib_header(frame: &[u8]) -> bool {
+    match frame {
+        [cmf, flg, ..] => {
+            cmf & 0x0f == 8 && cmf >> 4 <= 7 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn compress(format: Compression, data: &[u8]) -> Vec<u8> {
+        let level = flate2::Compression::default();
+        match format {
+            Compression::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            }
+            _ => {
+                let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), level);
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            }
+        }
+    }
+
+    #[test]
+    fn test_inflate_formats_and_limit() {
+        let mut cbor = Vec::new();
+        let reply = ciborium::Value::Map(vec![(
+            ciborium::Value::Text("result".into()),
+            ciborium::Value::Text("x".repeat(500)),
+        )]);
+        ciborium::into_writer(&reply, &mut cbor).unwrap();
+
+        for format in [Compression::Gzip, Compression::Deflate] {
+            let compressed = compress(format, &cbor);
+            assert!(compressed.len() < cbor.len());
+            assert_eq!(format.inflate(&compressed, 1024).unwrap(), cbor.as_slice());
+            // Plain frames pass through
+            assert!(matches!(
+                format.inflate(&cbor, 1024).unwrap(),
+                Cow::Borrowed(_)
+            ));
+            assert!(matches!(
+                format.inflate(&compressed, 100),
+                Err(SurrealError::MessageTooLarge { max: 100, .. })
+            ));
+        }
+
+        // Without compression a gzip frame is left alone
+        let gzip = compress(Compression::Gzip, &cbor);
+        assert_eq!(
+            Compression::None.inflate(&gzip, 1024).unwrap(),
+            gzip.as_slice()
+        );
+    }
+}
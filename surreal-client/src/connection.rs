@@ -1,6 +1,9 @@
 //! Connection builder for SurrealDB with authentication and engine creation
 
-use crate::{DebugEngine, Engine, Result, SurrealClient, SurrealError, WsCborEngine};
+use crate::engines::offline::OfflineQueueEngine;
+use crate::{Compression, DebugEngine, Engine, Result, SurrealClient, SurrealError, WsCborEngine};
+
+use std::time::Duration;
 
 use serde_json::Value;
 use url::Url;
@@ -25,6 +28,18 @@ pub struct SurrealConnection {
 
     /// Whether to enable debug mode for query logging
     debug: bool,
+
+    /// Per-RPC timeout handed to the client
+    request_timeout: Option<Duration>,
+
+    /// Largest WebSocket message (and frame) the engine accepts
+    max_message_size: Option<usize>,
+
+    /// Compression the engine inflates incoming frames from
+    compression: Compression,
+
+    /// Capacity of the offline write queue, when enabled
+    offline_queue: Option<usize>,
 }
 
 /// Authentication parameters
@@ -195,6 +210,55 @@ impl SurrealConnection {
         self
     }
 
+    /// Fail any RPC (including the sign-in handshake) that takes longer than
+    /// `timeout` with [`SurrealError::Timeout`], instead of waiting on a hung
+    /// server indefinitely. Individual queries can override it with
+    /// [`SurrealClient::query_with_timeout`].
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Raise (or lower) the largest WebSocket message the client will
+    /// receive, e.g. for big exports. A response over the limit fails the
+    /// request with [`SurrealError::MessageTooLarge`]. Defaults to the
+    /// tungstenite limit of 64 MiB per message and 16 MiB per frame; this
+    /// setting applies to both.
+    pub fn with_max_message_size(mut self, bytes: usize) -> Self {
+        self.max_message_size = Some(bytes);
+        self
+    }
+
+    pub(crate) fn max_message_size(&self) -> Option<usize> {
+        self.max_message_size
+    }
+
+    /// Inflate gzip- or zlib-compressed frames from the server before
+    /// decoding them; uncompressed frames are still accepted. The inflated
+    /// size counts against [`Self::with_max_message_size`]. See
+    /// [`compression`](crate::compression) for how frames are recognised.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub(crate) fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Keep up to `capacity` writes queued while the connection is down,
+    /// instead of failing them, and replay them in order once a later call
+    /// reconnects. Reads still fail while disconnected. A queued write
+    /// returns `Null`. See [`OfflineQueueEngine`] for the caveats.
+    pub fn with_offline_queue(mut self, capacity: usize) -> Self {
+        self.offline_queue = Some(capacity);
+        self
+    }
+
     // /// Configure connection pool with custom settings
     // pub fn with_pool_config(mut self, config: PoolConfig) -> Self {
     //     self.pool_config = Some(config);
@@ -298,8 +362,8 @@ impl SurrealConnection {
         Ok(())
     }
 
-    /// Connect to SurrealDB and return an immutable client
-    pub async fn connect(self) -> Result<SurrealClient> {
+    /// Open a signed-in engine for the configured URL.
+    async fn open_engine(&self) -> Result<Box<dyn Engine>> {
         let url_str = self
             .url
             .as_ref()
@@ -308,7 +372,7 @@ impl SurrealConnection {
             .map_err(|e| SurrealError::Connection(format!("Invalid URL: {}", e)))?;
 
         let mut engine: Box<dyn Engine> = match url.scheme() {
-            "ws" | "wss" | "cbor" => Box::new(WsCborEngine::from_connection(&self).await?),
+            "ws" | "wss" | "cbor" => Box::new(WsCborEngine::from_connection(self).await?),
             _ => {
                 return Err(SurrealError::Protocol(
                     "Unsupported protocol. Use ws://, wss://, or cbor://".to_string(),
@@ -319,9 +383,31 @@ impl SurrealConnection {
         if self.debug {
             engine = DebugEngine::wrap(engine);
         }
+        Ok(engine)
+    }
+
+    /// Connect to SurrealDB and return an immutable client
+    pub async fn connect(self) -> Result<SurrealClient> {
+        let mut engine = self.open_engine().await?;
+
+        if let Some(capacity) = self.offline_queue {
+            let connection = self.clone();
+            engine = Box::new(OfflineQueueEngine::new(
+                engine,
+                Box::new(move || {
+                    let connection = connection.clone();
+                    Box::pin(async move { connection.open_engine().await })
+                }),
+                capacity,
+            ));
+        }
 
-        let client = SurrealClient::new(engine, self.namespace, self.database);
-        Ok(client.with_debug(self.debug))
+        let mut client =
+            SurrealClient::new(engine, self.namespace, self.database).with_debug(self.debug);
+        if let Some(timeout) = self.request_timeout {
+            client = client.with_request_timeout(timeout);
+        }
+        Ok(client)
     }
 }
 
@@ -343,6 +429,10 @@ mod tests {
         assert_eq!(conn.database, Some("test_db".to_string()));
         assert!(!conn.version_check);
         assert!(matches!(conn.auth, Some(AuthParams::Root { .. })));
+        assert_eq!(conn.request_timeout, None);
+
+        let conn = conn.with_request_timeout(Duration::from_secs(5));
+        assert_eq!(conn.request_timeout(), Some(Duration::from_secs(5)));
     }
 
     #[test]
@@ -0,0 +1,82 @@
+//! Exact `decimal` parameters.
+//!
+//! SurrealDB reads CBOR tag 10 around a decimal string as a `decimal`. Bind
+//! a [`Decimal`] with [`decimal_to_cbor`] rather than through JSON: a JSON
+//! number would be a float, and a JSON string stays a string.
+//!
+//! `rust_decimal` holds up to 28 significant digits; a longer literal is
+//! rounded when it is parsed, before it ever reaches this module.
+
+use ciborium::Value as CborValue;
+use rust_decimal::Decimal;
+
+/// CBOR tag SurrealDB uses for decimals.
+pub const DECIMAL_TAG: u64 = 10;
+
+/// `value` as a tagged decimal string, bound without loss of precision.
+pub fn decimal_to_cbor(value: &Decimal) -> CborValue {
+    CborValue::Tag(DECIMAL_TAG, Box::new(CborValue::Text(value.to_string())))
+}
+
+/// Read a decimal back from a tagged or plain decimal string.
+pub fn decimal_from_cbor(value: &CborValue) -> Option<Decimal> {
+    match value {
+        CborValue::Tag(DECIMAL_TAG, inner) => decimal_from_cbor(inner),
+        CborValue::Text(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MockSurrealEngine, SurrealClient};
+    use serde_json::json;
+
+    const PRECISE: &str = "9999999999999999.999999999999";
+
+    #[test]
+    fn test_decimal_cbor_round_trip() {
+        let value: Decimal = PRECISE.parse().unwrap();
+        let cbor = decimal_to_cbor(&value);
+        assert_eq!(
+            cbor,
+            CborValue::Tag(10, Box::new(CborValue::Text(PRECISE.to_string())))
+        );
+        assert_eq!(decimal_from_cbor(&cbor), Some(value));
+        assert_eq!(decimal_from_cbor(&CborValue::Float(1.5)), None);
+    }
+
+    #[tokio::test]
+    async fn test_decimal_is_bound_tagged() {
+        let engine = MockSurrealEngine::new().with_exact_response(
+            "query",
+            json!(["CREATE price SET amount = $amount", {"amount": PRECISE}]),
+            json!([{"status": "OK", "result": [{"amount": PRECISE}]}]),
+        );
+        let log = engine.cbor_log();
+        let client = SurrealClient::new(Box::new(engine), None, None);
+
+        let value: Decimal = PRECISE.parse().unwrap();
+        let vars = CborValue::Map(vec![(
+            CborValue::Text("amount".to_string()),
+            decimal_to_cbor(&value),
+        )]);
+        client
+            .query_cbor("CREATE price SET amount = $amount", vars)
+            .await
+            .unwrap();
+
+        let log = log.lock().unwrap();
+        let CborValue::Array(params) = &log[0].1 else {
+            panic!("query params are not an array: {:?}", log[0].1);
+        };
+        let CborValue::Map(vars) = &params[1] else {
+            panic!("query vars are not a map: {:?}", params[1]);
+        };
+        assert_eq!(
+            vars[0].1,
+            CborValue::Tag(10, Box::new(CborValue::Text(PRECISE.to_string())))
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Typed builders for SurrealDB schema statements
+//!
+//! [`DefineTable`], [`DefineField`] and [`DefineIndex`] render `DEFINE`
+//! statements, and [`Migration`] collects several to run as one query with
+//! [`SurrealClient::define`](crate::SurrealClient::define):
+//!
+//! ```rust,ignore
+//! let migration = Migration::new()
+//!     .define(DefineTable::new("user").schemafull())
+//!     .define(DefineField::new("email", "user").ty("string").assert("string::is::email($value)"))
+//!     .define(DefineIndex::new("user_email", "user").fields(["email"]).unique());
+//! client.define(&migration).await?;
+//! ```
+//!
+//! Table, field and index names are escaped with
+//! [`escape_identifier`](crate::escape_identifier). Types and `ASSERT` / `DEFAULT` clauses are SurrealQL and
+//! are written into the statement as given, so don't build them from user
+//! input.
+
+use crate::record::escape_identifier;
+
+/// A statement (or batch of statements) that [`SurrealClient::define`](crate::SurrealClient::define) can run.
+pub trait DefineStatement {
+    /// The SurrealQL text.
+    fn to_surql(&self) -> String;
+}
+
+/// `DEFINE TABLE` — see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct DefineTable {
+    name: String,
+    if_not_exists: bool,
+    schema: Option<&'static str>,
+}
+
+impl DefineTable {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            if_not_exists: false,
+            schema: None,
+        }
+    }
+
+    /// Only allow the fields defined for the table.
+    pub fn schemafull(mut self) -> Self {
+        self.schema = Some("SCHEMAFULL");
+        self
+    }
+
+    /// Allow fields that aren't defined.
+    pub fn schemaless(mut self) -> Self {
+        self.schema = Some("SCHEMALESS");
+        self
+    }
+
+    /// Leave an existing definition alone instead of failing.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+}
+
+impl DefineStatement for DefineTable {
+    fn to_surql(&self) -> String {
+        let mut sql = format!(
+            "DEFINE TABLE {}{}",
+            if_not_exists(self.if_not_exists),
+            escape_identifier(&self.name)
+        );
+        if let Some(schema) = self.schema {
+            sql.push(' ');
+            sql.push_str(schema);
+        }
+        sql
+    }
+}
+
+/// `DEFINE FIELD ... ON TABLE ...` — see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct DefineField {
+    name: String,
+    table: String,
+    if_not_exists: bool,
+    ty: Option<String>,
+    default: Option<String>,
+    assert: Option<String>,
+}
+
+impl DefineField {
+    /// Define field `name` (a dotted path like `address.city` for nested
+    /// fields) on `table`.
+    pub fn new(name: impl Into<String>, table: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            if_not_exists: false,
+            ty: None,
+            default: None,
+            assert: None,
+        }
+    }
+
+    /// `TYPE ty`, e.g. `"string"`, `"option<int>"` or `"record<user>"`.
+    pub fn ty(mut self, ty: impl Into<String>) -> Self {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    /// `DEFAULT expr`, a SurrealQL expression.
+    pub fn default(mut self, expr: impl Into<String>) -> Self {
+        self.default = Some(expr.into());
+        self
+    }
+
+    /// `ASSERT expr`, a SurrealQL expression over `$value`.
+    pub fn assert(mut self, expr: impl Into<String>) -> Self {
+        self.assert = Some(expr.into());
+        self
+    }
+
+    /// Leave an existing definition alone instead of failing.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+}
+
+impl DefineStatement for DefineField {
+    fn to_surql(&self) -> String {
+        let mut sql = format!(
+            "DEFINE FIELD {}{} ON TABLE {}",
+            if_not_exists(self.if_not_exists),
+            field_path(&self.name),
+            escape_identifier(&self.table)
+        );
+        for (keyword, clause) in [
+            ("TYPE", &self.ty),
+            ("DEFAULT", &self.default),
+            ("ASSERT", &self.assert),
+        ] {
+            if let Some(clause) = clause {
+                sql.push_str(&format!(" {} {}", keyword, clause));
+            }
+        }
+        sql
+    }
+}
+
+/// `DEFINE INDEX ... ON TABLE ... FIELDS ...` — see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct DefineIndex {
+    name: String,
+    table: String,
+    if_not_exists: bool,
+    fields: Vec<String>,
+    unique: bool,
+}
+
+impl DefineIndex {
+    pub fn new(name: impl Into<String>, table: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            if_not_exists: false,
+            fields: Vec::new(),
+            unique: false,
+        }
+    }
+
+    /// The indexed fields, in order.
+    pub fn fields<S: Into<String>>(mut self, fields: impl IntoIterator<Item = S>) -> Self {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Reject records that repeat an indexed value.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Leave an existing definition alone instead of failing.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+}
+
+impl DefineStatement for DefineIndex {
+    fn to_surql(&self) -> String {
+        let fields: Vec<String> = self.fields.iter().map(|f| field_path(f)).collect();
+        let mut sql = format!(
+            "DEFINE INDEX {}{} ON TABLE {} FIELDS {}",
+            if_not_exists(self.if_not_exists),
+            escape_identifier(&self.name),
+            escape_identifier(&self.table),
+            fields.join(", ")
+        );
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        sql
+    }
+}
+
+/// Several `DEFINE` statements run as one query, in order.
+#[derive(Debug, Clone, Default)]
+pub struct Migration {
+    statements: Vec<String>,
+}
+
+impl Migration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a statement.
+    pub fn define(mut self, statement: impl DefineStatement) -> Self {
+        self.statements.push(statement.to_surql());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+}
+
+impl DefineStatement for Migration {
+    fn to_surql(&self) -> String {
+        self.statements.join(";\n")
+    }
+}
+
+fn if_not_exists(enabled: bool) -> &'static str {
+    if enabled { "IF NOT EXISTS " } else { "" }
+}
+
+/// A dotted field path; `*` parts (array elements) stay as they are.
+fn field_path(path: &str) -> String {
+    path.split('.')
+        .map(|part| {
+            if part == "*" {
+                part.to_string()
+            } else {
+                escape_identifier(part)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_define_statements_render() {
+        assert_eq!(
+            DefineTable::new("user").schemafull().to_surql(),
+            "DEFINE TABLE user SCHEMAFULL"
+        );
+        assert_eq!(
+            DefineTable::new("audit log")
+                .if_not_exists()
+                .schemaless()
+                .to_surql(),
+            "DEFINE TABLE IF NOT EXISTS ⟨audit log⟩ SCHEMALESS"
+        );
+
+        assert_eq!(
+            DefineField::new("email", "user")
+                .ty("string")
+                .assert("string::is::email($value)")
+                .to_surql(),
+            "DEFINE FIELD email ON TABLE user TYPE string ASSERT string::is::email($value)"
+        );
+        assert_eq!(
+            DefineField::new("tags.*", "user")
+                .ty("string")
+                .default("'new'")
+                .to_surql(),
+            "DEFINE FIELD tags.* ON TABLE user TYPE string DEFAULT 'new'"
+        );
+
+        assert_eq!(
+            DefineIndex::new("user_email", "user")
+                .fields(["email"])
+                .unique()
+                .to_surql(),
+            "DEFINE INDEX user_email ON TABLE user FIELDS email UNIQUE"
+        );
+        assert_eq!(
+            DefineIndex::new("by_name", "user")
+                .fields(["last-name", "first_name"])
+                .to_surql(),
+            "DEFINE INDEX by_name ON TABLE user FIELDS ⟨last-name⟩, first_name"
+        );
+    }
+}
@@ -39,4 +39,11 @@ pub trait Engine: Send + Sync {
     /// Drop local delivery for a live-query id. Does not send `KILL` — that is
     /// a separate RPC. Default is a no-op.
     async fn unregister_live(&mut self, _query_id: &str) {}
+
+    /// Close the transport cleanly once no requests are pending. Called by
+    /// [`SurrealClient::shutdown`](crate::SurrealClient::shutdown). Default is
+    /// a no-op, for engines with nothing to close.
+    async fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
 }
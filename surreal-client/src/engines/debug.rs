@@ -74,6 +74,10 @@ impl Engine for DebugEngine {
     async fn unregister_live(&mut self, query_id: &str) {
         self.inner.unregister_live(query_id).await
     }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 pub mod debug;
+pub mod offline;
 pub mod ws_cbor;
 
 pub use debug::DebugEngine;
+pub use offline::OfflineQueueEngine;
 pub use ws_cbor::WsCborEngine;
@@ -0,0 +1,404 @@
+//! Offline write queue for flaky connections
+//!
+//! [`OfflineQueueEngine`] wraps the transport engine. When an RPC fails with
+//! a connection error the engine is dropped and writes are buffered, up to a
+//! capacity, instead of failing. Each later call tries to reconnect once;
+//! after a successful reconnect the buffered writes are replayed in order
+//! before the new call goes out. Reads never wait: while disconnected they
+//! fail with [`SurrealError::Connection`].
+//!
+//! Enable it with [`SurrealConnection::with_offline_queue`](crate::SurrealConnection::with_offline_queue).
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use ciborium::Value as CborValue;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::live::Notification;
+use crate::{Engine, Result, SurrealError};
+
+/// Opens a fresh, signed-in engine.
+pub type Connector =
+    Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<Box<dyn Engine>>> + Send>> + Send + Sync>;
+
+/// RPC methods that change data.
+const WRITE_METHODS: &[&str] = &[
+    "create",
+    "insert",
+    "insert_relation",
+    "update",
+    "upsert",
+    "merge",
+    "patch",
+    "delete",
+    "relate",
+];
+
+/// Leading SurrealQL keywords of a `query` that changes data.
+const WRITE_STATEMENTS: &[&str] = &["CREATE", "INSERT", "UPDATE", "UPSERT", "DELETE", "RELATE"];
+
+/// Whether an RPC is a write, and so may be queued while disconnected. A
+/// `query` counts as a write when its text starts with a data-changing
+/// statement.
+fn is_write(method: &str, params: &CborValue) -> bool {
+    if WRITE_METHODS.contains(&method) {
+        return true;
+    }
+    if method != "query" {
+        return false;
+    }
+    let sql = match params {
+        CborValue::Array(items) => match items.first() {
+            Some(CborValue::Text(sql)) => sql,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let first_word = sql.split_whitespace().next().unwrap_or_default();
+    WRITE_STATEMENTS
+        .iter()
+        .any(|keyword| first_word.eq_ignore_ascii_case(keyword))
+}
+
+/// Engine wrapper that queues writes while the connection is down, see the
+/// [module docs](self).
+///
+/// A queued write returns `Null` right away, as its real result isn't known
+/// yet. A write whose connection dropped after it was sent is queued too, so
+/// it may be applied twice if the server had received it. Session variables
+/// set with `let` don't survive a reconnect. Sign-in does, as the connector
+/// repeats it, and so does the scope: the last successful `use` is sent
+/// again on the new connection before anything else.
+pub struct OfflineQueueEngine {
+    engine: Option<Box<dyn Engine>>,
+    connect: Connector,
+    queue: VecDeque<(String, CborValue)>,
+    capacity: usize,
+    /// Parameters of the last successful `use`, restored after a reconnect
+    scope: Option<CborValue>,
+}
+
+impl OfflineQueueEngine {
+    /// Wrap a connected `engine`. `connect` opens a replacement after a
+    /// disconnect; at most `capacity` writes are buffered meanwhile.
+    pub fn new(engine: Box<dyn Engine>, connect: Connector, capacity: usize) -> Self {
+        Self {
+            engine: Some(engine),
+            connect,
+            queue: VecDeque::new(),
+            capacity,
+            scope: None,
+        }
+    }
+
+    /// Number of writes waiting to be replayed.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Reconnect if needed and replay the queue. Returns the engine when the
+    /// connection is up and the queue is empty.
+    async fn online(&mut self) -> Option<&mut Box<dyn Engine>> {
+        if self.engine.is_none() {
+            let mut engine = match (self.connect)().await {
+                Ok(engine) => engine,
+                Err(e) => {
+                    warn!(error = %e, queued = self.queue.len(), "SurrealDB reconnect failed");
+                    return None;
+                }
+            };
+            // The connector opens with the connection's original scope
+            if let Some(scope) = &self.scope
+                && let Err(e) = engine.send_message_cbor("use", scope.clone()).await
+            {
+                warn!(error = %e, "restoring SurrealDB scope after reconnect failed");
+                return None;
+            }
+            self.engine = Some(engine);
+        }
+
+        while let Some((method, params)) = self.queue.pop_front() {
+            let engine = self.engine.as_mut()?;
+            match engine.send_message_cbor(&method, params.clone()).await {
+                Ok(_) => {}
+                Err(e) if is_disconnect(&e) => {
+                    self.queue.push_front((method, params));
+                    self.engine = None;
+                    return None;
+                }
+                // The server saw it and refused; replaying again won't help
+                Err(e) => warn!(error = %e, method = %method, "queued SurrealDB write failed"),
+            }
+        }
+
+        self.engine.as_mut()
+    }
+
+    fn enqueue(&mut self, method: &str, params: CborValue) -> Result<CborValue> {
+        if self.queue.len() >= self.capacity {
+            return Err(SurrealError::Connection(format!(
+                "disconnected and the offline queue is full ({} writes)",
+                self.capacity
+            )));
+        }
+        self.queue.push_back((method.to_string(), params));
+        Ok(CborValue::Null)
+    }
+}
+
+/// Errors that mean the transport is gone, rather than the request failing.
+fn is_disconnect(error: &SurrealError) -> bool {
+    matches!(
+        error,
+        SurrealError::Connection(_) | SurrealError::WebSocket(_) | SurrealError::Io(_)
+    )
+}
+
+#[async_trait]
+impl Engine for OfflineQueueEngine {
+    async fn send_message_cbor(&mut self, method: &str, params: CborValue) -> Result<CborValue> {
+        let write = is_write(method, &params);
+
+        let Some(engine) = self.online().await else {
+            if write {
+                return self.enqueue(method, params);
+            }
+            return Err(SurrealError::Connection(format!(
+                "disconnected, {} writes queued",
+                self.queue.len()
+            )));
+        };
+
+        let retry = write.then(|| params.clone());
+        let scope = (method == "use").then(|| params.clone());
+        match engine.send_message_cbor(method, params).await {
+            Err(e) if is_disconnect(&e) => {
+                self.engine = None;
+                match retry {
+                    Some(params) => self.enqueue(method, params),
+                    None => Err(e),
+                }
+            }
+            Ok(result) => {
+                if scope.is_some() {
+                    self.scope = scope;
+                }
+                Ok(result)
+            }
+            result => result,
+        }
+    }
+
+    async fn register_live(
+        &mut self,
+        query_id: &str,
+    ) -> Result<mpsc::UnboundedReceiver<Notification>> {
+        match self.engine.as_mut() {
+            Some(engine) => engine.register_live(query_id).await,
+            None => Err(SurrealError::Connection("disconnected".to_string())),
+        }
+    }
+
+    async fn unregister_live(&mut self, query_id: &str) {
+        if let Some(engine) = self.engine.as_mut() {
+            engine.unregister_live(query_id).await;
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if !self.queue.is_empty() {
+            warn!(
+                queued = self.queue.len(),
+                "closing with SurrealDB writes still queued"
+            );
+        }
+        match self.engine.as_mut() {
+            Some(engine) => engine.close().await,
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// Records every RPC it receives, with its text parameters; fails them
+    /// all while `up` is false.
+    struct FlakyEngine {
+        up: Arc<AtomicBool>,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Engine for FlakyEngine {
+        async fn send_message_cbor(
+            &mut self,
+            method: &str,
+            params: CborValue,
+        ) -> Result<CborValue> {
+            if !self.up.load(Ordering::SeqCst) {
+                return Err(SurrealError::Connection("WS send failed".to_string()));
+            }
+            let mut entry = method.to_string();
+            if let CborValue::Array(items) = params {
+                for text in items.iter().filter_map(CborValue::as_text) {
+                    entry.push(' ');
+                    entry.push_str(text);
+                }
+            }
+            self.log.lock().unwrap().push(entry);
+            Ok(CborValue::Bool(true))
+        }
+    }
+
+    fn rpc(text: &str) -> CborValue {
+        CborValue::Array(vec![CborValue::Text(text.to_string())])
+    }
+
+    /// An engine over `FlakyEngine`s sharing `up` and `log`, reconnecting
+    /// whenever `up` is set.
+    fn flaky_queue(
+        up: &Arc<AtomicBool>,
+        log: &Arc<Mutex<Vec<String>>>,
+        capacity: usize,
+    ) -> OfflineQueueEngine {
+        let flaky = {
+            let (up, log) = (up.clone(), log.clone());
+            move || FlakyEngine {
+                up: up.clone(),
+                log: log.clone(),
+            }
+        };
+        let connector: Connector = {
+            let (up, flaky) = (up.clone(), flaky.clone());
+            Box::new(move || {
+                let up = up.load(Ordering::SeqCst);
+                let engine: Box<dyn Engine> = Box::new(flaky());
+                Box::pin(async move {
+                    if !up {
+                        return Err(SurrealError::Connection("refused".to_string()));
+                    }
+                    Ok(engine)
+                })
+            })
+        };
+        OfflineQueueEngine::new(Box::new(flaky()), connector, capacity)
+    }
+
+    #[tokio::test]
+    async fn test_writes_replay_in_order_after_reconnect() {
+        let up = Arc::new(AtomicBool::new(true));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let connects = Arc::new(Mutex::new(0));
+
+        let flaky = {
+            let (up, log) = (up.clone(), log.clone());
+            move || FlakyEngine {
+                up: up.clone(),
+                log: log.clone(),
+            }
+        };
+        let connector: Connector = {
+            let (up, connects, flaky) = (up.clone(), connects.clone(), flaky.clone());
+            Box::new(move || {
+                let up = up.load(Ordering::SeqCst);
+                let connects = connects.clone();
+                let engine: Box<dyn Engine> = Box::new(flaky());
+                Box::pin(async move {
+                    if !up {
+                        return Err(SurrealError::Connection("refused".to_string()));
+                    }
+                    *connects.lock().unwrap() += 1;
+                    Ok(engine)
+                })
+            })
+        };
+        let mut engine = OfflineQueueEngine::new(Box::new(flaky()), connector, 2);
+
+        up.store(false, Ordering::SeqCst);
+        let queued = engine
+            .send_message_cbor("query", rpc("CREATE bakery:1"))
+            .await
+            .unwrap();
+        assert_eq!(queued, CborValue::Null);
+        engine
+            .send_message_cbor("merge", rpc("bakery:1"))
+            .await
+            .unwrap();
+        assert_eq!(engine.queued(), 2);
+
+        // Reads fail instead of queueing, and the queue is bounded
+        assert!(matches!(
+            engine
+                .send_message_cbor("query", rpc("SELECT * FROM bakery"))
+                .await,
+            Err(SurrealError::Connection(_))
+        ));
+        assert!(
+            engine
+                .send_message_cbor("delete", rpc("bakery:1"))
+                .await
+                .is_err()
+        );
+        assert_eq!(engine.queued(), 2);
+        assert!(log.lock().unwrap().is_empty());
+
+        up.store(true, Ordering::SeqCst);
+        engine
+            .send_message_cbor("query", rpc("SELECT * FROM bakery"))
+            .await
+            .unwrap();
+        assert_eq!(engine.queued(), 0);
+        assert_eq!(*connects.lock().unwrap(), 1);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "query CREATE bakery:1",
+                "merge bakery:1",
+                "query SELECT * FROM bakery"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scope_is_restored_after_reconnect() {
+        let up = Arc::new(AtomicBool::new(true));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = flaky_queue(&up, &log, 2);
+
+        let scope = CborValue::Array(vec![
+            CborValue::Text("shop".to_string()),
+            CborValue::Text("us".to_string()),
+        ]);
+        engine.send_message_cbor("use", scope).await.unwrap();
+
+        up.store(false, Ordering::SeqCst);
+        engine
+            .send_message_cbor("query", rpc("CREATE bakery:1"))
+            .await
+            .unwrap();
+
+        up.store(true, Ordering::SeqCst);
+        engine
+            .send_message_cbor("query", rpc("SELECT * FROM bakery"))
+            .await
+            .unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "use shop us",
+                // New connection: scope first, then the queue
+                "use shop us",
+                "query CREATE bakery:1",
+                "query SELECT * FROM bakery"
+            ]
+        );
+    }
+}
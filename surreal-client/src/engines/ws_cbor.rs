@@ -12,16 +12,23 @@ use futures_util::{SinkExt, StreamExt};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering::SeqCst};
+use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio::sync::{Mutex, mpsc, oneshot};
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::CapacityError;
 use tokio_tungstenite::tungstenite::http::HeaderValue;
 use tokio_tungstenite::tungstenite::http::header::SEC_WEBSOCKET_PROTOCOL;
-use tokio_tungstenite::{WebSocketStream, connect_async, tungstenite::Message};
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::{
+    WebSocketStream, connect_async_with_config,
+    tungstenite::{Error as WsError, Message},
+};
 use tracing::{Instrument as _, warn};
 
 use crate::SurrealConnection;
+use crate::compression::{Compression, DEFAULT_MAX_INFLATED};
 use crate::live::{Action, Notification};
 use crate::{
     engine::Engine,
@@ -29,6 +36,7 @@ use crate::{
 };
 
 /// Live-query subscribers, keyed by the server's live-query id.
+type PendingRequests = Arc<Mutex<HashMap<String, oneshot::Sender<Result<CborValue>>>>>;
 type LiveSubscribers = Arc<Mutex<HashMap<String, mpsc::UnboundedSender<Notification>>>>;
 
 /// Normalise a CBOR id to a stable string key.
@@ -89,6 +97,31 @@ fn parse_notification(inner: &[(CborValue, CborValue)]) -> Option<Notification>
     })
 }
 
+/// Map a receive-side WebSocket error to the error reported to callers.
+fn receive_error(e: &WsError) -> SurrealError {
+    match e {
+        WsError::Capacity(CapacityError::MessageTooLong { size, max_size }) => {
+            SurrealError::MessageTooLarge {
+                size: *size,
+                max: *max_size,
+            }
+        }
+        e => SurrealError::Connection(format!("WS receive failed: {}", e)),
+    }
+}
+
+/// Copy an inflate error for each waiting caller (errors aren't `Clone`).
+fn inflate_error(e: &SurrealError) -> SurrealError {
+    match e {
+        SurrealError::MessageTooLarge { size, max } => SurrealError::MessageTooLarge {
+            size: *size,
+            max: *max,
+        },
+        SurrealError::Protocol(message) => SurrealError::Protocol(message.clone()),
+        e => SurrealError::Protocol(e.to_string()),
+    }
+}
+
 /// Request structure for CBOR WebSocket protocol
 #[derive(Debug, Clone)]
 struct RouterRequest {
@@ -97,6 +130,35 @@ struct RouterRequest {
     params: Option<CborValue>,
 }
 
+/// Removes a request's waiter from `pending_requests` unless disarmed, so a
+/// request whose future is dropped (timed out, or failed to send) doesn't
+/// leave its entry behind.
+struct PendingGuard {
+    id: String,
+    pending: PendingRequests,
+    armed: bool,
+}
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        let id = std::mem::take(&mut self.id);
+        if let Ok(mut pending) = self.pending.try_lock() {
+            pending.remove(&id);
+        } else if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let pending = Arc::clone(&self.pending);
+            runtime.spawn(async move {
+                pending.lock().await.remove(&id);
+            });
+        }
+    }
+}
+
+/// How long `close` waits for the server to acknowledge the close frame.
+const CLOSE_GRACE: Duration = Duration::from_secs(1);
+
 type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
 /// WebSocket engine using native CBOR for SurrealDB.
@@ -108,9 +170,11 @@ pub struct WsCborEngine {
     sink: Arc<Mutex<SplitSink<WsStream, Message>>>,
     stream: Arc<Mutex<SplitStream<WsStream>>>,
     msg_id: AtomicU64,
-    pending_requests: Arc<Mutex<HashMap<String, oneshot::Sender<CborValue>>>>,
+    pending_requests: PendingRequests,
     live_subscribers: LiveSubscribers,
     task_handle: Option<tokio::task::JoinHandle<()>>,
+    compression: Compression,
+    max_inflated: usize,
 }
 
 impl WsCborEngine {
@@ -149,9 +213,17 @@ impl WsCborEngine {
             .headers_mut()
             .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("cbor"));
 
-        let (stream, _response) = connect_async(request).await.map_err(|e| {
-            SurrealError::Connection(format!("Failed to connect to WebSocket: {}", e))
-        })?;
+        let config = connect.max_message_size().map(|bytes| {
+            WebSocketConfig::default()
+                .max_message_size(Some(bytes))
+                .max_frame_size(Some(bytes))
+        });
+
+        let (stream, _response) = connect_async_with_config(request, config, false)
+            .await
+            .map_err(|e| {
+                SurrealError::Connection(format!("Failed to connect to WebSocket: {}", e))
+            })?;
 
         let (sink, stream) = stream.split();
 
@@ -162,12 +234,21 @@ impl WsCborEngine {
             pending_requests: Arc::new(Mutex::new(HashMap::new())),
             live_subscribers: Arc::new(Mutex::new(HashMap::new())),
             task_handle: None,
+            compression: connect.compression(),
+            max_inflated: connect.max_message_size().unwrap_or(DEFAULT_MAX_INFLATED),
         };
 
         let task_handle = engine.handle_messages();
         engine.task_handle = Some(task_handle);
 
-        connect.init_engine(&mut engine).await?;
+        match connect.request_timeout() {
+            None => connect.init_engine(&mut engine).await?,
+            Some(limit) => tokio::time::timeout(limit, connect.init_engine(&mut engine))
+                .await
+                .map_err(|_| {
+                    SurrealError::Timeout(format!("sign-in did not complete within {:?}", limit))
+                })??,
+        }
 
         Ok(engine)
     }
@@ -176,6 +257,7 @@ impl WsCborEngine {
         let stream = Arc::clone(&self.stream);
         let pending_requests = Arc::clone(&self.pending_requests);
         let live_subscribers = Arc::clone(&self.live_subscribers);
+        let (compression, max_inflated) = (self.compression, self.max_inflated);
 
         tokio::spawn(
             async move {
@@ -189,6 +271,14 @@ impl WsCborEngine {
                         None => break,
                         Some(Err(e)) => {
                             warn!(error = %e, "CBOR ws receive error");
+                            // The stream is unusable now; fail everyone still waiting.
+                            let waiters: Vec<_> = {
+                                let mut pending = pending_requests.lock().await;
+                                pending.drain().map(|(_, tx)| tx).collect()
+                            };
+                            for tx in waiters {
+                                let _ = tx.send(Err(receive_error(&e)));
+                            }
                             break;
                         }
                         Some(Ok(msg)) => msg,
@@ -199,6 +289,23 @@ impl WsCborEngine {
                             // Ignore text messages - we only use CBOR binary
                         }
                         Message::Binary(binary) => {
+                            let binary = match compression.inflate(&binary, max_inflated) {
+                                Ok(binary) => binary,
+                                Err(e) => {
+                                    // The frame's request id is inside it, so
+                                    // fail everyone rather than leave its
+                                    // waiter hanging.
+                                    warn!(error = %e, bytes = binary.len(), "CBOR ws frame inflate failed");
+                                    let waiters: Vec<_> = {
+                                        let mut pending = pending_requests.lock().await;
+                                        pending.drain().map(|(_, tx)| tx).collect()
+                                    };
+                                    for tx in waiters {
+                                        let _ = tx.send(Err(inflate_error(&e)));
+                                    }
+                                    continue;
+                                }
+                            };
                             // Two frame shapes share this channel:
                             //   response:     {id, result|error}         (id matches a request)
                             //   notification: {id, action, result}        (id is a live-query uuid)
@@ -245,14 +352,14 @@ impl WsCborEngine {
 
                                         if let Some(tx) = tx {
                                             if let Some(err) = error {
-                                                let _ = tx.send(CborValue::Map(vec![(
+                                                let _ = tx.send(Ok(CborValue::Map(vec![(
                                                     CborValue::Text("error".to_string()),
                                                     err,
-                                                )]));
+                                                )])));
                                             } else if let Some(res) = result {
-                                                let _ = tx.send(res);
+                                                let _ = tx.send(Ok(res));
                                             } else {
-                                                let _ = tx.send(CborValue::Null);
+                                                let _ = tx.send(Ok(CborValue::Null));
                                             }
                                         }
                                     }
@@ -285,6 +392,11 @@ impl Engine for WsCborEngine {
             let mut pending = self.pending_requests.lock().await;
             pending.insert(id.clone(), tx);
         }
+        let mut guard = PendingGuard {
+            id: id.clone(),
+            pending: Arc::clone(&self.pending_requests),
+            armed: true,
+        };
 
         let request = RouterRequest {
             id: id.clone(),
@@ -320,9 +432,11 @@ impl Engine for WsCborEngine {
                 .map_err(|e| SurrealError::Connection(format!("WS send failed: {}", e)))?;
         }
 
-        let response = rx
-            .await
-            .map_err(|_| SurrealError::Protocol("Response channel closed".to_string()))?;
+        let response = rx.await;
+        // The read loop removed the entry when it replied (or failed)
+        guard.armed = false;
+        let response = response
+            .map_err(|_| SurrealError::Protocol("Response channel closed".to_string()))??;
 
         if let CborValue::Map(map) = &response {
             for (key, value) in map {
@@ -378,6 +492,26 @@ impl Engine for WsCborEngine {
         let mut subs = self.live_subscribers.lock().await;
         subs.remove(query_id);
     }
+
+    /// Send a close frame and give the read loop a moment to see the
+    /// server's reply before stopping it.
+    async fn close(&mut self) -> Result<()> {
+        {
+            let mut sink = self.sink.lock().await;
+            sink.send(Message::Close(None))
+                .await
+                .map_err(|e| SurrealError::Connection(format!("WS close failed: {}", e)))?;
+        }
+
+        if let Some(mut handle) = self.task_handle.take()
+            && tokio::time::timeout(CLOSE_GRACE, &mut handle)
+                .await
+                .is_err()
+        {
+            handle.abort();
+        }
+        Ok(())
+    }
 }
 
 impl Drop for WsCborEngine {
@@ -387,3 +521,189 @@ impl Drop for WsCborEngine {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::Response;
+
+    /// Accept one client and answer its first request with `reply_len` bytes.
+    // The handshake callback's error type is fixed by tungstenite.
+    #[allow(clippy::result_large_err)]
+    async fn oversize_server(reply_len: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |_req: &_, mut res: Response| {
+                res.headers_mut()
+                    .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("cbor"));
+                Ok(res)
+            })
+            .await
+            .unwrap();
+            let _signin = ws.next().await;
+            let _ = ws.send(Message::Binary(vec![0u8; reply_len].into())).await;
+            let _ = ws.next().await;
+        });
+        format!("ws://{}", addr)
+    }
+
+    /// Accept one client and answer every request with `null`, except
+    /// `ping`, which never gets a reply.
+    #[allow(clippy::result_large_err)]
+    async fn silent_ping_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |_req: &_, mut res: Response| {
+                res.headers_mut()
+                    .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("cbor"));
+                Ok(res)
+            })
+            .await
+            .unwrap();
+            while let Some(Ok(Message::Binary(frame))) = ws.next().await {
+                let Ok(CborValue::Map(request)) = ciborium::from_reader(frame.as_ref()) else {
+                    continue;
+                };
+                let field = |name: &str| {
+                    request
+                        .iter()
+                        .find(|(k, _)| k.as_text() == Some(name))
+                        .map(|(_, v)| v.clone())
+                };
+                if field("method").and_then(|m| m.into_text().ok()).as_deref() == Some("ping") {
+                    continue;
+                }
+                let reply = CborValue::Map(vec![
+                    (CborValue::Text("id".into()), field("id").unwrap()),
+                    (CborValue::Text("result".into()), CborValue::Null),
+                ]);
+                let mut payload = Vec::new();
+                ciborium::into_writer(&reply, &mut payload).unwrap();
+                let _ = ws.send(Message::Binary(payload.into())).await;
+            }
+        });
+        format!("ws://{}", addr)
+    }
+
+    /// Accept one client and answer every request with `result`, gzipping
+    /// the frames when `gzip` is set.
+    #[allow(clippy::result_large_err)]
+    async fn reply_server(result: CborValue, gzip: bool) -> String {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |_req: &_, mut res: Response| {
+                res.headers_mut()
+                    .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("cbor"));
+                Ok(res)
+            })
+            .await
+            .unwrap();
+            while let Some(Ok(Message::Binary(frame))) = ws.next().await {
+                let Ok(CborValue::Map(request)) = ciborium::from_reader(frame.as_ref()) else {
+                    continue;
+                };
+                let id = request
+                    .iter()
+                    .find(|(k, _)| k.as_text() == Some("id"))
+                    .map(|(_, v)| v.clone())
+                    .unwrap();
+                let reply = CborValue::Map(vec![
+                    (CborValue::Text("id".into()), id),
+                    (CborValue::Text("result".into()), result.clone()),
+                ]);
+                let mut payload = Vec::new();
+                ciborium::into_writer(&reply, &mut payload).unwrap();
+                if gzip {
+                    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder.write_all(&payload).unwrap();
+                    payload = encoder.finish().unwrap();
+                }
+                let _ = ws.send(Message::Binary(payload.into())).await;
+            }
+        });
+        format!("ws://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_gzip_frames_decode_like_plain_ones() {
+        let rows = CborValue::Array(vec![CborValue::Map(vec![
+            (
+                CborValue::Text("name".into()),
+                CborValue::Text("bakery".into()),
+            ),
+            (
+                CborValue::Text("notes".into()),
+                CborValue::Text("fresh ".repeat(200)),
+            ),
+        ])]);
+
+        let mut results = Vec::new();
+        for gzip in [false, true] {
+            let connection = SurrealConnection::new()
+                .url(reply_server(rows.clone(), gzip).await)
+                .auth_root("root", "root")
+                .with_compression(Compression::Gzip)
+                .with_request_timeout(Duration::from_secs(5));
+            let mut engine = WsCborEngine::from_connection(&connection).await.unwrap();
+            let params = CborValue::Array(vec![CborValue::Text("SELECT * FROM shop".into())]);
+            results.push(engine.send_message_cbor("query", params).await.unwrap());
+        }
+        assert_eq!(results[0], rows);
+        assert_eq!(results[1], results[0]);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_request_leaves_no_pending_entry() {
+        let connection = SurrealConnection::new()
+            .url(silent_ping_server().await)
+            .auth_root("root", "root");
+        let mut engine = WsCborEngine::from_connection(&connection).await.unwrap();
+
+        let timed_out = tokio::time::timeout(
+            Duration::from_millis(50),
+            engine.send_message_cbor("ping", CborValue::Array(vec![])),
+        )
+        .await;
+        assert!(timed_out.is_err());
+        assert!(engine.pending_requests.lock().await.is_empty());
+
+        // The engine still answers later requests
+        engine
+            .send_message_cbor("version", CborValue::Array(vec![]))
+            .await
+            .unwrap();
+        assert!(engine.pending_requests.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_oversize_message_reported() {
+        let url = oversize_server(4096).await;
+        let connection = SurrealConnection::new()
+            .url(url)
+            .auth_root("root", "root")
+            .with_max_message_size(1024)
+            .with_request_timeout(Duration::from_secs(5));
+
+        let err = WsCborEngine::from_connection(&connection)
+            .await
+            .err()
+            .expect("oversize reply should fail the sign-in");
+        match err {
+            SurrealError::MessageTooLarge { size, max } => {
+                assert_eq!(max, 1024);
+                assert!(size > 1024, "got size {}", size);
+            }
+            other => panic!("expected MessageTooLarge, got {:?}", other),
+        }
+    }
+}
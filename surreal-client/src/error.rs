@@ -18,6 +18,12 @@ pub enum SurrealError {
     #[error("Protocol error: {0}")]
     Protocol(String),
 
+    #[error("Query error: {0}")]
+    Query(String),
+
+    #[error("Message too large: {size} bytes exceeds the {max} byte limit")]
+    MessageTooLarge { size: usize, max: usize },
+
     #[error("Timeout error: {0}")]
     Timeout(String),
 
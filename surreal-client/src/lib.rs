@@ -5,7 +5,11 @@
 
 mod cbor_convert;
 pub mod client;
+pub mod compression;
 pub mod connection;
+#[cfg(feature = "decimal")]
+pub mod decimal;
+pub mod define;
 pub mod engine;
 pub mod engines;
 pub mod error;
@@ -14,17 +18,25 @@ pub mod mocks;
 pub mod params;
 #[cfg(feature = "pool")]
 pub mod pool;
+pub mod query;
+pub mod query_meta;
 pub mod record;
 pub mod session;
 
 // Re-export the main client from the parent module
 pub use client::SurrealClient;
+pub use compression::Compression;
 pub use connection::SurrealConnection;
+pub use define::{DefineField, DefineIndex, DefineStatement, DefineTable, Migration};
 
 pub use engine::Engine;
-pub use engines::{DebugEngine, WsCborEngine};
+pub use engines::{DebugEngine, OfflineQueueEngine, WsCborEngine};
 pub use error::{Result, SurrealError};
 pub use live::{Action, LiveStream, Notification};
 pub use mocks::{MockSurrealEngine, SurrealMockBuilder};
-pub use record::{RecordId, RecordIdValue, RecordRange, Table, escape_identifier};
+pub use query::QueryBuilder;
+pub use query_meta::QueryMeta;
+pub use record::{
+    RecordId, RecordIdValue, RecordParseError, RecordRange, Table, escape_identifier,
+};
 pub use session::SessionState;
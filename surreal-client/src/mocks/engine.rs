@@ -1,21 +1,93 @@
 //! Mock SurrealDB Engine with Exact Request Matching
 //!
 //! Provides a simplified mock implementation that requires exact matching of method calls
-//! and parameters, making it predictable and easy to debug.
+//! and parameters, making it predictable and easy to debug. Queries can also be matched
+//! by a regex with [`MockSurrealEngine::on_pattern`], when the test cares about the shape
+//! of the query and its bound values rather than the exact prepared string.
+//! [`MockSurrealEngine::with_latency`] and [`with_jitter`](MockSurrealEngine::with_jitter)
+//! delay each response, for exercising request timeouts and shutdown draining.
 
 use crate::{Engine, error::Result};
 use async_trait::async_trait;
 use ciborium::Value as CborValue;
+use regex::Regex;
 use serde_json::{Value, json};
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A `query` request that matched an [`on_pattern`](MockSurrealEngine::on_pattern) regex.
+#[derive(Debug, Clone)]
+pub struct QueryMatch {
+    /// The query text as sent.
+    pub query: String,
+    /// Regex capture groups; index 0 is the whole query. Groups that didn't
+    /// participate are `None`.
+    pub captures: Vec<Option<String>>,
+    /// Bound variables as sent (`{"_arg1": ..., "_arg2": ...}`), or `{}`.
+    pub variables: Value,
+}
+
+impl QueryMatch {
+    /// Positional parameters — the `_argN` variables ordered by `N`.
+    pub fn params(&self) -> Vec<&Value> {
+        let Some(variables) = self.variables.as_object() else {
+            return vec![];
+        };
+        let mut args: Vec<(usize, &Value)> = variables
+            .iter()
+            .filter_map(|(name, value)| {
+                let n = name.strip_prefix("_arg")?.parse().ok()?;
+                Some((n, value))
+            })
+            .collect();
+        args.sort_by_key(|(n, _)| *n);
+        args.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Text of capture group `index`, if it matched.
+    pub fn capture(&self, index: usize) -> Option<&str> {
+        self.captures.get(index)?.as_deref()
+    }
+}
+
+type PatternHandler = Arc<dyn Fn(&QueryMatch) -> Value + Send + Sync>;
+
+/// Method and raw CBOR params of each request a mock received, see
+/// [`MockSurrealEngine::cbor_log`].
+pub type CborLog = Arc<Mutex<Vec<(String, CborValue)>>>;
+
+#[derive(Clone)]
+struct QueryPattern {
+    regex: Regex,
+    handler: PatternHandler,
+}
+
+impl fmt::Debug for QueryPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QueryPattern({})", self.regex)
+    }
+}
 
 /// A mock SurrealDB engine that requires exact matching of requests
 #[derive(Debug, Clone)]
 pub struct MockSurrealEngine {
     /// Exact method+params combinations mapped to responses
     exact_matches: HashMap<(String, Value), Value>,
+    /// Query regexes, tried in order when no exact match exists
+    patterns: Vec<QueryPattern>,
     /// Enable debug logging of queries
     debug: bool,
+    /// CBOR requests as received, before conversion for matching
+    cbor_log: CborLog,
+    /// Delay before every response
+    latency: Duration,
+    /// Upper bound of the extra delay added on top of `latency`
+    jitter: Duration,
+    /// Requests delayed so far, shared by clones; seeds the jitter sequence
+    delayed: Arc<AtomicU64>,
 }
 
 impl MockSurrealEngine {
@@ -23,10 +95,98 @@ impl MockSurrealEngine {
     pub fn new() -> Self {
         Self {
             exact_matches: HashMap::new(),
+            patterns: Vec::new(),
             debug: false,
+            cbor_log: CborLog::default(),
+            latency: Duration::ZERO,
+            jitter: Duration::ZERO,
+            delayed: Arc::default(),
         }
     }
 
+    /// Delay every response by `latency`, as a round-trip to a server would.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Add up to `jitter` on top of the latency, varying per request. The
+    /// sequence is deterministic, so a failing test replays the same delays.
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Latency plus this request's share of the jitter.
+    fn next_delay(&self) -> Duration {
+        let jitter = self.jitter.as_nanos() as u64;
+        if jitter == 0 {
+            return self.latency;
+        }
+        // splitmix64 over the request counter
+        let mut x = self
+            .delayed
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        x ^= x >> 31;
+        self.latency + Duration::from_nanos(x % (jitter + 1))
+    }
+
+    async fn delay(&self) {
+        let delay = self.next_delay();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Handle on the CBOR requests this engine (and its clones) receive.
+    /// Matching compares requests as JSON, which drops CBOR tags; the log
+    /// keeps them, so tests can check how a value was bound.
+    pub fn cbor_log(&self) -> CborLog {
+        self.cbor_log.clone()
+    }
+
+    /// Respond to `query` requests whose text matches `pattern` with the
+    /// value `handler` computes. The pattern must match the whole query.
+    /// Exact matches win; patterns are tried in the order they were added.
+    ///
+    /// Panics if `pattern` is not a valid regex.
+    pub fn on_pattern(
+        mut self,
+        pattern: &str,
+        handler: impl Fn(&QueryMatch) -> Value + Send + Sync + 'static,
+    ) -> Self {
+        let regex = Regex::new(&format!("^(?:{})$", pattern))
+            .unwrap_or_else(|e| panic!("MockSurrealEngine: invalid pattern {:?}: {}", pattern, e));
+        self.patterns.push(QueryPattern {
+            regex,
+            handler: Arc::new(handler),
+        });
+        self
+    }
+
+    /// Run the first pattern matching a `query` request, if any.
+    fn find_pattern_response(&self, method: &str, params: &Value) -> Option<Value> {
+        if method != "query" {
+            return None;
+        }
+        let query = params.get(0)?.as_str()?;
+        self.patterns.iter().find_map(|pattern| {
+            let captures = pattern.regex.captures(query)?;
+            let matched = QueryMatch {
+                query: query.to_string(),
+                captures: captures
+                    .iter()
+                    .map(|c| c.map(|c| c.as_str().to_string()))
+                    .collect(),
+                variables: params.get(1).cloned().unwrap_or_else(|| json!({})),
+            };
+            Some((pattern.handler)(&matched))
+        })
+    }
+
     /// Enable debug logging of queries
     pub fn with_debug(mut self, debug: bool) -> Self {
         self.debug = debug;
@@ -77,11 +237,26 @@ impl MockSurrealEngine {
             return response.clone();
         }
 
-        // No exact match found - panic with descriptive error
+        if let Some(response) = self.find_pattern_response(method, params) {
+            if self.debug {
+                println!(
+                    "MockSurrealEngine: pattern match found, returning {:?}",
+                    response
+                );
+            }
+            return response;
+        }
+
+        // No match found - panic with descriptive error
         let allowed_patterns: Vec<String> = self
             .exact_matches
             .keys()
             .map(|(method, params)| format!("{}({})", method, params))
+            .chain(
+                self.patterns
+                    .iter()
+                    .map(|pattern| format!("query(/{}/)", pattern.regex)),
+            )
             .collect();
 
         panic!(
@@ -106,10 +281,16 @@ impl Default for MockSurrealEngine {
 #[async_trait]
 impl Engine for MockSurrealEngine {
     async fn send_message(&mut self, method: &str, params: Value) -> Result<Value> {
+        self.delay().await;
         Ok(self.find_response(method, &params))
     }
 
     async fn send_message_cbor(&mut self, method: &str, params: CborValue) -> Result<CborValue> {
+        self.cbor_log
+            .lock()
+            .unwrap()
+            .push((method.to_string(), params.clone()));
+        self.delay().await;
         let json_params = crate::cbor_convert::cbor_to_json(params);
         let response = self.find_response(method, &json_params);
         Ok(crate::cbor_convert::json_to_cbor(response))
@@ -174,6 +355,28 @@ impl SurrealMockBuilder {
         self
     }
 
+    /// Delay every response; see [`MockSurrealEngine::with_latency`]
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.engine = self.engine.with_latency(latency);
+        self
+    }
+
+    /// Vary the response delay; see [`MockSurrealEngine::with_jitter`]
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.engine = self.engine.with_jitter(jitter);
+        self
+    }
+
+    /// Respond to queries matching a regex; see [`MockSurrealEngine::on_pattern`]
+    pub fn on_pattern(
+        mut self,
+        pattern: &str,
+        handler: impl Fn(&QueryMatch) -> Value + Send + Sync + 'static,
+    ) -> Self {
+        self.engine = self.engine.on_pattern(pattern, handler);
+        self
+    }
+
     /// Build the SurrealClient instance with the configured mock engine
     pub fn build(self) -> crate::SurrealClient {
         crate::SurrealClient::new(Box::new(self.engine), self.namespace, self.database)
@@ -266,6 +469,39 @@ mod tests {
         assert_eq!(result, json!([{"name": "Alice"}]));
     }
 
+    #[tokio::test]
+    async fn test_latency_trips_request_timeout() {
+        let db = SurrealMockBuilder::new()
+            .with_query_response("SELECT * FROM users", json!([{"name": "Alice"}]))
+            .with_latency(Duration::from_millis(50))
+            .build()
+            .with_request_timeout(Duration::from_millis(10));
+
+        let err = db.query("SELECT * FROM users", None).await.unwrap_err();
+        assert!(
+            matches!(err, crate::error::SurrealError::Timeout(_)),
+            "got {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let engine = MockSurrealEngine::new()
+            .with_latency(Duration::from_millis(20))
+            .with_jitter(Duration::from_millis(10));
+
+        let delays: Vec<Duration> = (0..8).map(|_| engine.next_delay()).collect();
+        assert!(
+            delays
+                .iter()
+                .all(|d| *d >= Duration::from_millis(20) && *d <= Duration::from_millis(30)),
+            "{:?}",
+            delays
+        );
+        assert!(delays.windows(2).any(|w| w[0] != w[1]), "{:?}", delays);
+    }
+
     #[tokio::test]
     #[should_panic(
         expected = "executed method query([\"SELECT * FROM posts\"]), but allowed patterns are"
@@ -279,6 +515,64 @@ mod tests {
         let _result = db.query("SELECT * FROM posts", None).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_pattern_computes_response_from_captures_and_params() {
+        let db = SurrealMockBuilder::new()
+            .on_pattern(r"SELECT \* FROM (\w+) WHERE age > \$_arg1", |m| {
+                let table = m.capture(1).unwrap();
+                let min_age = m.params()[0].as_i64().unwrap();
+                json!([{"status": "OK", "result": [{"table": table, "age": min_age + 1}]}])
+            })
+            .build();
+
+        let result = db
+            .query(
+                "SELECT * FROM users WHERE age > $_arg1",
+                Some(json!({"_arg1": 30})),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            json!([{"status": "OK", "result": [{"table": "users", "age": 31}]}])
+        );
+    }
+
+    #[test]
+    fn test_pattern_matches_whole_query_after_exact() {
+        let engine = MockSurrealEngine::new()
+            .with_query_response("SELECT * FROM users", json!("exact"))
+            .on_pattern(r"SELECT \* FROM \w+", |m| json!(m.query));
+
+        assert_eq!(
+            engine.find_response("query", &json!(["SELECT * FROM users"])),
+            json!("exact")
+        );
+        assert_eq!(
+            engine.find_response("query", &json!(["SELECT * FROM posts"])),
+            json!("SELECT * FROM posts")
+        );
+        // Anchored: a trailing clause is not a match
+        assert_eq!(
+            engine.find_pattern_response("query", &json!(["SELECT * FROM posts LIMIT 1"])),
+            None
+        );
+        assert_eq!(
+            engine.find_pattern_response("select", &json!(["SELECT * FROM posts"])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_match_params_are_ordered_by_index() {
+        let m = QueryMatch {
+            query: String::new(),
+            captures: vec![],
+            variables: json!({"_arg10": "j", "_arg2": "b", "_arg1": "a", "other": 0}),
+        };
+        assert_eq!(m.params(), vec![&json!("a"), &json!("b"), &json!("j")]);
+    }
+
     #[test]
     fn test_exact_matching_only() {
         let engine = MockSurrealEngine::new()
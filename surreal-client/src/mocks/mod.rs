@@ -46,6 +46,20 @@
 //!     .build();
 //! ```
 //!
+//! ### Pattern Matching
+//!
+//! ```rust
+//! use surreal_client::mocks::SurrealMockBuilder;
+//! use serde_json::json;
+//!
+//! // Any `SELECT * FROM <table>`; the handler sees captures and bound params
+//! let client = SurrealMockBuilder::new()
+//!     .on_pattern(r"SELECT \* FROM (\w+)", |m| {
+//!         json!([{"status": "OK", "result": [{"table": m.capture(1)}]}])
+//!     })
+//!     .build();
+//! ```
+//!
 //! ## Error Handling
 //!
 //! When a request doesn't match any configured pattern, the mock will panic with a descriptive error:
@@ -59,4 +73,4 @@
 
 pub mod engine;
 
-pub use engine::{MockSurrealEngine, SurrealMockBuilder};
+pub use engine::{CborLog, MockSurrealEngine, QueryMatch, SurrealMockBuilder};
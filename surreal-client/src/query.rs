@@ -0,0 +1,215 @@
+//! Parameterized query builder
+//!
+//! [`QueryBuilder`] assembles SurrealQL from fixed statement templates and
+//! named `$parameters`, so values never get spliced into the query text:
+//!
+//! ```rust,ignore
+//! let (sql, params) = QueryBuilder::new()
+//!     .stmt("SELECT * FROM $rec WHERE total > $min")
+//!     .bind("rec", RecordId::string("order", "o1"))
+//!     .bind("min", 100)
+//!     .build()?;
+//! client.query_cbor(&sql, params).await?;
+//! ```
+//!
+//! [`QueryBuilder::build`] fails if a template references a parameter that
+//! was never bound. Variables defined by an earlier `LET $name = …`
+//! statement, SurrealDB's own variables (`$this`, `$parent`, `$auth`, …)
+//! and anything inside string literals are not treated as placeholders.
+
+use ciborium::Value as CborValue;
+use indexmap::IndexMap;
+
+use crate::error::{Result, SurrealError};
+use crate::record::{RecordId, RecordIdValue};
+
+/// Variables SurrealDB defines itself; a template may use them unbound.
+const RESERVED_PARAMS: &[&str] = &[
+    "access", "after", "auth", "before", "event", "input", "parent", "session", "this", "token",
+    "value",
+];
+
+/// Builder for a parameterized SurrealQL query, see the [module docs](self).
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    statements: Vec<String>,
+    params: IndexMap<String, CborValue>,
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a statement. Statements are joined with `; `.
+    pub fn stmt(mut self, template: impl Into<String>) -> Self {
+        self.statements.push(template.into());
+        self
+    }
+
+    /// Bind `$name` to `value`. Binding the same name again replaces it.
+    pub fn bind(mut self, name: impl Into<String>, value: impl Into<CborValue>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// The query text and its CBOR parameter map, ready for
+    /// [`SurrealClient::query_cbor`](crate::SurrealClient::query_cbor).
+    pub fn build(self) -> Result<(String, CborValue)> {
+        let defined: Vec<String> = self
+            .statements
+            .iter()
+            .filter_map(|s| let_target(s))
+            .collect();
+        let query = self.statements.join("; ");
+        if let Some(name) = placeholders(&query).into_iter().find(|name| {
+            !self.params.contains_key(name)
+                && !defined.contains(name)
+                && !RESERVED_PARAMS.contains(&name.as_str())
+        }) {
+            return Err(SurrealError::Query(format!(
+                "placeholder ${} is not bound",
+                name
+            )));
+        }
+        let params = self
+            .params
+            .into_iter()
+            .map(|(name, value)| (CborValue::Text(name), value))
+            .collect();
+        Ok((query, CborValue::Map(params)))
+    }
+}
+
+/// The variable a `LET $name = …` statement defines.
+fn let_target(statement: &str) -> Option<String> {
+    let rest = statement.trim_start();
+    if !rest.get(..3)?.eq_ignore_ascii_case("let") {
+        return None;
+    }
+    let name = rest[3..].trim_start().strip_prefix('$')?;
+    let end = name
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(name.len());
+    (end > 0).then(|| name[..end].to_string())
+}
+
+/// `$name` references in `query`, skipping quoted string literals.
+fn placeholders(query: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut chars = query.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                // Skip to the closing quote, honouring backslash escapes
+                while let Some(next) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+            '$' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_alphanumeric() || next == '_' {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if !name.is_empty() {
+                    names.push(name);
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+/// A record id binds as SurrealDB's record-id tag, `Tag(8, [table, id])`, so
+/// the server sees a record link rather than a `"table:id"` string.
+impl From<RecordId> for CborValue {
+    fn from(record: RecordId) -> Self {
+        let id = match record.id {
+            RecordIdValue::String(s) => CborValue::Text(s),
+            RecordIdValue::Number(n) => CborValue::Integer(n.into()),
+            RecordIdValue::Object(v) => crate::cbor_convert::json_to_cbor(v),
+            RecordIdValue::Array(items) => CborValue::Array(
+                items
+                    .into_iter()
+                    .map(crate::cbor_convert::json_to_cbor)
+                    .collect(),
+            ),
+        };
+        CborValue::Tag(
+            8,
+            Box::new(CborValue::Array(vec![CborValue::Text(record.table), id])),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fully_bound_query() {
+        let (query, params) = QueryBuilder::new()
+            .stmt("LET $cutoff = time::now() - 1w")
+            .stmt("SELECT * FROM type::table($tb) WHERE created_at > $cutoff AND status = $status")
+            .bind("tb", "order")
+            .bind("status", "open")
+            .build()
+            .unwrap();
+        assert_eq!(
+            query,
+            "LET $cutoff = time::now() - 1w; \
+             SELECT * FROM type::table($tb) WHERE created_at > $cutoff AND status = $status"
+        );
+        assert_eq!(
+            params,
+            CborValue::Map(vec![
+                ("tb".into(), "order".into()),
+                ("status".into(), "open".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_unbound_placeholder_is_rejected() {
+        let err = QueryBuilder::new()
+            .stmt("SELECT * FROM user WHERE age > $min AND name = '$literal' AND $this.active")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Query error: placeholder $min is not bound"
+        );
+    }
+
+    #[test]
+    fn test_record_id_binds_as_record_tag() {
+        let (_, params) = QueryBuilder::new()
+            .stmt("SELECT * FROM $rec")
+            .bind("rec", RecordId::string("user", "alice"))
+            .build()
+            .unwrap();
+        let CborValue::Map(entries) = params else {
+            panic!("expected a parameter map");
+        };
+        assert_eq!(
+            entries,
+            vec![(
+                "rec".into(),
+                CborValue::Tag(
+                    8,
+                    Box::new(CborValue::Array(vec!["user".into(), "alice".into()]))
+                )
+            )]
+        );
+    }
+}
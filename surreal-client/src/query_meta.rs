@@ -0,0 +1,109 @@
+//! Server-reported statement metadata
+//!
+//! Each statement in a `query` response carries `status` (`"OK"` or
+//! `"ERR"`) and `time`, the server's execution time formatted like Rust's
+//! `Duration` debug output (`"1.53ms"`, `"812.4µs"`, `"2s"`).
+//! [`SurrealClient::query_with_meta`](crate::SurrealClient::query_with_meta)
+//! collects them into a [`QueryMeta`].
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+/// Timing and status of a query, see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryMeta {
+    /// Sum of the statements' server-side execution times.
+    pub execution_time: Duration,
+    /// `"OK"` if every statement succeeded, otherwise the first other status.
+    pub status: String,
+    /// Execution time of each statement, in order. A statement whose time
+    /// is missing or unreadable counts as zero.
+    pub statement_times: Vec<Duration>,
+}
+
+impl QueryMeta {
+    pub(crate) fn from_response(response: &Value) -> Self {
+        let statements = match response {
+            Value::Array(statements) => statements.as_slice(),
+            _ => &[],
+        };
+
+        let statement_times: Vec<Duration> = statements
+            .iter()
+            .map(|s| {
+                s.get("time")
+                    .and_then(Value::as_str)
+                    .and_then(parse_server_duration)
+                    .unwrap_or_default()
+            })
+            .collect();
+        let status = statements
+            .iter()
+            .filter_map(|s| s.get("status").and_then(Value::as_str))
+            .find(|status| *status != "OK")
+            .unwrap_or("OK")
+            .to_string();
+
+        Self {
+            execution_time: statement_times.iter().sum(),
+            status,
+            statement_times,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == "OK"
+    }
+}
+
+/// Parse a `Duration` debug string as SurrealDB reports it: a decimal
+/// number followed by `ns`, `µs`/`us`, `ms` or `s`.
+fn parse_server_duration(time: &str) -> Option<Duration> {
+    let split = time.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let (number, unit) = time.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let seconds = match unit {
+        "ns" => number / 1e9,
+        "µs" | "us" => number / 1e6,
+        "ms" => number / 1e3,
+        "s" => number,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_server_duration() {
+        assert_eq!(
+            parse_server_duration("1.5ms"),
+            Some(Duration::from_micros(1500))
+        );
+        assert_eq!(
+            parse_server_duration("812µs"),
+            Some(Duration::from_micros(812))
+        );
+        assert_eq!(parse_server_duration("2s"), Some(Duration::from_secs(2)));
+        assert_eq!(
+            parse_server_duration("90ns"),
+            Some(Duration::from_nanos(90))
+        );
+        assert_eq!(parse_server_duration("soon"), None);
+    }
+
+    #[test]
+    fn test_meta_from_response() {
+        let meta = QueryMeta::from_response(&json!([
+            {"status": "OK", "time": "1ms", "result": []},
+            {"status": "ERR", "time": "500µs", "result": "boom"}
+        ]));
+        assert_eq!(meta.execution_time, Duration::from_micros(1500));
+        assert_eq!(meta.status, "ERR");
+        assert!(!meta.is_ok());
+    }
+}
@@ -48,34 +48,21 @@ impl RecordId {
         Self::new(table, RecordIdValue::Array(id))
     }
 
-    /// Parse a record ID from a string format "table:id"
+    /// Parse a record ID from its `table:id` form, splitting on the first `:`.
+    ///
+    /// The table must be a plain identifier or `⟨…⟩`-quoted. The id may be an
+    /// integer, a plain identifier, a `⟨…⟩`-quoted string, or a JSON array /
+    /// object (`user:[1, "a"]`). This accepts everything [`Self::to_surql`]
+    /// renders, so the two round-trip. Ranges (`user:1..5`) are rejected with
+    /// a pointer to [`RecordRange::parse`].
     pub fn parse(input: &str) -> Result<Self, RecordParseError> {
-        let parts: Vec<&str> = input.splitn(2, ':').collect();
-        if parts.len() != 2 {
-            return Err(RecordParseError::InvalidFormat);
-        }
-
-        let table = parts[0].to_string();
-        let id_str = parts[1];
-
-        // Try to parse as number first
-        if let Ok(num) = id_str.parse::<i64>() {
-            return Ok(Self::number(table, num));
-        }
+        let (table, id_str) = split_record(input)?;
 
-        // Try to parse as JSON object/array
-        if (id_str.starts_with('{') || id_str.starts_with('['))
-            && let Ok(value) = serde_json::from_str::<Value>(id_str)
-        {
-            match value {
-                Value::Object(_) => return Ok(Self::object(table, value)),
-                Value::Array(arr) => return Ok(Self::array(table, arr)),
-                _ => {}
-            }
+        if id_str.contains("..") && !id_str.starts_with(['⟨', '[', '{']) {
+            return Err(RecordParseError::Range(input.to_string()));
         }
 
-        // Default to string
-        Ok(Self::string(table, id_str))
+        Ok(Self::new(table, parse_id_value(id_str)?))
     }
 
     /// Convert to SurrealQL string representation
@@ -331,24 +318,181 @@ impl fmt::Display for RecordRange {
     }
 }
 
+impl RecordRange {
+    /// Parse a numeric range in the form [`Self::to_surql`] renders:
+    /// `table:start..end`, either bound optional, `>` before the start for an
+    /// exclusive start and `=` before the end for an exclusive end.
+    pub fn parse(input: &str) -> Result<Self, RecordParseError> {
+        let (table, bounds) = split_record(input)?;
+        let (start, end) = bounds
+            .split_once("..")
+            .ok_or_else(|| RecordParseError::InvalidId(format!("`{}` is not a range", bounds)))?;
+
+        let mut range = RecordRange::new(table);
+        if !start.is_empty() {
+            let (inclusive, digits) = match start.strip_prefix('>') {
+                Some(rest) => (false, rest),
+                None => (true, start),
+            };
+            range = range.start(parse_range_bound(digits)?, inclusive);
+        }
+        if !end.is_empty() {
+            let (inclusive, digits) = match end.strip_prefix('=') {
+                Some(rest) => (false, rest),
+                None => (true, end),
+            };
+            range = range.end(parse_range_bound(digits)?, inclusive);
+        }
+        Ok(range)
+    }
+}
+
+fn parse_range_bound(bound: &str) -> Result<i64, RecordParseError> {
+    bound.parse().map_err(|_| {
+        RecordParseError::InvalidId(format!("range bound `{}` is not an integer", bound))
+    })
+}
+
+/// Split `table:rest` on the first `:` and validate the table part. A `:`
+/// inside a `⟨…⟩`-quoted table doesn't count.
+fn split_record(input: &str) -> Result<(String, &str), RecordParseError> {
+    let split_at = if input.starts_with('⟨') {
+        let close = input
+            .find('⟩')
+            .ok_or_else(|| RecordParseError::InvalidTable(input.to_string()))?;
+        let after = close + '⟩'.len_utf8();
+        if !input[after..].starts_with(':') {
+            return Err(RecordParseError::MissingColon(input.to_string()));
+        }
+        after
+    } else {
+        input
+            .find(':')
+            .ok_or_else(|| RecordParseError::MissingColon(input.to_string()))?
+    };
+
+    let (table, id) = (&input[..split_at], &input[split_at + 1..]);
+    if table.is_empty() {
+        return Err(RecordParseError::EmptyTable);
+    }
+    if id.is_empty() {
+        return Err(RecordParseError::EmptyId);
+    }
+    let table = match unquote_identifier(table) {
+        Some(name) if !name.is_empty() => name,
+        Some(_) => return Err(RecordParseError::EmptyTable),
+        None if is_bare_identifier(table) && !table.starts_with(|c: char| c.is_ascii_digit()) => {
+            table.to_string()
+        }
+        None => return Err(RecordParseError::InvalidTable(table.to_string())),
+    };
+    Ok((table, id))
+}
+
+fn parse_id_value(id: &str) -> Result<RecordIdValue, RecordParseError> {
+    if let Ok(num) = id.parse::<i64>() {
+        return Ok(RecordIdValue::Number(num));
+    }
+    if let Some(name) = unquote_identifier(id) {
+        return Ok(RecordIdValue::String(name));
+    }
+    if id.starts_with('[') || id.starts_with('{') {
+        return match serde_json::from_str::<Value>(id) {
+            Ok(Value::Array(arr)) => Ok(RecordIdValue::Array(arr)),
+            Ok(value @ Value::Object(_)) => Ok(RecordIdValue::Object(value)),
+            _ => Err(RecordParseError::InvalidId(format!(
+                "`{}` is not a valid array or object id",
+                id
+            ))),
+        };
+    }
+    if is_bare_identifier(id) {
+        return Ok(RecordIdValue::String(id.to_string()));
+    }
+    Err(RecordParseError::InvalidId(format!(
+        "`{}` must be a number, an identifier, ⟨quoted⟩, or an array/object",
+        id
+    )))
+}
+
+fn is_bare_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Reverse of [`escape_identifier`] for a `⟨…⟩`-quoted identifier; `None` if
+/// `s` isn't quoted.
+fn unquote_identifier(s: &str) -> Option<String> {
+    let inner = s.strip_prefix('⟨')?.strip_suffix('⟩')?;
+    let mut out = String::with_capacity(inner.len());
+    let mut rest = inner;
+    while let Some(pos) = rest.find('\\') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if let Some(after) = rest.strip_prefix("\\u{27E9}") {
+            out.push('⟩');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("\\\\") {
+            out.push('\\');
+            rest = after;
+        } else {
+            out.push('\\');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
 /// Error type for record ID parsing
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RecordParseError {
-    InvalidFormat,
-    InvalidId,
+    /// No `:` separating table and id.
+    MissingColon(String),
+    /// Nothing before the `:`.
+    EmptyTable,
+    /// The table part is not a valid identifier.
+    InvalidTable(String),
+    /// Nothing after the `:`.
+    EmptyId,
+    /// The id part could not be parsed; carries the reason.
+    InvalidId(String),
+    /// A range was given where a single record id is expected.
+    Range(String),
 }
 
 impl fmt::Display for RecordParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            RecordParseError::InvalidFormat => write!(f, "Invalid record ID format"),
-            RecordParseError::InvalidId => write!(f, "Invalid record ID value"),
+            RecordParseError::MissingColon(input) => {
+                write!(f, "Invalid record ID `{}`: expected `table:id`", input)
+            }
+            RecordParseError::EmptyTable => write!(f, "Invalid record ID: table name is empty"),
+            RecordParseError::InvalidTable(table) => {
+                write!(
+                    f,
+                    "Invalid record ID: `{}` is not a valid table name",
+                    table
+                )
+            }
+            RecordParseError::EmptyId => write!(f, "Invalid record ID: id is empty"),
+            RecordParseError::InvalidId(reason) => write!(f, "Invalid record ID value: {}", reason),
+            RecordParseError::Range(input) => write!(
+                f,
+                "`{}` is a record range, not a record ID; use RecordRange::parse",
+                input
+            ),
         }
     }
 }
 
 impl std::error::Error for RecordParseError {}
 
+impl From<RecordParseError> for crate::SurrealError {
+    fn from(err: RecordParseError) -> Self {
+        crate::SurrealError::Serialization(err.to_string())
+    }
+}
+
 /// SurrealQL keywords that must be escaped when used as bare identifiers,
 /// even though they are otherwise valid identifier characters.
 const RESERVED_KEYWORDS: &[&str] = &[
@@ -416,6 +560,88 @@ mod tests {
         assert_eq!(record.id, RecordIdValue::String("john".to_string()));
     }
 
+    #[test]
+    fn test_record_id_parse_quoted_and_array() {
+        let record = RecordId::parse("user:⟨john doe⟩").unwrap();
+        assert_eq!(record.id, RecordIdValue::String("john doe".to_string()));
+
+        let record = RecordId::parse("⟨my table⟩:1").unwrap();
+        assert_eq!(record.table, "my table");
+        assert_eq!(record.id, RecordIdValue::Number(1));
+
+        let record = RecordId::parse(r#"temp:["london", 2026]"#).unwrap();
+        assert_eq!(
+            record.id,
+            RecordIdValue::Array(vec![Value::from("london"), Value::from(2026)])
+        );
+
+        let record = RecordId::parse(r#"user:{"org": 7}"#).unwrap();
+        assert_eq!(
+            record.id,
+            RecordIdValue::Object(serde_json::json!({"org": 7}))
+        );
+
+        // Whatever to_surql renders parses back to the same id
+        for original in [
+            RecordId::string("user", "a⟩b\\c"),
+            RecordId::string("select", "from"),
+            RecordId::array("temp", vec![Value::from("x"), Value::from(1)]),
+            RecordId::number("user", -5),
+        ] {
+            assert_eq!(RecordId::parse(&original.to_surql()).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn test_record_range_parse() {
+        let range = RecordRange::parse("user:1..100").unwrap();
+        assert_eq!(range.start, Some(RecordIdValue::Number(1)));
+        assert_eq!(range.end, Some(RecordIdValue::Number(100)));
+        assert_eq!(range.to_surql(), "user:1..100");
+
+        let range = RecordRange::parse("user:>5..").unwrap();
+        assert!(!range.start_inclusive);
+        assert_eq!(range.end, None);
+        assert_eq!(range.to_surql(), "user:>5..");
+
+        assert_eq!(
+            RecordId::parse("user:1..100"),
+            Err(RecordParseError::Range("user:1..100".to_string()))
+        );
+        assert!(matches!(
+            RecordRange::parse("user:a..b"),
+            Err(RecordParseError::InvalidId(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_id_parse_invalid() {
+        assert_eq!(
+            RecordId::parse("user"),
+            Err(RecordParseError::MissingColon("user".to_string()))
+        );
+        assert_eq!(RecordId::parse(":123"), Err(RecordParseError::EmptyTable));
+        assert_eq!(RecordId::parse("user:"), Err(RecordParseError::EmptyId));
+        assert_eq!(
+            RecordId::parse("my-table:1"),
+            Err(RecordParseError::InvalidTable("my-table".to_string()))
+        );
+        assert!(matches!(
+            RecordId::parse("user:[1, 2"),
+            Err(RecordParseError::InvalidId(_))
+        ));
+        assert!(matches!(
+            RecordId::parse("user:john doe"),
+            Err(RecordParseError::InvalidId(_))
+        ));
+
+        let err = RecordId::parse("user").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid record ID `user`: expected `table:id`"
+        );
+    }
+
     #[test]
     fn test_record_id_surql() {
         let record = RecordId::string("user", "john");
@@ -36,7 +36,7 @@ use crate::graphql::types::AnyGraphqlType;
 /// - `root_field` ← `table.table_name()`
 /// - `fields` ← `table.columns().keys()` (plus id field if not in the column set)
 /// - `conditions` ← `table.conditions()`
-/// - `sort` ← `table.orders()`, mapping the condition's first `Field` to a column name (mirrors Mongo's posture)
+/// - `sort` ← `table.effective_orders()`, mapping the condition's first `Field` to a column name (mirrors Mongo's posture)
 /// - `limit/skip` ← `table.pagination()`
 /// - `dialect` / `filter_arg_name` propagate from the API
 fn select_from_table<E: Entity<AnyGraphqlType>>(table: &Table<GraphqlApi, E>) -> GraphqlSelect {
@@ -68,7 +68,7 @@ fn select_from_table<E: Entity<AnyGraphqlType>>(table: &Table<GraphqlApi, E>) ->
     }
 
     // Orders: GraphqlCondition's `Field` variant carries the column name.
-    for (cond, direction) in table.orders() {
+    for (cond, direction) in table.effective_orders() {
         if let GraphqlCondition::Field(fc) = cond {
             let order = if matches!(direction, vantage_table::sorting::SortDirection::Ascending) {
                 Order::Asc
@@ -154,6 +154,17 @@ impl Selectable<AnyGraphqlType, GraphqlCondition> for GraphqlSelect {
             vec![],
         )
     }
+
+    fn as_avg(&self, column: impl Expressive<AnyGraphqlType>) -> Expression<AnyGraphqlType> {
+        Expression::new(
+            format!(
+                "{}_aggregate {{ aggregate {{ avg {{ {} }} }} }}",
+                self.root_field.clone().unwrap_or_default(),
+                column.preview()
+            ),
+            vec![],
+        )
+    }
 }
 
 impl Expressive<AnyGraphqlType> for GraphqlSelect {
@@ -517,7 +517,7 @@ fn resolve_deferreds(
                     let resolved = resolve_deferreds(inner.clone()).await?;
                     *inner = resolved;
                 }
-                ExpressiveEnum::Scalar(_) => {}
+                ExpressiveEnum::Scalar(_) | ExpressiveEnum::Raw(_) => {}
             }
         }
         Ok(expr)
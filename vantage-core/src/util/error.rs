@@ -452,6 +452,23 @@ impl<T, E: std::error::Error + Send + Sync + 'static> Context<T> for std::result
     }
 }
 
+/// Trait for annotating a failed `Result` with extra context fields as it
+/// propagates, e.g. `load(id).context_kv("id", id)?`.
+pub trait ResultExt<T> {
+    /// On error, add `key: value` to the error's context map (replacing an
+    /// existing value for the same key). `Ok` passes through untouched.
+    fn context_kv(self, key: &str, value: impl fmt::Display) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn context_kv(self, key: &str, value: impl fmt::Display) -> Result<T> {
+        self.map_err(|mut err| {
+            err.context.insert(key.to_string(), value.to_string());
+            err
+        })
+    }
+}
+
 /// From String for backward compatibility
 impl From<String> for VantageError {
     fn from(msg: String) -> Self {
@@ -548,6 +565,27 @@ mod tests {
         assert!(error_msg.contains("Failed to read file"));
     }
 
+    #[test]
+    fn test_context_kv() {
+        use super::ResultExt;
+
+        fn load(id: u64) -> Result<()> {
+            Err(crate::error!("Record not found", table = "books")).context_kv("id", id)
+        }
+
+        let err = load(42).context_kv("caller", "sync").unwrap_err();
+        assert_eq!(err.context.get("id").map(String::as_str), Some("42"));
+        assert_eq!(err.context.get("caller").map(String::as_str), Some("sync"));
+        // Inline fields from `error!` are kept, added ones follow in order
+        assert_eq!(
+            err.context.keys().collect::<Vec<_>>(),
+            vec!["table", "id", "caller"]
+        );
+
+        let ok: Result<u8> = Ok(1);
+        assert_eq!(ok.context_kv("id", 1).unwrap(), 1);
+    }
+
     #[test]
     fn test_macro() {
         let err = vantage_error!("Test error: {}", 42);
@@ -3,5 +3,5 @@
 pub mod error;
 pub mod into_vec;
 
-pub use error::{Context, ErrorKind, Result, VantageError};
+pub use error::{Context, ErrorKind, Result, ResultExt, VantageError};
 pub use into_vec::IntoVec;
@@ -106,6 +106,8 @@ pub(crate) fn resolve_param(
                     Ok(AnyCsvType::new(expr.template.clone()))
                 }
             }
+            // Raw text is a reference, like a parameterless nested expression
+            ExpressiveEnum::Raw(sql) => Ok(AnyCsvType::new(sql.clone())),
         }
     })
 }
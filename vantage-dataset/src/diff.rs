@@ -0,0 +1,143 @@
+//! Compare two value sets by id — used for sync reconciliation between a
+//! local snapshot and its remote counterpart.
+
+use std::hash::Hash;
+
+use vantage_types::Record;
+
+use crate::traits::{ReadableValueSet, Result};
+
+/// Keys whose values differ between the two versions of a record, including
+/// keys present on one side only.
+pub type ChangedFields = Vec<String>;
+
+/// Result of [`diff`], seen from the local side: `added` records exist only
+/// locally, `removed` only remotely. Ids keep the order of the set they came
+/// from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffReport<Id> {
+    pub added: Vec<Id>,
+    pub removed: Vec<Id>,
+    pub changed: Vec<(Id, ChangedFields)>,
+}
+
+impl<Id> DiffReport<Id> {
+    /// True when both sets hold the same records.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diff `local` against `remote`, matching records by id.
+pub async fn diff<L, R>(local: &L, remote: &R) -> Result<DiffReport<L::Id>>
+where
+    L: ReadableValueSet + Sync,
+    R: ReadableValueSet<Id = L::Id, Value = L::Value> + Sync,
+    L::Id: Hash + Eq,
+    L::Value: PartialEq,
+{
+    let local = local.list_values().await?;
+    let mut remote = remote.list_values().await?;
+
+    let mut report = DiffReport {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+
+    for (id, record) in local {
+        match remote.shift_remove(&id) {
+            None => report.added.push(id),
+            Some(other) => {
+                let fields = changed_fields(&record, &other);
+                if !fields.is_empty() {
+                    report.changed.push((id, fields));
+                }
+            }
+        }
+    }
+    report.removed.extend(remote.into_keys());
+
+    Ok(report)
+}
+
+fn changed_fields<V: PartialEq>(local: &Record<V>, remote: &Record<V>) -> ChangedFields {
+    let mut fields: ChangedFields = local
+        .iter()
+        .filter(|(key, value)| remote.get(*key) != Some(*value))
+        .map(|(key, _)| key.clone())
+        .collect();
+    fields.extend(
+        remote
+            .keys()
+            .filter(|key| !local.contains_key(*key))
+            .cloned(),
+    );
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::im::{ImDataSource, ImTable};
+    use crate::traits::WritableValueSet;
+    use serde_json::{Value, json};
+
+    fn record(value: Value) -> Record<Value> {
+        Record::from(value)
+    }
+
+    async fn seed(ds: &ImDataSource, name: &str, rows: Vec<(&str, Value)>) -> ImTable<()> {
+        let table = ImTable::<()>::new(ds, name);
+        for (id, value) in rows {
+            table.replace_value(id, &record(value)).await.unwrap();
+        }
+        table
+    }
+
+    #[tokio::test]
+    async fn test_diff_add_delete_change() {
+        let ds = ImDataSource::new();
+        let local = seed(
+            &ds,
+            "local",
+            vec![
+                ("a", json!({"name": "Alice", "age": 30})),
+                ("b", json!({"name": "Bob", "age": 41})),
+                ("d", json!({"name": "Dan"})),
+            ],
+        )
+        .await;
+        let remote = seed(
+            &ds,
+            "remote",
+            vec![
+                ("a", json!({"name": "Alice", "age": 30})),
+                ("b", json!({"name": "Bob", "age": 40, "email": "bob@x"})),
+                ("c", json!({"name": "Carol"})),
+            ],
+        )
+        .await;
+
+        let report = diff(&local, &remote).await.unwrap();
+        assert_eq!(report.added, vec!["d".to_string()]);
+        assert_eq!(report.removed, vec!["c".to_string()]);
+        assert_eq!(
+            report.changed,
+            vec![(
+                "b".to_string(),
+                vec!["age".to_string(), "email".to_string()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diff_identical_sets() {
+        let ds = ImDataSource::new();
+        let rows = || vec![("a", json!({"name": "Alice"}))];
+        let local = seed(&ds, "local", rows()).await;
+        let remote = seed(&ds, "remote", rows()).await;
+
+        assert!(diff(&local, &remote).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! Change notifications for in-memory tables.
+//!
+//! Every write through an [`ImTable`] handle is broadcast to the table's
+//! subscribers, so a UI bound to an in-memory dataset can refresh the same
+//! way it would for a live backend. Subscriptions are per table name and
+//! shared by all handles onto the same [`ImDataSource`].
+
+use tokio::sync::broadcast;
+
+use crate::im::{ImDataSource, ImTable};
+
+/// Buffered events per table before a slow subscriber starts lagging.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// A write to an [`ImTable`], carrying the id of the affected record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImChange {
+    Insert { id: String },
+    Update { id: String },
+    Delete { id: String },
+}
+
+impl ImChange {
+    /// `Update` if a record was replaced, `Insert` otherwise.
+    pub(super) fn stored(id: String, replaced: bool) -> Self {
+        if replaced {
+            Self::Update { id }
+        } else {
+            Self::Insert { id }
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Insert { id } | Self::Update { id } | Self::Delete { id } => id,
+        }
+    }
+}
+
+impl<V> ImDataSource<V> {
+    pub(super) fn subscribe(&self, table_name: &str) -> broadcast::Receiver<ImChange> {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .entry(table_name.to_string())
+            .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+            .subscribe()
+    }
+
+    /// Broadcast `changes` to the table's subscribers, if it has any.
+    pub(super) fn notify(&self, table_name: &str, changes: impl IntoIterator<Item = ImChange>) {
+        if let Some(sender) = self.subscribers.lock().unwrap().get(table_name) {
+            for change in changes {
+                // No receivers left is fine — nobody is listening.
+                let _ = sender.send(change);
+            }
+        }
+    }
+}
+
+impl<E, V> ImTable<E, V> {
+    /// Receive an [`ImChange`] for every insert, update and delete made to
+    /// this table from now on, through any handle.
+    pub fn subscribe(&self) -> broadcast::Receiver<ImChange> {
+        self.data_source.subscribe(&self.table_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{InsertableValueSet, WritableValueSet};
+    use serde_json::json;
+    use vantage_types::Record;
+
+    #[tokio::test]
+    async fn test_insert_update_delete_are_broadcast() {
+        let ds = ImDataSource::new();
+        let table = ImTable::<()>::new(&ds, "users");
+        let mut changes = table.subscribe();
+
+        let record = Record::from(json!({"id": "u1", "name": "Alice"}));
+        let id = table.insert_return_id_value(&record).await.unwrap();
+        assert_eq!(changes.recv().await.unwrap(), ImChange::Insert { id });
+
+        // Writes through another handle reach the same subscribers
+        let other = ImTable::<()>::new(&ds, "users");
+        other
+            .patch_value("u1", &Record::from(json!({"name": "Alicia"})))
+            .await
+            .unwrap();
+        other.delete("u1").await.unwrap();
+        let update = changes.recv().await.unwrap();
+        assert_eq!(update, ImChange::Update { id: "u1".into() });
+        assert_eq!(update.id(), "u1");
+        assert_eq!(
+            changes.recv().await.unwrap(),
+            ImChange::Delete { id: "u1".into() }
+        );
+
+        // Deleting a missing record changes nothing
+        other.delete("u1").await.unwrap();
+        ImTable::<()>::new(&ds, "orders")
+            .insert_value("o1", &record)
+            .await
+            .unwrap();
+        assert!(changes.try_recv().is_err());
+    }
+}
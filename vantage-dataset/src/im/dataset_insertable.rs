@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use vantage_types::{Entity, Record, TryIntoRecord};
 
-use crate::{im::ImTable, traits::InsertableDataSet};
+use crate::{
+    im::{ImChange, ImTable},
+    traits::InsertableDataSet,
+};
 
 #[async_trait]
 impl<E> InsertableDataSet<E> for ImTable<E>
@@ -41,8 +44,11 @@ where
         // Remove id from the stored record since it's in the key
         record.shift_remove("id");
 
+        let replaced = self.data_source.with_table_mut(&self.table_name, |table| {
+            table.insert(id.clone(), record).is_some()
+        });
         self.data_source
-            .with_table_mut(&self.table_name, |table| table.insert(id.clone(), record));
+            .notify(&self.table_name, [ImChange::stored(id.clone(), replaced)]);
 
         Ok(id)
     }
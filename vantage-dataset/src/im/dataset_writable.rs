@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use vantage_types::{Entity, Record, TryFromRecord, TryIntoRecord};
 
 use crate::{
-    im::ImTable,
+    im::{ImChange, ImTable},
     traits::{Result, WritableDataSet},
 };
 
@@ -15,34 +15,43 @@ where
 {
     async fn insert(&self, id: impl Into<Self::Id> + Send, entity: &E) -> Result<E> {
         let id = id.into();
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            // Check if record already exists (idempotent behavior)
-            if let Some(existing_record) = table.get(&id) {
-                // Return existing entity; add the id field back for conversion
-                let mut record_with_id = existing_record.clone();
-                record_with_id.insert("id".to_string(), serde_json::Value::String(id.clone()));
-
-                return E::try_from_record(&record_with_id).map_err(|e| {
-                    vantage_core::util::error::vantage_error!(
-                        "Failed to convert record to entity: {:?}",
-                        e
-                    )
-                });
-            }
+        let (stored, inserted) =
+            self.data_source
+                .with_table_mut(&self.table_name, |table| -> Result<_> {
+                    // Check if record already exists (idempotent behavior)
+                    if let Some(existing_record) = table.get(&id) {
+                        // Return existing entity; add the id field back for conversion
+                        let mut record_with_id = existing_record.clone();
+                        record_with_id
+                            .insert("id".to_string(), serde_json::Value::String(id.clone()));
 
-            // Convert entity to record for storage (remove id field since it's in the key)
-            let mut record: Record<serde_json::Value> =
-                entity.clone().try_into_record().map_err(|e| {
-                    vantage_core::util::error::vantage_error!(
-                        "Failed to serialize entity to record: {:?}",
-                        e
-                    )
-                })?;
-            record.shift_remove("id");
+                        let existing = E::try_from_record(&record_with_id).map_err(|e| {
+                            vantage_core::util::error::vantage_error!(
+                                "Failed to convert record to entity: {:?}",
+                                e
+                            )
+                        })?;
+                        return Ok((existing, false));
+                    }
+
+                    // Convert entity to record for storage (remove id field since it's in the key)
+                    let mut record: Record<serde_json::Value> =
+                        entity.clone().try_into_record().map_err(|e| {
+                            vantage_core::util::error::vantage_error!(
+                                "Failed to serialize entity to record: {:?}",
+                                e
+                            )
+                        })?;
+                    record.shift_remove("id");
 
-            table.insert(id.clone(), record);
-            Ok(entity.clone())
-        })
+                    table.insert(id.clone(), record);
+                    Ok((entity.clone(), true))
+                })?;
+        if inserted {
+            self.data_source
+                .notify(&self.table_name, [ImChange::Insert { id }]);
+        }
+        Ok(stored)
     }
 
     async fn replace(&self, id: impl Into<Self::Id> + Send, entity: &E) -> Result<E> {
@@ -57,9 +66,11 @@ where
             })?;
         record.shift_remove("id");
 
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            table.insert(id.clone(), record);
+        let replaced = self.data_source.with_table_mut(&self.table_name, |table| {
+            table.insert(id.clone(), record).is_some()
         });
+        self.data_source
+            .notify(&self.table_name, [ImChange::stored(id, replaced)]);
 
         Ok(entity.clone())
     }
@@ -75,7 +86,7 @@ where
                 )
             })?;
 
-        self.data_source.with_table_mut(&self.table_name, |table| {
+        let patched = self.data_source.with_table_mut(&self.table_name, |table| {
             // Check if record exists
             let mut existing_record = table
                 .get(&id)
@@ -96,7 +107,7 @@ where
 
             // Return the merged entity
             let mut record_with_id = existing_record;
-            record_with_id.insert("id".to_string(), serde_json::Value::String(id));
+            record_with_id.insert("id".to_string(), serde_json::Value::String(id.clone()));
 
             E::try_from_record(&record_with_id).map_err(|e| {
                 vantage_core::util::error::vantage_error!(
@@ -104,7 +115,11 @@ where
                     e
                 )
             })
-        })
+        })?;
+        self.data_source
+            .notify(&self.table_name, [ImChange::Update { id }]);
+
+        Ok(patched)
     }
 }
 
@@ -2,7 +2,10 @@ use indexmap::IndexMap;
 use uuid::Uuid;
 use vantage_types::Record;
 
-use crate::{im::ImDataSource, traits::ValueSet};
+use crate::{
+    im::{ImChange, ImDataSource},
+    traits::ValueSet,
+};
 
 /// Typed handle into one named table inside an [`ImDataSource`].
 ///
@@ -37,13 +40,26 @@ impl<E, V> ImTable<E, V> {
     /// Synchronously replace this table's rows with `records` (ordered).
     /// Lets callers seed an in-memory table from a known collection without
     /// going through the async insert path — used to materialize a contained
-    /// relation's records from a parent row's embedded column.
+    /// relation's records from a parent row's embedded column. Subscribers
+    /// see a `Delete` for each dropped row and an `Insert`/`Update` for each
+    /// seeded one.
     pub fn seed(&self, records: IndexMap<String, Record<V>>)
     where
         V: Clone,
     {
-        self.data_source
-            .with_table_mut(&self.table_name, |table| *table = records);
+        let changes: Vec<ImChange> = self.data_source.with_table_mut(&self.table_name, |table| {
+            let dropped = table
+                .keys()
+                .filter(|id| !records.contains_key(*id))
+                .map(|id| ImChange::Delete { id: id.clone() });
+            let seeded = records
+                .keys()
+                .map(|id| ImChange::stored(id.clone(), table.contains_key(id)));
+            let changes = dropped.chain(seeded).collect();
+            *table = records;
+            changes
+        });
+        self.data_source.notify(&self.table_name, changes);
     }
 }
 
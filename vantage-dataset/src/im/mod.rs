@@ -3,8 +3,10 @@
 use indexmap::IndexMap;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
 use vantage_types::Record;
 
+pub mod change;
 pub mod dataset_insertable;
 pub mod dataset_readable;
 pub mod dataset_writable;
@@ -13,11 +15,15 @@ pub mod im_table;
 pub mod valueset_insertable;
 pub mod valueset_readable;
 pub mod valueset_writable;
+pub use change::ImChange;
 pub use im_table::ImTable;
 
 /// Type alias for the complex table storage structure
 type TableStorage<V> = Arc<Mutex<HashMap<String, IndexMap<String, Record<V>>>>>;
 
+/// Per-table change broadcasters, created on first subscription
+type Subscribers = Arc<Mutex<HashMap<String, broadcast::Sender<ImChange>>>>;
+
 /// In-memory data source storing tables as nested maps, keyed first by table
 /// name then by row id. Generic over the wire value type `V` so the same
 /// storage primitive can hold `serde_json::Value` records (the original
@@ -28,12 +34,14 @@ type TableStorage<V> = Arc<Mutex<HashMap<String, IndexMap<String, Record<V>>>>>;
 pub struct ImDataSource<V = serde_json::Value> {
     // table_name -> IndexMap<id, record>
     tables: TableStorage<V>,
+    subscribers: Subscribers,
 }
 
 impl<V> Clone for ImDataSource<V> {
     fn clone(&self) -> Self {
         Self {
             tables: self.tables.clone(),
+            subscribers: self.subscribers.clone(),
         }
     }
 }
@@ -42,6 +50,7 @@ impl<V> ImDataSource<V> {
     pub fn new() -> Self {
         Self {
             tables: Arc::new(Mutex::new(HashMap::new())),
+            subscribers: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use vantage_types::Record;
 
-use crate::{im::ImTable, traits::InsertableValueSet};
+use crate::{
+    im::{ImChange, ImTable},
+    traits::InsertableValueSet,
+};
 
 /// `InsertableValueSet` is only available for `V = serde_json::Value` because
 /// it inspects the record for an `"id"` field via JSON-specific accessors
@@ -36,9 +39,11 @@ where
             self.generate_id()
         };
 
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            table.insert(id.clone(), record.clone())
+        let replaced = self.data_source.with_table_mut(&self.table_name, |table| {
+            table.insert(id.clone(), record.clone()).is_some()
         });
+        self.data_source
+            .notify(&self.table_name, [ImChange::stored(id.clone(), replaced)]);
 
         Ok(id)
     }
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use vantage_types::Record;
 
-use crate::{im::ImTable, traits::WritableValueSet};
+use crate::{
+    im::{ImChange, ImTable},
+    traits::WritableValueSet,
+};
 
 #[async_trait]
 impl<E, V> WritableValueSet for ImTable<E, V>
@@ -15,15 +18,19 @@ where
         record: &Record<Self::Value>,
     ) -> crate::traits::Result<Record<Self::Value>> {
         let id = id.into();
-        let stored = self.data_source.with_table_mut(&self.table_name, |table| {
+        let (stored, inserted) = self.data_source.with_table_mut(&self.table_name, |table| {
             // Check if record already exists (idempotent behavior)
             if let Some(existing_record) = table.get(&id) {
-                return existing_record.clone();
+                return (existing_record.clone(), false);
             }
 
             table.insert(id.clone(), record.clone());
-            record.clone()
+            (record.clone(), true)
         });
+        if inserted {
+            self.data_source
+                .notify(&self.table_name, [ImChange::Insert { id }]);
+        }
 
         Ok(stored)
     }
@@ -34,9 +41,11 @@ where
         record: &Record<Self::Value>,
     ) -> crate::traits::Result<Record<Self::Value>> {
         let id = id.into();
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            table.insert(id.clone(), record.clone());
+        let replaced = self.data_source.with_table_mut(&self.table_name, |table| {
+            table.insert(id.clone(), record.clone()).is_some()
         });
+        self.data_source
+            .notify(&self.table_name, [ImChange::stored(id, replaced)]);
 
         Ok(record.clone())
     }
@@ -47,38 +56,57 @@ where
         partial: &Record<Self::Value>,
     ) -> crate::traits::Result<Record<Self::Value>> {
         let id = id.into();
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            // Check if record exists
-            let mut existing_record = table
-                .get(&id)
-                .ok_or_else(|| {
-                    vantage_core::util::error::vantage_error!("Record with id '{}' not found", id)
-                })?
-                .clone();
-
-            // Merge the partial fields into the existing record
-            for (key, value) in partial.iter() {
-                existing_record.insert(key.clone(), value.clone());
-            }
-
-            table.insert(id.clone(), existing_record.clone());
+        let patched = self.data_source.with_table_mut(
+            &self.table_name,
+            |table| -> crate::traits::Result<_> {
+                // Check if record exists
+                let mut existing_record = table
+                    .get(&id)
+                    .ok_or_else(|| {
+                        vantage_core::util::error::vantage_error!(
+                            "Record with id '{}' not found",
+                            id
+                        )
+                    })?
+                    .clone();
+
+                // Merge the partial fields into the existing record
+                for (key, value) in partial.iter() {
+                    existing_record.insert(key.clone(), value.clone());
+                }
+
+                table.insert(id.clone(), existing_record.clone());
+
+                Ok(existing_record)
+            },
+        )?;
+        self.data_source
+            .notify(&self.table_name, [ImChange::Update { id }]);
 
-            Ok(existing_record)
-        })
+        Ok(patched)
     }
 
     async fn delete(&self, id: impl Into<Self::Id> + Send) -> crate::traits::Result<()> {
         let id = id.into();
         // Delete is idempotent - success even if record doesn't exist
-        self.data_source.with_table_mut(&self.table_name, |table| {
-            table.shift_remove(&id);
-        });
+        let removed = self
+            .data_source
+            .with_table_mut(&self.table_name, |table| table.shift_remove(&id).is_some());
+        if removed {
+            self.data_source
+                .notify(&self.table_name, [ImChange::Delete { id }]);
+        }
         Ok(())
     }
 
     async fn delete_all(&self) -> crate::traits::Result<()> {
-        self.data_source
-            .with_table_mut(&self.table_name, |table| table.clear());
+        let removed: Vec<String> = self.data_source.with_table_mut(&self.table_name, |table| {
+            table.drain(..).map(|(id, _)| id).collect()
+        });
+        self.data_source.notify(
+            &self.table_name,
+            removed.into_iter().map(|id| ImChange::Delete { id }),
+        );
         Ok(())
     }
 }
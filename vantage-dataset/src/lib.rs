@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 
+pub mod diff;
 pub mod record;
 pub mod traits;
 
@@ -7,7 +8,8 @@ pub mod im;
 pub mod mocks;
 pub mod prelude;
 
-pub use im::{ImDataSource, ImTable};
+pub use diff::{ChangedFields, DiffReport, diff};
+pub use im::{ImChange, ImDataSource, ImTable};
 pub use mocks::csv::{AnyCsvType, CsvType, CsvTypePersistence};
 pub use record::ActiveEntity;
 pub use traits::{
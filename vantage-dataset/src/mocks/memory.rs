@@ -0,0 +1,317 @@
+//! Memory-backed dataset mock with failure injection
+//!
+//! [`MemoryDataSet`] implements every dataset and valueset trait over a
+//! private [`ImTable`], so code written against the traits can be tested
+//! without a database. [`MemoryDataSet::fail_next`] makes the next call of a
+//! given kind return an error, for exercising error paths.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use vantage_core::util::error::vantage_error;
+use vantage_types::{Entity, Record, TryFromRecord, TryIntoRecord};
+
+use crate::im::{ImDataSource, ImTable};
+use crate::traits::{
+    DataSet, InsertableDataSet, InsertableValueSet, ReadableDataSet, ReadableValueSet, Result,
+    ValueSet, WritableDataSet, WritableValueSet,
+};
+
+/// Kind of call that [`MemoryDataSet::fail_next`] can make fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockOperation {
+    /// `list`, `get`, `get_some` and their value forms
+    Read,
+    /// `insert`, `insert_return_id` and their value forms
+    Insert,
+    Replace,
+    Patch,
+    /// `delete` and `delete_all`
+    Delete,
+}
+
+/// In-memory dataset of `E` with injectable failures.
+///
+/// Clones share the same rows and pending failures.
+pub struct MemoryDataSet<E> {
+    data_source: ImDataSource,
+    failures: Arc<Mutex<Vec<MockOperation>>>,
+    _phantom: std::marker::PhantomData<E>,
+}
+
+impl<E> MemoryDataSet<E> {
+    pub fn new() -> Self {
+        Self {
+            data_source: ImDataSource::new(),
+            failures: Arc::new(Mutex::new(Vec::new())),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Make the next `operation` call fail instead of touching the data.
+    /// Calling it several times queues one failure per call.
+    pub fn fail_next(&self, operation: MockOperation) {
+        self.failures.lock().unwrap().push(operation);
+    }
+
+    /// Number of stored records.
+    pub fn len(&self) -> usize {
+        self.data_source.table_len("memory")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn table(&self) -> ImTable<E> {
+        ImTable::new(&self.data_source, "memory")
+    }
+
+    /// Consume a pending failure for `operation`, if there is one.
+    fn check(&self, operation: MockOperation) -> Result<()> {
+        let mut failures = self.failures.lock().unwrap();
+        match failures.iter().position(|f| *f == operation) {
+            Some(pos) => {
+                failures.remove(pos);
+                Err(vantage_error!("Injected {:?} failure", operation))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E> Default for MemoryDataSet<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for MemoryDataSet<E> {
+    fn clone(&self) -> Self {
+        Self {
+            data_source: self.data_source.clone(),
+            failures: self.failures.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<E: Send + Sync> ValueSet for MemoryDataSet<E> {
+    type Id = String;
+    type Value = serde_json::Value;
+}
+
+#[async_trait]
+impl<E: Send + Sync> ReadableValueSet for MemoryDataSet<E> {
+    async fn list_values(&self) -> Result<IndexMap<Self::Id, Record<Self::Value>>> {
+        self.check(MockOperation::Read)?;
+        self.table().list_values().await
+    }
+
+    async fn get_value(
+        &self,
+        id: impl Into<Self::Id> + Send,
+    ) -> Result<Option<Record<Self::Value>>> {
+        self.check(MockOperation::Read)?;
+        self.table().get_value(id).await
+    }
+
+    async fn get_some_value(&self) -> Result<Option<(Self::Id, Record<Self::Value>)>> {
+        self.check(MockOperation::Read)?;
+        self.table().get_some_value().await
+    }
+}
+
+#[async_trait]
+impl<E: Send + Sync> WritableValueSet for MemoryDataSet<E> {
+    async fn insert_value(
+        &self,
+        id: impl Into<Self::Id> + Send,
+        record: &Record<Self::Value>,
+    ) -> Result<Record<Self::Value>> {
+        self.check(MockOperation::Insert)?;
+        self.table().insert_value(id, record).await
+    }
+
+    async fn replace_value(
+        &self,
+        id: impl Into<Self::Id> + Send,
+        record: &Record<Self::Value>,
+    ) -> Result<Record<Self::Value>> {
+        self.check(MockOperation::Replace)?;
+        self.table().replace_value(id, record).await
+    }
+
+    async fn patch_value(
+        &self,
+        id: impl Into<Self::Id> + Send,
+        partial: &Record<Self::Value>,
+    ) -> Result<Record<Self::Value>> {
+        self.check(MockOperation::Patch)?;
+        self.table().patch_value(id, partial).await
+    }
+
+    async fn delete(&self, id: impl Into<Self::Id> + Send) -> Result<()> {
+        self.check(MockOperation::Delete)?;
+        WritableValueSet::delete(&self.table(), id).await
+    }
+
+    async fn delete_all(&self) -> Result<()> {
+        self.check(MockOperation::Delete)?;
+        self.table().delete_all().await
+    }
+}
+
+#[async_trait]
+impl<E: Send + Sync> InsertableValueSet for MemoryDataSet<E> {
+    async fn insert_return_id_value(&self, record: &Record<Self::Value>) -> Result<Self::Id> {
+        self.check(MockOperation::Insert)?;
+        self.table().insert_return_id_value(record).await
+    }
+}
+
+#[async_trait]
+impl<E: Entity> DataSet<E> for MemoryDataSet<E> {}
+
+#[async_trait]
+impl<E> ReadableDataSet<E> for MemoryDataSet<E>
+where
+    E: Entity,
+    <E as TryFromRecord<serde_json::Value>>::Error: std::fmt::Debug,
+{
+    async fn list(&self) -> Result<IndexMap<Self::Id, E>> {
+        self.check(MockOperation::Read)?;
+        self.table().list().await
+    }
+
+    async fn get(&self, id: impl Into<Self::Id> + Send) -> Result<Option<E>> {
+        self.check(MockOperation::Read)?;
+        self.table().get(id).await
+    }
+
+    async fn get_some(&self) -> Result<Option<(Self::Id, E)>> {
+        self.check(MockOperation::Read)?;
+        self.table().get_some().await
+    }
+}
+
+#[async_trait]
+impl<E> WritableDataSet<E> for MemoryDataSet<E>
+where
+    E: Entity + Clone + Send + Sync,
+    <E as TryFromRecord<serde_json::Value>>::Error: std::fmt::Debug,
+    <E as TryIntoRecord<serde_json::Value>>::Error: std::fmt::Debug,
+{
+    async fn insert(&self, id: impl Into<Self::Id> + Send, entity: &E) -> Result<E> {
+        self.check(MockOperation::Insert)?;
+        self.table().insert(id, entity).await
+    }
+
+    async fn replace(&self, id: impl Into<Self::Id> + Send, entity: &E) -> Result<E> {
+        self.check(MockOperation::Replace)?;
+        self.table().replace(id, entity).await
+    }
+
+    async fn patch(&self, id: impl Into<Self::Id> + Send, partial: &E) -> Result<E> {
+        self.check(MockOperation::Patch)?;
+        self.table().patch(id, partial).await
+    }
+}
+
+#[async_trait]
+impl<E> InsertableDataSet<E> for MemoryDataSet<E>
+where
+    E: Entity,
+    <E as TryIntoRecord<serde_json::Value>>::Error: std::fmt::Debug,
+{
+    async fn insert_return_id(&self, entity: &E) -> Result<Self::Id> {
+        self.check(MockOperation::Insert)?;
+        self.table().insert_return_id(entity).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Traveler {
+        name: String,
+        year: i64,
+    }
+
+    fn marty() -> Traveler {
+        Traveler {
+            name: "Marty".to_string(),
+            year: 1985,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_update_delete_read() {
+        let set = MemoryDataSet::<Traveler>::new();
+
+        set.insert("m", &marty()).await.unwrap();
+        let id = set
+            .insert_return_id(&Traveler {
+                name: "Doc".to_string(),
+                year: 1955,
+            })
+            .await
+            .unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get("m").await.unwrap(), Some(marty()));
+
+        let patched = set
+            .patch_value("m", &Record::from(serde_json::json!({"year": 2015})))
+            .await
+            .unwrap();
+        assert_eq!(patched["year"], 2015);
+        set.replace(
+            id.clone(),
+            &Traveler {
+                name: "Emmett".to_string(),
+                year: 1885,
+            },
+        )
+        .await
+        .unwrap();
+        let names: Vec<String> = set
+            .list()
+            .await
+            .unwrap()
+            .into_values()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["Marty", "Emmett"]);
+
+        ReadableValueSet::get_value(&set, "missing").await.unwrap();
+        WritableValueSet::delete(&set, "m").await.unwrap();
+        assert_eq!(set.get("m").await.unwrap(), None);
+        set.delete_all().await.unwrap();
+        assert!(set.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_injected_failure_hits_next_call_only() {
+        let set = MemoryDataSet::<Traveler>::new();
+        set.fail_next(MockOperation::Insert);
+
+        // Other operations are unaffected
+        assert!(set.list().await.unwrap().is_empty());
+
+        let err = set.insert("m", &marty()).await.unwrap_err();
+        assert!(err.to_string().contains("Injected Insert failure"));
+        assert!(set.is_empty());
+
+        set.insert("m", &marty()).await.unwrap();
+        assert_eq!(set.len(), 1);
+
+        // Clones share pending failures
+        set.clone().fail_next(MockOperation::Delete);
+        assert!(set.delete_all().await.is_err());
+        assert_eq!(set.len(), 1);
+    }
+}
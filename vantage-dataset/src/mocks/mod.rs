@@ -4,7 +4,9 @@
 //! and demonstration purposes without requiring external dependencies.
 
 pub mod csv;
+pub mod memory;
 pub mod queue;
 
 pub use csv::{CsvFile, MockCsv};
+pub use memory::{MemoryDataSet, MockOperation};
 pub use queue::{MockQueue, Topic};
@@ -14,7 +14,7 @@ pub use crate::traits::{
 //     DataSetSource, InsertableDataSetSource, ReadableDataSetSource, WritableDataSetSource,
 // };
 
-pub use crate::im::{ImDataSource, ImTable};
+pub use crate::im::{ImChange, ImDataSource, ImTable};
 
 // Record functionality
 pub use crate::record::{ActiveEntity, ActiveRecord};
@@ -0,0 +1,192 @@
+//! JSON form of an expression's structure, for caching or shipping a query
+//! to another process.
+//!
+//! [`Expression::to_ast_json`] keeps the tree as built — templates, nested
+//! expressions and scalar parameters — rather than its rendered preview, and
+//! [`Expression::from_ast_json`] rebuilds the same tree:
+//!
+//! ```rust
+//! use vantage_expressions::prelude::*;
+//! use serde_json::json;
+//!
+//! let cond = expr!("age > {}", 21);
+//! let query = expr!("SELECT * FROM users WHERE {}", (cond));
+//! let ast = query.to_ast_json();
+//! assert_eq!(
+//!     ast,
+//!     json!({"template": "SELECT * FROM users WHERE {}", "parameters": [
+//!         {"nested": {"template": "age > {}", "parameters": [{"scalar": 21}]}}
+//!     ]})
+//! );
+//!
+//! let back = Expression::<serde_json::Value>::from_ast_json(&ast).unwrap();
+//! assert_eq!(back.preview(), query.preview());
+//! ```
+//!
+//! A parameter is one of `{"scalar": value}`, `{"nested": expression}`,
+//! `{"raw": text}` or `{"deferred": null}`. A deferred parameter is a closure,
+//! so only its position survives: `from_ast_json` rejects it. Resolve
+//! deferred parameters first (see [`resolve`](crate::expression::resolve))
+//! when the tree has to travel whole.
+
+use serde_json::{Value, json};
+use vantage_core::{Result, error};
+
+use crate::expression::core::Expression;
+use crate::traits::expressive::ExpressiveEnum;
+
+impl<T: Clone + Into<Value>> Expression<T> {
+    /// Serialize this expression's tree, see the [module docs](self).
+    pub fn to_ast_json(&self) -> Value {
+        let parameters: Vec<Value> = self
+            .parameters
+            .iter()
+            .map(|param| match param {
+                ExpressiveEnum::Scalar(value) => json!({"scalar": value.clone().into()}),
+                ExpressiveEnum::Nested(inner) => json!({"nested": inner.to_ast_json()}),
+                ExpressiveEnum::Raw(sql) => json!({"raw": sql}),
+                ExpressiveEnum::Deferred(_) => json!({"deferred": null}),
+            })
+            .collect();
+        json!({"template": self.template, "parameters": parameters})
+    }
+}
+
+impl<T> Expression<T>
+where
+    T: TryFrom<Value>,
+    <T as TryFrom<Value>>::Error: std::fmt::Display,
+{
+    /// Rebuild an expression from [`to_ast_json`](Self::to_ast_json) output.
+    /// Fails on malformed input and on deferred parameters.
+    pub fn from_ast_json(ast: &Value) -> Result<Self> {
+        let node = ast
+            .as_object()
+            .ok_or_else(|| error!("AST node must be an object", node = ast.to_string()))?;
+        let template = node
+            .get("template")
+            .and_then(Value::as_str)
+            .ok_or_else(|| error!("AST node has no template", node = ast.to_string()))?;
+        let parameters = match node.get("parameters") {
+            None => Vec::new(),
+            Some(Value::Array(params)) => params
+                .iter()
+                .map(param_from_ast)
+                .collect::<Result<Vec<_>>>()?,
+            Some(other) => {
+                return Err(error!(
+                    "AST parameters must be an array",
+                    parameters = other.to_string()
+                ));
+            }
+        };
+
+        let placeholders = template.matches("{}").count();
+        if placeholders != parameters.len() {
+            return Err(error!(
+                "AST template placeholders don't match its parameters",
+                template = template,
+                placeholders = placeholders,
+                parameters = parameters.len()
+            ));
+        }
+
+        Ok(Expression::new(template, parameters))
+    }
+}
+
+fn param_from_ast<T>(param: &Value) -> Result<ExpressiveEnum<T>>
+where
+    T: TryFrom<Value>,
+    <T as TryFrom<Value>>::Error: std::fmt::Display,
+{
+    let (kind, value) = param
+        .as_object()
+        .filter(|node| node.len() == 1)
+        .and_then(|node| node.iter().next())
+        .ok_or_else(|| {
+            error!(
+                "AST parameter must have exactly one key",
+                param = param.to_string()
+            )
+        })?;
+    match kind.as_str() {
+        "scalar" => T::try_from(value.clone())
+            .map(ExpressiveEnum::Scalar)
+            .map_err(|e| {
+                error!(
+                    "AST scalar doesn't convert",
+                    value = value.to_string(),
+                    error = e.to_string()
+                )
+            }),
+        "nested" => Ok(ExpressiveEnum::Nested(Expression::from_ast_json(value)?)),
+        "raw" => value
+            .as_str()
+            .map(|sql| ExpressiveEnum::Raw(sql.to_string()))
+            .ok_or_else(|| {
+                error!(
+                    "AST raw parameter must be a string",
+                    param = param.to_string()
+                )
+            }),
+        "deferred" => Err(error!(
+            "Deferred parameters can't be rebuilt from an AST; resolve them before serializing"
+        )),
+        other => Err(error!("Unknown AST parameter kind", kind = other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::traits::expressive::DeferredFn;
+
+    #[test]
+    fn test_nested_parameterized_round_trip() {
+        let filter = expr!("age > {} AND status = {}", 21, "active");
+        let sub = expr!(
+            "SELECT id FROM orders WHERE {} AND paid = {}",
+            (filter),
+            true
+        );
+        let query = expr!(
+            "SELECT * FROM users WHERE id IN ({}) AND created < {} LIMIT {}",
+            (sub),
+            (Expression::raw("NOW()")),
+            10
+        );
+
+        let ast = query.to_ast_json();
+        let text = serde_json::to_string(&ast).unwrap();
+        let back =
+            Expression::<Value>::from_ast_json(&serde_json::from_str(&text).unwrap()).unwrap();
+
+        assert_eq!(back.preview(), query.preview());
+        assert_eq!(back.structural_hash(), query.structural_hash());
+        assert_eq!(back.to_ast_json(), ast);
+    }
+
+    #[test]
+    fn test_deferred_is_a_placeholder() {
+        let deferred = DeferredFn::from_fn(|| async { Ok(json!([1, 2])) });
+        let query = expr!("id IN {}", { deferred });
+
+        let ast = query.to_ast_json();
+        assert_eq!(
+            ast,
+            json!({"template": "id IN {}", "parameters": [{"deferred": null}]})
+        );
+        assert!(Expression::<Value>::from_ast_json(&ast).is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_ast() {
+        let missing_param = json!({"template": "a = {}", "parameters": []});
+        assert!(Expression::<Value>::from_ast_json(&missing_param).is_err());
+
+        let unknown_kind = json!({"template": "{}", "parameters": [{"lambda": 1}]});
+        assert!(Expression::<Value>::from_ast_json(&unknown_kind).is_err());
+    }
+}
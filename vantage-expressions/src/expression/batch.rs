@@ -0,0 +1,261 @@
+//! Several independent queries sent in one round-trip.
+//!
+//! [`Expression::batch`] joins queries with `; ` into a single
+//! [`BatchExpression`]. Each query stays a nested expression, so its
+//! parameters are bound in their own contiguous block after flattening
+//! ([`BatchExpression::parameter_ranges`]) and never mix with another
+//! query's. The backend's response — one result per statement — is split
+//! back with [`BatchExpression::split`].
+//!
+//! ```rust
+//! use vantage_expressions::prelude::*;
+//! use vantage_expressions::mocks::MockExprDataSource;
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let batch = Expression::batch(vec![
+//!     expr!("SELECT COUNT(*) FROM orders WHERE status = {}", "open"),
+//!     expr!("SELECT COUNT(*) FROM users"),
+//! ]);
+//! assert_eq!(
+//!     batch.preview(),
+//!     r#"SELECT COUNT(*) FROM orders WHERE status = "open"; SELECT COUNT(*) FROM users"#
+//! );
+//!
+//! let db = MockExprDataSource::new(json!([12, 40]));
+//! let results = batch.execute(&db).await.unwrap();
+//! assert_eq!(results.get(1), Some(&json!(40)));
+//! # });
+//! ```
+
+use std::fmt::{Debug, Display};
+use std::ops::Range;
+
+use vantage_core::{Result, error};
+
+use crate::expression::core::Expression;
+use crate::expression::flatten::{ExpressionFlattener, Flatten};
+use crate::traits::datasource::ExprDataSource;
+use crate::traits::expressive::Expressive;
+
+/// Backend values that can carry one result per batched statement.
+pub trait BatchResponse: Sized {
+    /// The per-statement results, or `None` if the value isn't a list.
+    fn into_statement_results(self) -> Option<Vec<Self>>;
+}
+
+impl BatchResponse for serde_json::Value {
+    fn into_statement_results(self) -> Option<Vec<Self>> {
+        match self {
+            serde_json::Value::Array(results) => Some(results),
+            _ => None,
+        }
+    }
+}
+
+/// Queries joined with `; `, built by [`Expression::batch`].
+pub struct BatchExpression<T> {
+    queries: Vec<Expression<T>>,
+}
+
+impl<T> Debug for BatchExpression<T>
+where
+    Expression<T>: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.queries).finish()
+    }
+}
+
+impl<T> Clone for BatchExpression<T>
+where
+    Expression<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            queries: self.queries.clone(),
+        }
+    }
+}
+
+/// Per-query results of a [`BatchExpression`], in query order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResult<T> {
+    results: Vec<T>,
+}
+
+impl<T> Expression<T> {
+    /// Combine independent queries into one `; `-separated statement.
+    pub fn batch(queries: Vec<Expression<T>>) -> BatchExpression<T> {
+        BatchExpression { queries }
+    }
+}
+
+impl<T: Clone> BatchExpression<T> {
+    pub fn queries(&self) -> &[Expression<T>] {
+        &self.queries
+    }
+
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// For each query, the positions its parameters take in the flattened
+    /// batch. Backends number bound parameters in that order (`$_arg1`, …),
+    /// so query `i` owns exactly the indexes in `ranges[i]`.
+    pub fn parameter_ranges(&self) -> Vec<Range<usize>> {
+        let flattener = ExpressionFlattener::new();
+        let mut start = 0;
+        self.queries
+            .iter()
+            .map(|query| {
+                let count = flattener.flatten(query).parameters.len();
+                let range = start..start + count;
+                start += count;
+                range
+            })
+            .collect()
+    }
+
+    /// Split a backend response holding one result per statement back
+    /// into per-query results. Errors if the response isn't a list or
+    /// holds a different number of results than there are queries.
+    pub fn split(&self, response: T) -> Result<BatchResult<T>>
+    where
+        T: BatchResponse,
+    {
+        let results = response
+            .into_statement_results()
+            .ok_or_else(|| error!("Batch response is not a list of statement results"))?;
+        if results.len() != self.queries.len() {
+            return Err(error!(
+                "Batch response does not match the number of queries",
+                queries = self.queries.len(),
+                results = results.len()
+            ));
+        }
+        Ok(BatchResult { results })
+    }
+
+    /// Run the batch in one call and split the response.
+    pub async fn execute<D>(&self, data_source: &D) -> Result<BatchResult<T>>
+    where
+        D: ExprDataSource<T>,
+        T: BatchResponse,
+    {
+        let response = data_source.execute(&self.expr()).await?;
+        self.split(response)
+    }
+}
+
+impl<T: Display + Debug + Clone> BatchExpression<T> {
+    pub fn preview(&self) -> String {
+        self.expr().preview()
+    }
+}
+
+impl<T: Clone> Expressive<T> for BatchExpression<T> {
+    fn expr(&self) -> Expression<T> {
+        Expression::from_vec(self.queries.clone(), "; ")
+    }
+}
+
+impl<T> BatchResult<T> {
+    /// Result of the query at `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.results.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.results
+    }
+}
+
+impl<T> IntoIterator for BatchResult<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::mocks::MockExprDataSource;
+    use crate::traits::expressive::ExpressiveEnum;
+    use serde_json::{Value, json};
+
+    fn scalars(e: &Expression<Value>) -> Vec<Value> {
+        e.parameters
+            .iter()
+            .map(|p| match p {
+                ExpressiveEnum::Scalar(v) => v.clone(),
+                other => panic!("expected scalar, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parameters_stay_with_their_query() {
+        let batch = Expression::batch(vec![
+            expr!(
+                "SELECT COUNT(*) FROM orders WHERE status = {} AND total > {}",
+                "open",
+                10
+            ),
+            expr!("SELECT COUNT(*) FROM users WHERE status = {}", "open"),
+        ]);
+
+        let flat = ExpressionFlattener::new().flatten(&batch.expr());
+        assert_eq!(
+            flat.template,
+            "SELECT COUNT(*) FROM orders WHERE status = {} AND total > {}; \
+             SELECT COUNT(*) FROM users WHERE status = {}"
+        );
+        assert_eq!(
+            scalars(&flat),
+            vec![json!("open"), json!(10), json!("open")]
+        );
+
+        let ranges = batch.parameter_ranges();
+        assert_eq!(ranges, vec![0..2, 2..3]);
+        assert_eq!(scalars(&flat)[ranges[1].clone()], [json!("open")]);
+    }
+
+    #[tokio::test]
+    async fn test_results_map_back_to_queries() {
+        let batch = Expression::batch(vec![
+            expr!("SELECT COUNT(*) FROM orders"),
+            expr!("SELECT COUNT(*) FROM users"),
+        ]);
+
+        let results = batch
+            .execute(&MockExprDataSource::new(
+                json!([[{"count": 3}], [{"count": 8}]]),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get(0), Some(&json!([{"count": 3}])));
+        assert_eq!(results.get(1), Some(&json!([{"count": 8}])));
+
+        let short = batch.execute(&MockExprDataSource::new(json!([1]))).await;
+        assert!(short.is_err());
+        assert!(batch.split(json!({"count": 3})).is_err());
+    }
+}
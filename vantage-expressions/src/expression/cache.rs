@@ -0,0 +1,309 @@
+//! Memoized rendering keyed by an expression's structure.
+//!
+//! Rebuilding the same query every UI frame and rendering it again is wasted
+//! work. [`Expression::render_cached`] hashes the expression tree — template,
+//! parameters and nested expressions — and only calls the render closure when
+//! that hash isn't in the [`RenderCache`] yet. Changing the expression changes
+//! its hash, so there is nothing to invalidate by hand.
+//!
+//! ```rust
+//! use vantage_expressions::prelude::*;
+//! use vantage_expressions::RenderCache;
+//!
+//! let cache = RenderCache::new();
+//! let query = expr!("SELECT * FROM users WHERE age > {}", 21);
+//!
+//! let sql = query.render_cached(&cache, |e| e.preview());
+//! assert_eq!(sql, "SELECT * FROM users WHERE age > 21");
+//! assert_eq!(cache.len(), 1);
+//! ```
+//!
+//! A hit also requires the cached expression to have the same structure, so
+//! a hash collision renders afresh instead of returning another query's
+//! result. Deferred parameters match by identity (clones of one `DeferredFn`
+//! match), since their value is only known at execution time; the cache
+//! keeps its own clone of each, so an identity can't be reused while its
+//! entry lives.
+//!
+//! The cache holds at most [`RenderCache::with_capacity`] expressions
+//! ([`DEFAULT_CAPACITY`] by default) and evicts the least recently used.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use indexmap::IndexMap;
+
+use crate::expression::core::Expression;
+use crate::traits::expressive::ExpressiveEnum;
+
+/// Capacity of [`RenderCache::new`].
+pub const DEFAULT_CAPACITY: usize = 256;
+
+/// Rendered results keyed by [`Expression::structural_hash`], least
+/// recently used first.
+///
+/// Shareable across threads; the render closure runs outside the lock.
+pub struct RenderCache<T, R> {
+    entries: Mutex<IndexMap<u64, (Expression<T>, R)>>,
+    capacity: usize,
+}
+
+impl<T, R> fmt::Debug for RenderCache<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderCache")
+            .field("len", &self.entries.lock().unwrap().len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<T, R> Default for RenderCache<T, R> {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl<T, R> RenderCache<T, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cache holding at most `capacity` (at least one) expressions.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(IndexMap::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drop every cached render.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+impl<T: Clone + PartialEq, R: Clone> RenderCache<T, R> {
+    fn get_or_insert_with(&self, key: u64, expr: &Expression<T>, render: impl FnOnce() -> R) -> R {
+        {
+            let mut entries = self.entries.lock().unwrap();
+            if let Some(index) = entries.get_index_of(&key)
+                && entries[index].0.same_structure(expr)
+            {
+                let hit = entries[index].1.clone();
+                let last = entries.len() - 1;
+                entries.move_index(index, last);
+                return hit;
+            }
+        }
+
+        let rendered = render();
+        let mut entries = self.entries.lock().unwrap();
+        // Replaces a colliding entry, or one another thread just rendered
+        entries.shift_remove(&key);
+        if entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(key, (expr.clone(), rendered.clone()));
+        rendered
+    }
+}
+
+impl<T: Hash> Expression<T> {
+    /// Hash of the whole tree: template, scalar values, nested expressions
+    /// and raw text. Equal structures hash equally.
+    pub fn structural_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_structure(&mut hasher);
+        hasher.finish()
+    }
+
+    fn hash_structure(&self, hasher: &mut DefaultHasher) {
+        self.template.hash(hasher);
+        self.parameters.len().hash(hasher);
+        for param in &self.parameters {
+            match param {
+                ExpressiveEnum::Scalar(value) => {
+                    0u8.hash(hasher);
+                    value.hash(hasher);
+                }
+                ExpressiveEnum::Nested(nested) => {
+                    1u8.hash(hasher);
+                    nested.hash_structure(hasher);
+                }
+                ExpressiveEnum::Deferred(deferred) => {
+                    2u8.hash(hasher);
+                    deferred.identity().hash(hasher);
+                }
+                ExpressiveEnum::Raw(sql) => {
+                    3u8.hash(hasher);
+                    sql.hash(hasher);
+                }
+            }
+        }
+    }
+
+    /// Return the cached render of this expression, calling `render` only
+    /// when its structure isn't cached.
+    pub fn render_cached<R: Clone>(
+        &self,
+        cache: &RenderCache<T, R>,
+        render: impl FnOnce(&Self) -> R,
+    ) -> R
+    where
+        T: Clone + PartialEq,
+    {
+        cache.get_or_insert_with(self.structural_hash(), self, || render(self))
+    }
+}
+
+impl<T: PartialEq> Expression<T> {
+    /// Whether both trees have the same templates, scalars, raw text and
+    /// deferred callbacks, i.e. whether they render the same.
+    fn same_structure(&self, other: &Self) -> bool {
+        self.template == other.template
+            && self.parameters.len() == other.parameters.len()
+            && self
+                .parameters
+                .iter()
+                .zip(&other.parameters)
+                .all(|pair| match pair {
+                    (ExpressiveEnum::Scalar(a), ExpressiveEnum::Scalar(b)) => a == b,
+                    (ExpressiveEnum::Nested(a), ExpressiveEnum::Nested(b)) => a.same_structure(b),
+                    (ExpressiveEnum::Deferred(a), ExpressiveEnum::Deferred(b)) => {
+                        a.same_callback(b)
+                    }
+                    (ExpressiveEnum::Raw(a), ExpressiveEnum::Raw(b)) => a == b,
+                    _ => false,
+                })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::traits::expressive::DeferredFn;
+    use serde_json::json;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_renders_once_until_mutated() {
+        let cache = RenderCache::new();
+        let calls = Cell::new(0);
+        let render = |e: &Expression<serde_json::Value>| {
+            calls.set(calls.get() + 1);
+            e.preview()
+        };
+
+        let inner = expr!("age > {}", 21);
+        let mut query = expr!("SELECT * FROM users WHERE {}", (inner));
+        let first = query.render_cached(&cache, render);
+        let second = query.clone().render_cached(&cache, render);
+        assert_eq!(first, second);
+        assert_eq!(calls.get(), 1);
+
+        query.parameters[0] = ExpressiveEnum::Nested(expr!("age > {}", 30));
+        let third = query.render_cached(&cache, render);
+        assert_eq!(third, "SELECT * FROM users WHERE age > 30");
+        assert_eq!(calls.get(), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_structural_hash_sees_nesting_and_deferreds() {
+        // Same preview, different shape
+        let nested = expr!("a = {}", (expr!("1")));
+        let raw = Expression::<serde_json::Value>::raw("1");
+        assert_ne!(
+            nested.structural_hash(),
+            expr!("a = {}", (raw)).structural_hash()
+        );
+        assert_ne!(
+            expr!("x = {}", 1).structural_hash(),
+            expr!("x = {}", "1").structural_hash()
+        );
+
+        let deferred = DeferredFn::new(|| Box::pin(async { Ok(ExpressiveEnum::Scalar(json!(1))) }));
+        let a = expr!("x = {}", { deferred.clone() });
+        let b = expr!("x = {}", { deferred });
+        let other = DeferredFn::new(|| Box::pin(async { Ok(ExpressiveEnum::Scalar(json!(1))) }));
+        assert_eq!(a.structural_hash(), b.structural_hash());
+        assert_ne!(
+            a.structural_hash(),
+            expr!("x = {}", { other }).structural_hash()
+        );
+    }
+
+    #[test]
+    fn test_same_hash_different_structure_is_a_miss() {
+        let cache = RenderCache::new();
+        let deferred =
+            || DeferredFn::new(|| Box::pin(async { Ok(ExpressiveEnum::Scalar(json!(1))) }));
+        let (first, second) = (deferred(), deferred());
+        let users = expr!("SELECT * FROM users WHERE id = {}", { first.clone() });
+
+        // Force the collision a real hash would only hit by chance
+        let render = |e: &Expression<serde_json::Value>| e.template.clone();
+        let key = 42;
+        assert_eq!(
+            cache.get_or_insert_with(key, &users, || render(&users)),
+            users.template
+        );
+        let orders = expr!("SELECT * FROM orders");
+        assert_eq!(
+            cache.get_or_insert_with(key, &orders, || render(&orders)),
+            "SELECT * FROM orders"
+        );
+
+        // A different deferred callback doesn't match either; a clone does
+        let other = expr!("SELECT * FROM users WHERE id = {}", { second });
+        assert_eq!(
+            cache.get_or_insert_with(key, &other, || "other".to_string()),
+            "other"
+        );
+        let same = expr!("SELECT * FROM users WHERE id = {}", { first });
+        cache.get_or_insert_with(key, &same, || "same".to_string());
+        let clone = same.clone();
+        assert_eq!(
+            cache.get_or_insert_with(key, &clone, || unreachable!()),
+            "same"
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = RenderCache::with_capacity(2);
+        let calls = Cell::new(0);
+        let render = |e: &Expression<serde_json::Value>| {
+            calls.set(calls.get() + 1);
+            e.preview()
+        };
+        let (a, b, c) = (expr!("a = {}", 1), expr!("b = {}", 2), expr!("c = {}", 3));
+
+        a.render_cached(&cache, render);
+        b.render_cached(&cache, render);
+        a.render_cached(&cache, render); // a is now the most recent
+        c.render_cached(&cache, render); // evicts b
+        assert_eq!((cache.len(), calls.get()), (2, 3));
+
+        a.render_cached(&cache, render);
+        assert_eq!(calls.get(), 3);
+        b.render_cached(&cache, render);
+        assert_eq!(calls.get(), 4);
+    }
+}
@@ -0,0 +1,383 @@
+//! Compound WHERE logic as a single expression.
+//!
+//! Each `add_where_condition` call is ANDed with the others, so OR and
+//! grouping have to be built up front. [`Condition`] combines comparisons
+//! with `and`, `or` and `not`, adding parentheses only where precedence
+//! requires them — an OR inside an AND:
+//!
+//! ```rust
+//! use vantage_expressions::{Condition, Expression};
+//!
+//! let cond = Condition::field("a").eq(1)
+//!     .or(Condition::field("b").eq(2))
+//!     .and(Condition::field("c").eq(3));
+//!
+//! let expr: Expression<serde_json::Value> = cond.into();
+//! assert_eq!(expr.preview(), "(a = 1 OR b = 2) AND c = 3");
+//! ```
+//!
+//! [`col`] is shorthand for [`Condition::field`], for when conditions are
+//! written inline:
+//!
+//! ```rust
+//! use vantage_expressions::{col, Condition, Expressive};
+//!
+//! let cond: Condition<serde_json::Value> =
+//!     col("age").gt(18).and(col("status").is_in(["active", "trial"]));
+//! assert_eq!(cond.expr().preview(), "age > 18 AND status IN (\"active\", \"trial\")");
+//! ```
+//!
+//! Values stay bound parameters. Field names must be plain identifiers
+//! (letters, digits and underscores, optionally dotted like `u.name`), used
+//! as written; any other name panics, as quoting differs between backends.
+//! For such a column, quote it with the backend's identifier rules and
+//! start the comparison with [`ConditionField::from`], or wrap a whole
+//! backend expression with [`Condition::from`].
+
+use std::fmt::Debug;
+
+use crate::expression::core::Expression;
+use crate::traits::expressive::{Expressive, ExpressiveEnum};
+
+/// How tightly a condition binds, to decide when it needs parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Or,
+    And,
+    Atom,
+}
+
+/// A boolean condition built from comparisons, see the [module docs](self).
+pub struct Condition<T> {
+    expr: Expression<T>,
+    precedence: Precedence,
+}
+
+/// Left-hand side of a comparison, created by [`Condition::field`].
+pub struct ConditionField<T> {
+    field: Expression<T>,
+}
+
+impl<T> Debug for Condition<T>
+where
+    Expression<T>: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Condition")
+            .field("expr", &self.expr)
+            .field("precedence", &self.precedence)
+            .finish()
+    }
+}
+
+impl<T> Clone for Condition<T>
+where
+    Expression<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            expr: self.expr.clone(),
+            precedence: self.precedence,
+        }
+    }
+}
+
+impl<T> Debug for ConditionField<T>
+where
+    Expression<T>: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConditionField")
+            .field("field", &self.field)
+            .finish()
+    }
+}
+
+impl<T> Condition<T> {
+    /// Start a comparison on the column `name`.
+    ///
+    /// # Panics
+    ///
+    /// If `name` isn't a plain (optionally dotted) identifier, see the
+    /// [module docs](self).
+    pub fn field(name: impl Into<String>) -> ConditionField<T> {
+        let name = name.into();
+        assert!(
+            is_plain_identifier(&name),
+            "Condition::field: {:?} is not a plain identifier; quote it for the \
+             backend and use ConditionField::from",
+            name
+        );
+        ConditionField {
+            field: Expression::new(name, vec![]),
+        }
+    }
+
+    /// Both conditions hold. An OR operand is parenthesized.
+    pub fn and(self, other: Condition<T>) -> Self {
+        Self::join(
+            " AND ",
+            self.grouped_below(Precedence::And),
+            other.grouped_below(Precedence::And),
+            Precedence::And,
+        )
+    }
+
+    /// Either condition holds.
+    pub fn or(self, other: Condition<T>) -> Self {
+        Self::join(" OR ", self.expr, other.expr, Precedence::Or)
+    }
+
+    /// `NOT (condition)`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self {
+            expr: Expression::not(self.expr),
+            precedence: Precedence::Atom,
+        }
+    }
+
+    fn join(op: &str, left: Expression<T>, right: Expression<T>, precedence: Precedence) -> Self {
+        Self {
+            expr: Expression::from_vec(vec![left, right], op),
+            precedence,
+        }
+    }
+
+    /// This condition as an operand of an operator binding at `precedence`.
+    fn grouped_below(self, precedence: Precedence) -> Expression<T> {
+        if self.precedence < precedence {
+            Expression::new("({})", vec![ExpressiveEnum::nested(self.expr)])
+        } else {
+            self.expr
+        }
+    }
+}
+
+impl<T> ConditionField<T> {
+    fn compare(self, op: &str, value: impl Into<T>) -> Condition<T> {
+        Condition {
+            expr: Expression::new(
+                format!("{{}} {} {{}}", op),
+                vec![
+                    ExpressiveEnum::nested(self.field),
+                    ExpressiveEnum::Scalar(value.into()),
+                ],
+            ),
+            precedence: Precedence::Atom,
+        }
+    }
+
+    /// `field = value`
+    pub fn eq(self, value: impl Into<T>) -> Condition<T> {
+        self.compare("=", value)
+    }
+
+    /// `field != value`
+    pub fn ne(self, value: impl Into<T>) -> Condition<T> {
+        self.compare("!=", value)
+    }
+
+    /// `field > value`
+    pub fn gt(self, value: impl Into<T>) -> Condition<T> {
+        self.compare(">", value)
+    }
+
+    /// `field >= value`
+    pub fn gte(self, value: impl Into<T>) -> Condition<T> {
+        self.compare(">=", value)
+    }
+
+    /// `field < value`
+    pub fn lt(self, value: impl Into<T>) -> Condition<T> {
+        self.compare("<", value)
+    }
+
+    /// `field <= value`
+    pub fn lte(self, value: impl Into<T>) -> Condition<T> {
+        self.compare("<=", value)
+    }
+
+    /// `field >= value`, same as [`gte`](Self::gte).
+    pub fn ge(self, value: impl Into<T>) -> Condition<T> {
+        self.gte(value)
+    }
+
+    /// `field <= value`, same as [`lte`](Self::lte).
+    pub fn le(self, value: impl Into<T>) -> Condition<T> {
+        self.lte(value)
+    }
+
+    /// `field LIKE pattern`
+    pub fn like(self, pattern: impl Into<T>) -> Condition<T> {
+        self.compare("LIKE", pattern)
+    }
+
+    /// `field IN (v1, v2, ...)`, each value bound separately. An empty list
+    /// renders `1 = 0`, which matches nothing.
+    pub fn is_in<V: Into<T>>(self, values: impl IntoIterator<Item = V>) -> Condition<T> {
+        let values: Vec<ExpressiveEnum<T>> = values
+            .into_iter()
+            .map(|value| ExpressiveEnum::Scalar(value.into()))
+            .collect();
+        if values.is_empty() {
+            return Condition {
+                expr: Expression::new("1 = 0", vec![]),
+                precedence: Precedence::Atom,
+            };
+        }
+        let placeholders = vec!["{}"; values.len()].join(", ");
+        let mut parameters = vec![ExpressiveEnum::nested(self.field)];
+        parameters.extend(values);
+        Condition {
+            expr: Expression::new(format!("{{}} IN ({})", placeholders), parameters),
+            precedence: Precedence::Atom,
+        }
+    }
+}
+
+/// Start a comparison on the column `name`, same as [`Condition::field`].
+pub fn col<T>(name: impl Into<String>) -> ConditionField<T> {
+    Condition::field(name)
+}
+
+/// Letters, digits and underscores, not starting with a digit, optionally
+/// dotted.
+fn is_plain_identifier(name: &str) -> bool {
+    name.split('.').all(|part| {
+        let mut chars = part.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Start a comparison on any expression, such as a column quoted by the
+/// backend.
+impl<T> From<Expression<T>> for ConditionField<T> {
+    fn from(field: Expression<T>) -> Self {
+        Self { field }
+    }
+}
+
+/// Wrap any expression as a condition. It is parenthesized when combined,
+/// since its own precedence is unknown.
+impl<T> From<Expression<T>> for Condition<T> {
+    fn from(expr: Expression<T>) -> Self {
+        Self {
+            expr,
+            precedence: Precedence::Or,
+        }
+    }
+}
+
+impl<T> From<Condition<T>> for Expression<T> {
+    fn from(condition: Condition<T>) -> Self {
+        condition.expr
+    }
+}
+
+impl<T: Clone> Expressive<T> for Condition<T> {
+    fn expr(&self) -> Expression<T> {
+        self.expr.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::expression::flatten::{ExpressionFlattener, Flatten};
+    use serde_json::Value;
+
+    fn field(name: &str) -> ConditionField<Value> {
+        Condition::field(name)
+    }
+
+    #[test]
+    fn test_or_inside_and_is_parenthesized() {
+        let cond = field("a").eq(1).or(field("b").eq(2)).and(field("c").eq(3));
+        assert_eq!(cond.expr().preview(), "(a = 1 OR b = 2) AND c = 3");
+
+        let flat = ExpressionFlattener::new().flatten(&cond.expr());
+        assert_eq!(flat.template, "(a = {} OR b = {}) AND c = {}");
+        assert_eq!(flat.parameters.len(), 3);
+
+        // AND binds tighter, so no parentheses are needed under OR
+        let cond = field("a")
+            .eq(1)
+            .and(field("b").eq(2))
+            .or(field("c").gt(3).and(field("d").lte(4)));
+        assert_eq!(cond.expr().preview(), "a = 1 AND b = 2 OR c > 3 AND d <= 4");
+    }
+
+    #[test]
+    fn test_not_and_wrapped_expressions() {
+        let cond = field("a").eq(1).or(field("b").ne(2)).not();
+        assert_eq!(cond.expr().preview(), "NOT (a = 1 OR b != 2)");
+
+        let raw: Condition<Value> = expr!("x = {} OR y = {}", 1, 2).into();
+        let cond = raw.and(field("z").lt(3));
+        assert_eq!(cond.expr().preview(), "(x = 1 OR y = 2) AND z < 3");
+    }
+
+    #[test]
+    fn test_condition_goes_into_where() {
+        use crate::mocks::select::MockSelect;
+        use crate::traits::selectable::Selectable;
+
+        let mut select = MockSelect::new();
+        select.add_where_condition(field("a").eq(1).or(field("b").eq(2)));
+        assert_eq!(select.where_conditions()[0].preview(), "a = 1 OR b = 2");
+    }
+
+    #[test]
+    fn test_col_builds_compound_condition() {
+        let cond: Condition<Value> = col("age")
+            .ge(18)
+            .and(col("status").eq("active").or(col("name").like("A%")))
+            .and(col("u.role").is_in(["admin", "staff"]));
+
+        let flat = ExpressionFlattener::new().flatten(&cond.expr());
+        assert_eq!(
+            flat.template,
+            "age >= {} AND (status = {} OR name LIKE {}) AND u.role IN ({}, {})"
+        );
+        let params: Vec<Value> = flat
+            .parameters
+            .into_iter()
+            .map(|param| match param {
+                ExpressiveEnum::Scalar(value) => value,
+                other => panic!("expected a bound value, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            params,
+            vec![
+                Value::from(18),
+                Value::from("active"),
+                Value::from("A%"),
+                Value::from("admin"),
+                Value::from("staff"),
+            ]
+        );
+
+        let none: Condition<Value> = col("id").is_in(Vec::<i64>::new());
+        assert_eq!(none.expr().preview(), "1 = 0");
+    }
+
+    #[test]
+    #[should_panic(expected = "not a plain identifier")]
+    fn test_unusual_field_names_are_rejected() {
+        let _ = col::<Value>("order date");
+    }
+
+    #[test]
+    fn test_field_from_backend_expression() {
+        // What a backend's identifier quoting would produce
+        let quoted = Expression::<Value>::new("`order date`", vec![]);
+        let cond = ConditionField::from(quoted).le(5).and(col("u.id").ne(0));
+        assert_eq!(cond.expr().preview(), "`order date` <= 5 AND u.id != 0");
+    }
+}
@@ -115,6 +115,46 @@ impl<T> Expression<T> {
         }
     }
 
+    /// Trusted query text rendered verbatim — never bound as a parameter.
+    ///
+    /// For fragments that can't be parameters, such as a function or
+    /// operator name picked by the application. Never pass user input here.
+    /// The text is kept in an [`ExpressiveEnum::Raw`] node rather than the
+    /// template, so [`contains_raw`](Self::contains_raw) can flag its use.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let func = Expression::raw("NOW()");
+    /// let e = expr!("created < {} AND kind = {}", (func), "draft");
+    /// assert_eq!(e.preview(), r#"created < NOW() AND kind = "draft""#);
+    /// assert!(e.contains_raw());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `sql` contains `{}`, which would be read as a placeholder once
+    /// flattened into the template.
+    pub fn raw(sql: impl Into<String>) -> Self {
+        let sql = sql.into();
+        assert!(
+            !sql.contains("{}"),
+            "Expression::raw text must not contain a `{{}}` placeholder: {}",
+            sql
+        );
+        Self::new("{}", vec![ExpressiveEnum::Raw(sql)])
+    }
+
+    /// Whether this expression, or any expression nested in it, has a
+    /// [`raw`](Self::raw) node. Deferred parameters aren't inspected.
+    pub fn contains_raw(&self) -> bool {
+        self.parameters.iter().any(|param| match param {
+            ExpressiveEnum::Raw(_) => true,
+            ExpressiveEnum::Nested(inner) => inner.contains_raw(),
+            _ => false,
+        })
+    }
+
     /// Create expression from vector of expressions and a delimiter
     ///
     /// See the [module-level documentation](crate::expression::core) for examples.
@@ -132,6 +172,187 @@ impl<T> Expression<T> {
             parameters,
         }
     }
+
+    /// `COALESCE(a, b, ...)` — the first non-NULL argument. Arguments are
+    /// nested, so their values stay bound parameters.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::coalesce(vec![expr!("nickname"), expr!("{}", "anonymous")]);
+    /// assert_eq!(e.preview(), r#"COALESCE(nickname, "anonymous")"#);
+    /// ```
+    ///
+    /// SurrealDB has no `COALESCE`; use its `coalesce` primitives (`??`) there.
+    pub fn coalesce(args: Vec<Expression<T>>) -> Self {
+        Self::sql_fn("COALESCE", args)
+    }
+
+    /// `CONCAT(a, b, ...)`. SQLite and PostgreSQL also accept `||`; for
+    /// per-vendor rendering use the SQL crate's `Concat`, and `concat` from
+    /// the SurrealDB primitives (`string::concat`) there.
+    pub fn concat(parts: Vec<Expression<T>>) -> Self {
+        Self::sql_fn("CONCAT", parts)
+    }
+
+    /// `LOWER(e)` — SurrealDB spells it `string::lowercase`.
+    pub fn lower(e: Expression<T>) -> Self {
+        Self::sql_fn("LOWER", vec![e])
+    }
+
+    /// `UPPER(e)` — SurrealDB spells it `string::uppercase`.
+    pub fn upper(e: Expression<T>) -> Self {
+        Self::sql_fn("UPPER", vec![e])
+    }
+
+    /// `TRIM(e)` — SurrealDB spells it `string::trim`.
+    pub fn trim(e: Expression<T>) -> Self {
+        Self::sql_fn("TRIM", vec![e])
+    }
+
+    /// `NOT (inner)` — negate a condition. The parentheses keep a compound
+    /// condition negated as a whole; `inner` is nested, so its values stay
+    /// bound parameters.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::not(expr!("a = {} AND b = {}", 1, 2));
+    /// assert_eq!(e.preview(), "NOT (a = 1 AND b = 2)");
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(inner: Expression<T>) -> Self {
+        Self::new("NOT ({})", vec![ExpressiveEnum::nested(inner)])
+    }
+
+    /// `CAST(inner AS ty)` — SQL type coercion. The inner expression stays
+    /// nested, so its values remain bound parameters; `ty` is used as written.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::cast(expr!("{}", "2024-01-31"), "date");
+    /// assert_eq!(e.preview(), "CAST(\"2024-01-31\" AS date)");
+    /// ```
+    pub fn cast(inner: Expression<T>, ty: &str) -> Self {
+        Self::new(
+            format!("CAST({{}} AS {})", ty),
+            vec![ExpressiveEnum::nested(inner)],
+        )
+    }
+
+    /// `column LIKE '%term%' ESCAPE '$'` — substring match on a user-supplied
+    /// term. `%`, `_` and the `$` escape character in `term` are escaped, so
+    /// they match literally; the pattern is a bound parameter. `$` rather
+    /// than a backslash keeps the clause valid on SQLite, PostgreSQL and
+    /// MySQL alike.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::contains(expr!("name"), "50%_off");
+    /// assert_eq!(e.preview(), "name LIKE \"%50$%$_off%\" ESCAPE '$'");
+    /// ```
+    pub fn contains(column: Expression<T>, term: &str) -> Self
+    where
+        T: From<String>,
+    {
+        let escaped = term
+            .replace('$', "$$")
+            .replace('%', "$%")
+            .replace('_', "$_");
+        Self::new(
+            "{} LIKE {} ESCAPE '$'",
+            vec![
+                ExpressiveEnum::nested(column),
+                ExpressiveEnum::Scalar(format!("%{}%", escaped).into()),
+            ],
+        )
+    }
+
+    /// `column IS NOT DISTINCT FROM value` — equality that treats two NULLs
+    /// as equal and a NULL against a value as unequal, where `=` yields
+    /// NULL and drops the row. This is the SQL-standard spelling (PostgreSQL,
+    /// SQLite 3.39+); use [`Selectable::add_where_eq_null_safe`] to get the
+    /// backend's own operator, e.g. MySQL's `<=>`.
+    ///
+    /// [`Selectable::add_where_eq_null_safe`]: crate::traits::selectable::Selectable::add_where_eq_null_safe
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::eq_null_safe(expr!("manager_id"), expr!("{}", 7));
+    /// assert_eq!(e.preview(), "manager_id IS NOT DISTINCT FROM 7");
+    /// ```
+    pub fn eq_null_safe(column: Expression<T>, value: Expression<T>) -> Self {
+        Self::new(
+            "{} IS NOT DISTINCT FROM {}",
+            vec![
+                ExpressiveEnum::nested(column),
+                ExpressiveEnum::nested(value),
+            ],
+        )
+    }
+
+    /// `func OVER (PARTITION BY ... ORDER BY ...)` — a SQL window function.
+    /// Each `order_by` entry is an expression and whether it sorts ascending.
+    /// Empty clauses are left out; every part is nested, so values stay
+    /// bound parameters.
+    ///
+    /// ```rust
+    /// use vantage_expressions::{expr, Expression};
+    ///
+    /// let e = Expression::<serde_json::Value>::window(
+    ///     expr!("ROW_NUMBER()"),
+    ///     vec![expr!("department")],
+    ///     vec![(expr!("salary"), false)],
+    /// );
+    /// assert_eq!(
+    ///     e.preview(),
+    ///     "ROW_NUMBER() OVER (PARTITION BY department ORDER BY salary DESC)"
+    /// );
+    /// ```
+    pub fn window(
+        func: Expression<T>,
+        partition_by: Vec<Expression<T>>,
+        order_by: Vec<(Expression<T>, bool)>,
+    ) -> Self {
+        let mut clauses = Vec::new();
+        if !partition_by.is_empty() {
+            clauses.push(Self::new(
+                "PARTITION BY {}",
+                vec![ExpressiveEnum::nested(Self::from_vec(partition_by, ", "))],
+            ));
+        }
+        if !order_by.is_empty() {
+            let order = order_by
+                .into_iter()
+                .map(|(e, ascending)| {
+                    let template = if ascending { "{}" } else { "{} DESC" };
+                    Self::new(template, vec![ExpressiveEnum::nested(e)])
+                })
+                .collect();
+            clauses.push(Self::new(
+                "ORDER BY {}",
+                vec![ExpressiveEnum::nested(Self::from_vec(order, ", "))],
+            ));
+        }
+        Self::new(
+            "{} OVER ({})",
+            vec![
+                ExpressiveEnum::nested(func),
+                ExpressiveEnum::nested(Self::from_vec(clauses, " ")),
+            ],
+        )
+    }
+
+    /// `NAME(arg, ...)` with every argument nested.
+    fn sql_fn(name: &str, args: Vec<Expression<T>>) -> Self {
+        Self::new(
+            format!("{}({{}})", name),
+            vec![ExpressiveEnum::nested(Self::from_vec(args, ", "))],
+        )
+    }
 }
 
 impl<T> Expression<T> {
@@ -226,6 +447,42 @@ impl<T> std::ops::Div for Expression<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::expression::flatten::{ExpressionFlattener, Flatten};
+
+    #[test]
+    fn test_cast_keeps_inner_parameters() {
+        let e = Expression::cast(crate::expr!("{}", "2024-01-31"), "date");
+        let flat = ExpressionFlattener::new().flatten(&e);
+        assert_eq!(flat.template, "CAST({} AS date)");
+        assert_eq!(flat.parameters.len(), 1);
+    }
+
+    #[test]
+    fn test_contains_escapes_wildcards() {
+        let e = Expression::contains(crate::expr!("name"), "100%");
+        let flat = ExpressionFlattener::new().flatten(&e);
+        assert_eq!(flat.template, "name LIKE {} ESCAPE '$'");
+        assert_eq!(e.preview(), "name LIKE \"%100$%%\" ESCAPE '$'");
+
+        let e = Expression::contains(crate::expr!("code"), "a_b$c");
+        assert_eq!(e.preview(), "code LIKE \"%a$_b$$c%\" ESCAPE '$'");
+    }
+
+    #[test]
+    fn test_not_wraps_condition() {
+        let e = Expression::not(crate::expr!("a = {}", 1));
+        assert_eq!(e.preview(), "NOT (a = 1)");
+
+        let nested = Expression::not(crate::expr!(
+            "{} AND {}",
+            (crate::expr!("a = {}", 1)),
+            (crate::expr!("b = {}", 2))
+        ));
+        let flat = ExpressionFlattener::new().flatten(&nested);
+        assert_eq!(flat.template, "NOT (a = {} AND b = {})");
+        assert_eq!(flat.parameters.len(), 2);
+        assert_eq!(nested.preview(), "NOT (a = 1 AND b = 2)");
+    }
 
     #[test]
     fn test_expression_basic() {
@@ -252,4 +509,134 @@ mod tests {
         );
         assert_eq!(expr.preview(), "weird{} = x");
     }
+
+    #[test]
+    fn test_coalesce_two_args() {
+        let expr = Expression::coalesce(vec![
+            Expression::new("nickname", vec![]),
+            Expression::new("{}", vec![ExpressiveEnum::Scalar("anonymous".to_string())]),
+        ]);
+        assert_eq!(expr.preview(), "COALESCE(nickname, anonymous)");
+
+        let flat = ExpressionFlattener::new().flatten(&expr);
+        assert_eq!(flat.template, "COALESCE(nickname, {})");
+        assert_eq!(flat.parameters.len(), 1);
+    }
+
+    #[test]
+    fn test_string_helpers() {
+        let name = || Expression::<i64>::new("name", vec![]);
+
+        assert_eq!(Expression::lower(name()).preview(), "LOWER(name)");
+        assert_eq!(Expression::upper(name()).preview(), "UPPER(name)");
+        assert_eq!(Expression::trim(name()).preview(), "TRIM(name)");
+        assert_eq!(
+            Expression::upper(Expression::trim(name())).preview(),
+            "UPPER(TRIM(name))"
+        );
+    }
+
+    #[test]
+    fn test_concat() {
+        let expr = Expression::concat(vec![
+            Expression::lower(Expression::new("first_name", vec![])),
+            Expression::new("{}", vec![ExpressiveEnum::Scalar(" ".to_string())]),
+            Expression::new("last_name", vec![]),
+        ]);
+        assert_eq!(expr.preview(), "CONCAT(LOWER(first_name),  , last_name)");
+
+        let flat = ExpressionFlattener::new().flatten(&expr);
+        assert_eq!(flat.template, "CONCAT(LOWER(first_name), {}, last_name)");
+        assert_eq!(flat.parameters.len(), 1);
+    }
+
+    #[test]
+    fn test_coalesce_three_args() {
+        let expr = Expression::coalesce(vec![
+            Expression::new("{}", vec![ExpressiveEnum::Scalar(1)]),
+            Expression::new("{}", vec![ExpressiveEnum::Scalar(2)]),
+            Expression::new("{}", vec![ExpressiveEnum::Scalar(3)]),
+        ]);
+        assert_eq!(expr.preview(), "COALESCE(1, 2, 3)");
+
+        let flat = ExpressionFlattener::new().flatten(&expr);
+        assert_eq!(flat.template, "COALESCE({}, {}, {})");
+        assert_eq!(flat.parameters.len(), 3);
+    }
+
+    #[test]
+    fn test_window_row_number_partition_and_order() {
+        let expr = Expression::<i64>::window(
+            Expression::new("ROW_NUMBER()", vec![]),
+            vec![
+                Expression::new("department", vec![]),
+                Expression::new("LOWER(region)", vec![]),
+            ],
+            vec![
+                (Expression::new("salary", vec![]), false),
+                (Expression::new("name", vec![]), true),
+            ],
+        );
+        assert_eq!(
+            expr.preview(),
+            "ROW_NUMBER() OVER (PARTITION BY department, LOWER(region) \
+             ORDER BY salary DESC, name)"
+        );
+    }
+
+    #[test]
+    fn test_window_sum_partition_only() {
+        let expr = Expression::window(
+            Expression::new("SUM({})", vec![ExpressiveEnum::Scalar(1)]),
+            vec![Expression::new(
+                "year = {}",
+                vec![ExpressiveEnum::Scalar(2024)],
+            )],
+            vec![],
+        );
+        assert_eq!(expr.preview(), "SUM(1) OVER (PARTITION BY year = 2024)");
+
+        let flat = ExpressionFlattener::new().flatten(&expr);
+        assert_eq!(flat.template, "SUM({}) OVER (PARTITION BY year = {})");
+        assert_eq!(flat.parameters.len(), 2);
+    }
+
+    #[test]
+    fn test_raw_renders_unchanged_without_parameters() {
+        let raw = Expression::<i64>::raw("pg_catalog.now()");
+        assert_eq!(raw.preview(), "pg_catalog.now()");
+        assert!(raw.contains_raw());
+
+        let flat = ExpressionFlattener::new().flatten(&raw);
+        assert_eq!(flat.template, "pg_catalog.now()");
+        assert!(flat.parameters.is_empty());
+    }
+
+    #[test]
+    fn test_raw_flattens_into_template() {
+        let condition = Expression::new(
+            "created_at < {} AND id = {}",
+            vec![
+                ExpressiveEnum::nested(Expression::raw("NOW()")),
+                ExpressiveEnum::Scalar(7),
+            ],
+        );
+        let query = Expression::new("SELECT * FROM t WHERE {}", vec![condition.into()]);
+        assert!(query.contains_raw());
+        assert!(!Expression::new("id = {}", vec![ExpressiveEnum::Scalar(7)]).contains_raw());
+
+        let flat = ExpressionFlattener::new().flatten(&query);
+        assert_eq!(
+            flat.template,
+            "SELECT * FROM t WHERE created_at < NOW() AND id = {}"
+        );
+        assert_eq!(flat.parameters.len(), 1);
+        assert!(matches!(flat.parameters[0], ExpressiveEnum::Scalar(7)));
+    }
+
+    #[test]
+    #[should_panic(expected = "must not contain")]
+    fn test_raw_rejects_placeholder() {
+        Expression::<i64>::raw("'{}'::jsonb");
+    }
 }
@@ -24,6 +24,18 @@
 //! ```
 //!
 //! This pattern allows complex query composition while maintaining parameter safety during execution.
+//!
+//! # Parameter order
+//!
+//! Flattening walks the expression depth-first, left to right: a nested
+//! expression's parameters take the place of its `{}`, ahead of whatever
+//! follows it in the parent. Parameter N of the flattened expression is
+//! therefore the N-th scalar in reading order of the rendered query, and
+//! placeholders such as `$_argN` are the same for the same expression tree on
+//! every run. Backends rely on this for stable query text (snapshot tests,
+//! prepared statement caches) and must not reorder parameters.
+
+use indexmap::IndexMap;
 
 use crate::expression::core::Expression;
 use crate::traits::expressive::ExpressiveEnum;
@@ -79,6 +91,8 @@ impl<T: Clone> Flatten<Expression<T>> for ExpressionFlattener {
                         final_template.push_str(&nested_expr.template);
                         final_params.extend(nested_expr.parameters.clone());
                     }
+                    // Raw text becomes part of the template, never a parameter
+                    ExpressiveEnum::Raw(sql) => final_template.push_str(sql),
                     other => {
                         final_template.push_str("{}");
                         final_params.push(other.clone());
@@ -100,6 +114,126 @@ impl<T: Clone> Flatten<Expression<T>> for ExpressionFlattener {
     }
 }
 
+/// Placeholder syntax used by [`Expression::prepare_with_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamStyle {
+    /// Named SurrealDB parameters: `$_arg1`, `$_arg2`, …
+    Surreal,
+    /// Numbered PostgreSQL parameters: `$1`, `$2`, …
+    Postgres,
+    /// Positional MySQL / SQLite parameters: `?`
+    Question,
+}
+
+impl ParamStyle {
+    /// Placeholder for the parameter at 1-based position `n`.
+    pub fn placeholder(&self, n: usize) -> String {
+        match self {
+            ParamStyle::Surreal => format!("$_arg{}", n),
+            ParamStyle::Postgres => format!("${}", n),
+            ParamStyle::Question => "?".to_string(),
+        }
+    }
+}
+
+impl<T: Clone> Expression<T> {
+    /// Flatten the expression and replace each `{}` with a placeholder in
+    /// `style`. The returned values are in placeholder order, so the N-th
+    /// value binds to `$_argN` / `$N` / the N-th `?`.
+    ///
+    /// ```rust
+    /// use vantage_expressions::prelude::*;
+    ///
+    /// let expr = expr_as!(i64, "SELECT * FROM t WHERE a = {} AND b = {}", 1i64, 2i64);
+    /// let (sql, params) = expr.prepare_with_style(ParamStyle::Postgres);
+    /// assert_eq!(sql, "SELECT * FROM t WHERE a = $1 AND b = $2");
+    /// assert_eq!(params, vec![1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Deferred parameters must be resolved first; one left in the
+    /// expression panics.
+    pub fn prepare_with_style(&self, style: ParamStyle) -> (String, Vec<T>) {
+        let flattened = ExpressionFlattener::new().flatten(self);
+
+        let mut parts = flattened.template.split("{}");
+        let mut query = parts.next().unwrap_or("").to_string();
+        let mut params = Vec::with_capacity(flattened.parameters.len());
+
+        for param in &flattened.parameters {
+            match param {
+                ExpressiveEnum::Scalar(value) => {
+                    params.push(value.clone());
+                    query.push_str(&style.placeholder(params.len()));
+                }
+                ExpressiveEnum::Deferred(_) => {
+                    panic!("deferred parameters must be resolved before prepare_with_style")
+                }
+                ExpressiveEnum::Nested(_) | ExpressiveEnum::Raw(_) => {
+                    unreachable!("nested and raw parameters are removed by flattening")
+                }
+            }
+            query.push_str(parts.next().unwrap_or(""));
+        }
+
+        (query, params)
+    }
+}
+
+impl<T: Clone> Expression<T> {
+    /// The bound values keyed by their placeholder name — `_arg1`, `_arg2`,
+    /// … in the order [`prepare_with_style`](Self::prepare_with_style)
+    /// numbers them. Meant for logging and tracing. Deferred parameters have
+    /// no value yet and are left out, but still take up a number.
+    ///
+    /// ```rust
+    /// use vantage_expressions::prelude::*;
+    ///
+    /// let expr = expr!("SELECT * FROM users WHERE email = {} AND age > {}", "a@b.c", 21);
+    /// let params = expr.named_parameters();
+    /// assert_eq!(params["_arg1"], "a@b.c");
+    /// assert_eq!(expr.redact(&["_arg1"]), "SELECT * FROM users WHERE email = *** AND age > 21");
+    /// ```
+    pub fn named_parameters(&self) -> IndexMap<String, T> {
+        let flattened = ExpressionFlattener::new().flatten(self);
+        flattened
+            .parameters
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, param)| match param {
+                ExpressiveEnum::Scalar(value) => Some((param_name(i + 1), value)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Preview for logs with the parameters named in `keys` (see
+    /// [`named_parameters`](Self::named_parameters)) shown as `***`.
+    pub fn redact(&self, keys: &[&str]) -> String
+    where
+        T: std::fmt::Display + std::fmt::Debug,
+    {
+        let flattened = ExpressionFlattener::new().flatten(self);
+        let mut parts = flattened.template.split("{}");
+        let mut logged = parts.next().unwrap_or("").to_string();
+        for (i, param) in flattened.parameters.iter().enumerate() {
+            if keys.contains(&param_name(i + 1).as_str()) {
+                logged.push_str("***");
+            } else {
+                logged.push_str(&param.preview());
+            }
+            logged.push_str(parts.next().unwrap_or(""));
+        }
+        logged
+    }
+}
+
+/// Name of the parameter at 1-based position `n`, as bound by SurrealDB.
+fn param_name(n: usize) -> String {
+    format!("_arg{}", n)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +266,77 @@ mod tests {
         assert_eq!(flattened.parameters.len(), 2);
     }
 
+    #[test]
+    fn test_prepare_with_style() {
+        let filter = expr_as!(String, "age > {} AND name = {}", "25", "John");
+        let expr = expr_as!(
+            String,
+            "SELECT * FROM users WHERE {} LIMIT {}",
+            (filter),
+            "10"
+        );
+        let values = vec!["25".to_string(), "John".to_string(), "10".to_string()];
+
+        let (sql, params) = expr.prepare_with_style(ParamStyle::Surreal);
+        assert_eq!(
+            sql,
+            "SELECT * FROM users WHERE age > $_arg1 AND name = $_arg2 LIMIT $_arg3"
+        );
+        assert_eq!(params, values);
+
+        let (sql, params) = expr.prepare_with_style(ParamStyle::Postgres);
+        assert_eq!(
+            sql,
+            "SELECT * FROM users WHERE age > $1 AND name = $2 LIMIT $3"
+        );
+        assert_eq!(params, values);
+
+        let (sql, params) = expr.prepare_with_style(ParamStyle::Question);
+        assert_eq!(
+            sql,
+            "SELECT * FROM users WHERE age > ? AND name = ? LIMIT ?"
+        );
+        assert_eq!(params, values);
+    }
+
+    #[test]
+    fn test_parameter_order_is_depth_first() {
+        let owner = expr_as!(String, "SELECT id FROM user WHERE name = {}", "a");
+        let tags = expr_as!(
+            String,
+            "SELECT id FROM tag WHERE owner IN ({}) AND label = {}",
+            (owner),
+            "b"
+        );
+        let total = expr_as!(
+            String,
+            "math::sum(SELECT total FROM order WHERE id > {})",
+            "c"
+        );
+        let query = expr_as!(
+            String,
+            "SELECT {}, {} AS total FROM post WHERE tag IN ({}) AND score > {} LIMIT {}",
+            "d",
+            (total),
+            (tags),
+            "e",
+            "f"
+        );
+
+        let (sql, params) = query.prepare_with_style(ParamStyle::Surreal);
+        assert_eq!(
+            sql,
+            "SELECT $_arg1, math::sum(SELECT total FROM order WHERE id > $_arg2) AS total \
+             FROM post WHERE tag IN (SELECT id FROM tag WHERE owner IN \
+             (SELECT id FROM user WHERE name = $_arg3) AND label = $_arg4) \
+             AND score > $_arg5 LIMIT $_arg6"
+        );
+        assert_eq!(params, vec!["d", "c", "a", "b", "e", "f"]);
+
+        // Same tree, same numbering.
+        assert_eq!(query.prepare_with_style(ParamStyle::Surreal).0, sql);
+    }
+
     #[test]
     fn test_mixed_parameters() {
         let flattener = ExpressionFlattener::new();
@@ -152,4 +357,27 @@ mod tests {
         );
         assert_eq!(flattened.parameters.len(), 2);
     }
+
+    #[test]
+    fn test_named_parameters_and_redact() {
+        let login = expr_as!(String, "email = {} AND password = {}", "a@b.c", "hunter2");
+        let expr = expr_as!(
+            String,
+            "SELECT * FROM users WHERE {} LIMIT {}",
+            (login),
+            "10"
+        );
+
+        let params = expr.named_parameters();
+        let names: Vec<&str> = params.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["_arg1", "_arg2", "_arg3"]);
+        assert_eq!(params["_arg2"], "hunter2");
+        assert_eq!(params["_arg3"], "10");
+
+        assert_eq!(
+            expr.redact(&["_arg2"]),
+            "SELECT * FROM users WHERE email = a@b.c AND password = *** LIMIT 10"
+        );
+        assert_eq!(expr.redact(&[]), expr.preview());
+    }
 }
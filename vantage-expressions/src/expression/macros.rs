@@ -69,6 +69,61 @@ macro_rules! expr {
     };
 }
 
+/// Like [`expr_as!`], but the template must be a string literal and its `{}`
+/// placeholder count is checked against the arguments at compile time.
+/// Dialect crates wrap it (`surreal_query!`, `sqlite_query!`, …).
+///
+/// ```rust
+/// use vantage_expressions::checked_expr_as;
+///
+/// let expr = checked_expr_as!(String, "name = {} AND city = {}", "John", "Riga");
+/// assert_eq!(expr.preview(), "name = John AND city = Riga");
+/// ```
+///
+/// A mismatch fails to build:
+///
+/// ```rust,compile_fail
+/// use vantage_expressions::checked_expr_as;
+///
+/// let expr = checked_expr_as!(String, "name = {} AND city = {}", "John");
+/// ```
+#[macro_export]
+macro_rules! checked_expr_as {
+    ($t:ty, $template:literal $(, $param:tt)* $(,)?) => {{
+        const _: () = assert!(
+            $crate::expression::macros::placeholder_count($template)
+                == <[()]>::len(&[$($crate::checked_expr_as!(@unit $param)),*]),
+            "expression template placeholder count does not match the number of arguments"
+        );
+        $crate::expression::core::Expression::<$t>::new(
+            $template,
+            vec![$($crate::expr_param!($param)),*],
+        )
+    }};
+
+    (@unit $param:tt) => {
+        ()
+    };
+}
+
+/// Number of `{}` placeholders in `template`. Used by [`checked_expr_as!`]
+/// in a const context.
+#[doc(hidden)]
+pub const fn placeholder_count(template: &str) -> usize {
+    let bytes = template.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            count += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
 /// Helper macro to handle different parameter syntaxes.
 ///
 /// - `(expr)` — nested expression (calls `.expr()` via `Expressive`)
@@ -133,6 +188,14 @@ mod tests {
         assert_eq!(outer.parameters.len(), 2);
     }
 
+    #[test]
+    fn test_checked_expr_as_macro() {
+        let inner = expr!("status = {}", "active");
+        let expr = checked_expr_as!(serde_json::Value, "{} AND age > {}", (inner), 21);
+        assert_eq!(expr.preview(), "status = \"active\" AND age > 21");
+        assert_eq!(checked_expr_as!(i32, "count()").parameters.len(), 0);
+    }
+
     #[test]
     fn test_preview() {
         let expr = expr_as!(String, "Hello {}", "world");
@@ -43,6 +43,9 @@ where
 
         // Deferred values need to be wrapped in a conversion closure
         ExpressiveEnum::Deferred(deferred) => ExpressiveEnum::Deferred(map_deferred_fn(deferred)),
+
+        // Raw text carries no value
+        ExpressiveEnum::Raw(sql) => ExpressiveEnum::Raw(sql),
     }
 }
 
@@ -52,10 +55,12 @@ where
     From: Into<To> + Send + Clone + 'static,
     To: Send + 'static,
 {
-    DeferredFn::new(move || {
+    // Forward the context so context-aware deferreds survive mapping
+    DeferredFn::with_context(move |ctx| {
         let deferred = deferred.clone();
+        let ctx = ctx.clone();
         Box::pin(async move {
-            let result = deferred.call().await?;
+            let result = deferred.call_with(&ctx).await?;
             Ok(map_expressive_enum(result))
         }) as DeferredFuture<To>
     })
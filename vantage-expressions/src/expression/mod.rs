@@ -2,9 +2,14 @@
 //!
 //! Modules:
 //! - [`core`] - Core `Expression<T>` struct
+//! - [`ast`] - JSON form of the expression tree
 //! - [`macros`] - `expr!` and `expr_as!` macros
 //! - [`mapping`] - Type conversion utilities
 //! - [`flatten`] - Expression flattening
+//! - [`resolve`] - Resolving deferred parameters against a context
+//! - [`batch`] - Several queries in one round-trip
+//! - [`cache`] - Memoized rendering keyed by structural hash
+//! - [`condition`] - Compound AND / OR / NOT conditions
 //!
 //! ## Expression
 //!
@@ -52,7 +57,12 @@
 //! // Template becomes: "SELECT * FROM users WHERE age > {} AND status = {}"
 //! ```
 
+pub mod ast;
+pub mod batch;
+pub mod cache;
+pub mod condition;
 pub mod core;
 pub mod flatten;
 pub mod macros;
 pub mod mapping;
+pub mod resolve;
@@ -0,0 +1,177 @@
+//! Resolving deferred parameters against a context.
+//!
+//! Backends resolve [`DeferredFn`](crate::DeferredFn) parameters right before
+//! executing a query. [`Expression::resolve_deferred`] makes that step explicit:
+//! a query can be assembled before values such as the current tenant id are
+//! known, then resolved once a [`ResolutionContext`] carrying them exists.
+//!
+//! ```rust
+//! use vantage_expressions::prelude::*;
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let tenant = DeferredFn::with_context(|ctx: &ResolutionContext| {
+//!     let id = ctx.get::<i64>("tenant_id").copied();
+//!     Box::pin(async move {
+//!         id.map(|id| ExpressiveEnum::Scalar(json!(id)))
+//!             .ok_or_else(|| vantage_core::error!("tenant_id is not set"))
+//!     })
+//! });
+//! let query = expr!("SELECT * FROM orders WHERE tenant_id = {}", { tenant });
+//!
+//! let ctx = ResolutionContext::new().with("tenant_id", 42i64);
+//! let resolved = query.resolve_deferred(&ctx).await.unwrap();
+//! assert_eq!(resolved.preview(), "SELECT * FROM orders WHERE tenant_id = 42");
+//! # });
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+
+use vantage_core::Result;
+
+use crate::expression::core::Expression;
+use crate::traits::expressive::ExpressiveEnum;
+
+/// Named values available to deferred parameters while they resolve.
+///
+/// Values are stored type-erased; read them back with [`Self::get`] using the
+/// type they were inserted as. Cloning is cheap — values are shared.
+#[derive(Clone, Default)]
+pub struct ResolutionContext {
+    values: HashMap<String, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ResolutionContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `value` under `key`, replacing an earlier value.
+    pub fn insert<V: Any + Send + Sync>(&mut self, key: impl Into<String>, value: V) {
+        self.values.insert(key.into(), Arc::new(value));
+    }
+
+    /// Builder form of [`Self::insert`].
+    pub fn with<V: Any + Send + Sync>(mut self, key: impl Into<String>, value: V) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// The value under `key`, if present and stored as a `V`.
+    pub fn get<V: Any>(&self, key: &str) -> Option<&V> {
+        self.values.get(key)?.downcast_ref::<V>()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+}
+
+impl Debug for ResolutionContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.values.keys()).finish()
+    }
+}
+
+impl<T: Clone> Expression<T> {
+    /// Replace every `Deferred` parameter, at any nesting depth, with the
+    /// value it computes under `ctx`. Scalars are kept as they are, and
+    /// nested expressions are kept with their own deferreds resolved. A
+    /// deferred that returns an expression is resolved in turn.
+    ///
+    /// Fails with the first error a deferred returns.
+    pub async fn resolve_deferred(&self, ctx: &ResolutionContext) -> Result<Expression<T>> {
+        let mut parameters = Vec::with_capacity(self.parameters.len());
+        for param in &self.parameters {
+            let resolved = match param {
+                ExpressiveEnum::Deferred(deferred) => deferred.call_with(ctx).await?,
+                other => other.clone(),
+            };
+            parameters.push(match resolved {
+                ExpressiveEnum::Nested(inner) => {
+                    ExpressiveEnum::Nested(Box::pin(inner.resolve_deferred(ctx)).await?)
+                }
+                other => other,
+            });
+        }
+        Ok(Expression::new(self.template.clone(), parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::mapping::ExpressionMap;
+    use crate::traits::expressive::DeferredFn;
+    use crate::{expr, expr_as};
+    use serde_json::{Value, json};
+
+    /// Deferred that reads the tenant id from the context.
+    fn tenant_id() -> DeferredFn<Value> {
+        DeferredFn::with_context(|ctx| {
+            let id = ctx.get::<i64>("tenant_id").copied();
+            Box::pin(async move {
+                id.map(|id| ExpressiveEnum::Scalar(json!(id)))
+                    .ok_or_else(|| vantage_core::error!("tenant_id is not set"))
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_resolves_scalar_from_context() {
+        let query = expr!(
+            "SELECT * FROM orders WHERE tenant_id = {} AND status = {}",
+            { tenant_id() },
+            "open"
+        );
+        let ctx = ResolutionContext::new().with("tenant_id", 7i64);
+
+        let resolved = query.resolve_deferred(&ctx).await.unwrap();
+        assert!(matches!(
+            resolved.parameters[0],
+            ExpressiveEnum::Scalar(Value::Number(_))
+        ));
+        assert_eq!(
+            resolved.preview(),
+            r#"SELECT * FROM orders WHERE tenant_id = 7 AND status = "open""#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolves_nested_and_reports_missing_value() {
+        let condition = expr!("tenant_id = {}", { tenant_id() });
+        let query = expr!("SELECT * FROM orders WHERE {}", (condition));
+
+        let resolved = query
+            .resolve_deferred(&ResolutionContext::new().with("tenant_id", 3i64))
+            .await
+            .unwrap();
+        assert_eq!(
+            resolved.preview(),
+            "SELECT * FROM orders WHERE tenant_id = 3"
+        );
+
+        assert!(
+            query
+                .resolve_deferred(&ResolutionContext::new())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_context_survives_mapping() {
+        let deferred = DeferredFn::<String>::with_context(|ctx| {
+            let region = ctx.get::<String>("region").cloned().unwrap_or_default();
+            Box::pin(async move { Ok(ExpressiveEnum::Scalar(region)) })
+        });
+        let query: crate::Expression<Value> = expr_as!(String, "region = {}", { deferred }).map();
+
+        let ctx = ResolutionContext::new().with("region", "eu".to_string());
+        let resolved = query.resolve_deferred(&ctx).await.unwrap();
+        assert_eq!(resolved.preview(), r#"region = "eu""#);
+    }
+}
@@ -9,16 +9,20 @@ pub mod util;
 pub mod value;
 
 // pub use expression::lazy::LazyExpression;
+pub use expression::batch::{BatchExpression, BatchResponse, BatchResult};
+pub use expression::cache::RenderCache;
+pub use expression::condition::{Condition, ConditionField, col};
 pub use expression::core::Expression;
-pub use expression::flatten::{ExpressionFlattener, Flatten};
+pub use expression::flatten::{ExpressionFlattener, Flatten, ParamStyle};
 pub use expression::mapping::{ExpressionMap, ExpressionMapper};
+pub use expression::resolve::ResolutionContext;
 pub use traits::associated_expressions::AssociatedExpression;
 pub use traits::associated_queryable::AssociatedQueryable;
 pub use traits::datasource::ExprDataSource;
 pub use traits::datasource::SelectableDataSource;
 pub use traits::expressive::{DeferredFn, Expressive, ExpressiveEnum};
 pub use traits::expressive_or::ExpressiveOr;
-pub use traits::selectable::{Nulls, Order, Selectable};
+pub use traits::selectable::{JoinKind, Nulls, Order, Selectable};
 pub use vantage_vista::{AnyExpression, ExpressionLike};
 
 pub use traits::result;
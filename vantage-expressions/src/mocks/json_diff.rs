@@ -0,0 +1,163 @@
+//! Structural JSON comparison for expression previews.
+//!
+//! Backends whose queries render as JSON (MongoDB documents, SurrealDB
+//! objects) are easier to test structurally than by string: key order and
+//! whitespace stop mattering, and a failure names the exact place that
+//! differs as a JSON pointer instead of dumping two long strings.
+//!
+//! ```rust
+//! use vantage_expressions::{expr, mocks::assert_expr_json_eq};
+//! use serde_json::json;
+//!
+//! let query = expr!(r#"{"price": {"$gt": 100}, "active": true}"#);
+//! assert_expr_json_eq(&query, json!({"active": true, "price": {"$gt": 100}}));
+//! ```
+
+use std::fmt::{Debug, Display};
+
+use serde_json::Value;
+
+use crate::Expression;
+
+/// First point where two JSON values disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonDifference {
+    /// JSON pointer (RFC 6901) to the differing value; `""` is the root.
+    pub pointer: String,
+    /// Value found in the actual document (`None` if the key/index is missing).
+    pub actual: Option<Value>,
+    /// Value expected at that place (`None` if the key/index shouldn't exist).
+    pub expected: Option<Value>,
+}
+
+impl Display for JsonDifference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |v: &Option<Value>| match v {
+            Some(v) => v.to_string(),
+            None => "<missing>".to_string(),
+        };
+        let pointer = if self.pointer.is_empty() {
+            "<root>"
+        } else {
+            &self.pointer
+        };
+        write!(
+            f,
+            "JSON differs at {}: expected {}, got {}",
+            pointer,
+            show(&self.expected),
+            show(&self.actual)
+        )
+    }
+}
+
+/// Compare two JSON values structurally (object key order is ignored) and
+/// return the first difference, walking depth-first in `expected` order.
+pub fn json_diff(actual: &Value, expected: &Value) -> Option<JsonDifference> {
+    diff_at(String::new(), Some(actual), Some(expected))
+}
+
+fn diff_at(
+    pointer: String,
+    actual: Option<&Value>,
+    expected: Option<&Value>,
+) -> Option<JsonDifference> {
+    match (actual, expected) {
+        (Some(Value::Object(a)), Some(Value::Object(e))) => {
+            for (key, value) in e {
+                if let Some(diff) = diff_at(child(&pointer, key), a.get(key), Some(value)) {
+                    return Some(diff);
+                }
+            }
+            a.iter()
+                .find(|(key, _)| !e.contains_key(*key))
+                .map(|(key, value)| JsonDifference {
+                    pointer: child(&pointer, key),
+                    actual: Some(value.clone()),
+                    expected: None,
+                })
+        }
+        (Some(Value::Array(a)), Some(Value::Array(e))) => (0..a.len().max(e.len()))
+            .find_map(|i| diff_at(child(&pointer, &i.to_string()), a.get(i), e.get(i))),
+        (a, e) if a == e => None,
+        (a, e) => Some(JsonDifference {
+            pointer,
+            actual: a.cloned(),
+            expected: e.cloned(),
+        }),
+    }
+}
+
+/// Append an escaped reference token to a JSON pointer.
+fn child(pointer: &str, token: &str) -> String {
+    format!(
+        "{}/{}",
+        pointer,
+        token.replace('~', "~0").replace('/', "~1")
+    )
+}
+
+/// Assert that an expression's preview is JSON structurally equal to
+/// `expected`. Panics naming the JSON pointer of the first difference, or
+/// if the preview doesn't parse as JSON.
+#[track_caller]
+pub fn assert_expr_json_eq<T: Display + Debug>(expr: &Expression<T>, expected: Value) {
+    let preview = expr.preview();
+    let actual: Value = serde_json::from_str(&preview)
+        .unwrap_or_else(|e| panic!("expression preview is not JSON ({}): {}", e, preview));
+    if let Some(diff) = json_diff(&actual, &expected) {
+        panic!("{}\n  preview: {}", diff, preview);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use serde_json::json;
+
+    #[test]
+    fn test_equal_ignores_key_order() {
+        let a = json!({"a": 1, "b": [1, {"c": null}]});
+        let e = json!({"b": [1, {"c": null}], "a": 1});
+        assert_eq!(json_diff(&a, &e), None);
+    }
+
+    #[test]
+    fn test_reports_pointer() {
+        let a = json!({"$match": {"tags": ["x", "y"], "a/b": 1}});
+
+        let diff = json_diff(&a, &json!({"$match": {"tags": ["x", "z"], "a/b": 1}})).unwrap();
+        assert_eq!(diff.pointer, "/$match/tags/1");
+        assert_eq!(diff.actual, Some(json!("y")));
+        assert_eq!(diff.expected, Some(json!("z")));
+
+        // Missing, extra and escaped keys
+        let diff = json_diff(
+            &a,
+            &json!({"$match": {"tags": ["x", "y"], "a/b": 1, "n": 0}}),
+        );
+        assert_eq!(diff.unwrap().pointer, "/$match/n");
+        let diff = json_diff(&a, &json!({"$match": {"tags": ["x", "y"]}})).unwrap();
+        assert_eq!(diff.pointer, "/$match/a~1b");
+        assert_eq!(diff.expected, None);
+        let diff = json_diff(&a, &json!({"$match": {"tags": ["x"], "a/b": 1}})).unwrap();
+        assert_eq!(
+            diff.to_string(),
+            r#"JSON differs at /$match/tags/1: expected <missing>, got "y""#
+        );
+    }
+
+    #[test]
+    fn test_assert_expr_json_eq_passes() {
+        let e = expr!(r#"{"price": {"$gt": 100}, "active": true}"#);
+        assert_expr_json_eq(&e, json!({"active": true, "price": {"$gt": 100}}));
+    }
+
+    #[test]
+    #[should_panic(expected = "JSON differs at /price/$gt: expected 200, got 100")]
+    fn test_assert_expr_json_eq_mismatch() {
+        let e = expr!(r#"{"price": {"$gt": 100}}"#);
+        assert_expr_json_eq(&e, json!({"price": {"$gt": 200}}));
+    }
+}
@@ -4,6 +4,7 @@
 //! - [`datasource`] - DataSource trait mock implementations
 //! - [`select`] - MockSelect query builder
 //! - [`mockbuilder`] - Pattern-based mock builder
+//! - [`json_diff`] - Structural JSON assertions on expression previews
 //!
 //! ## Mock Testing
 //!
@@ -24,10 +25,12 @@
 //! ```
 
 pub mod datasource;
+pub mod json_diff;
 pub mod mock_builder;
 pub mod select;
 
 pub use datasource::{MockDataSource, MockExprDataSource, MockSelectableDataSource};
+pub use json_diff::{JsonDifference, assert_expr_json_eq, json_diff};
 pub use mock_builder::MockBuilder;
 pub use select::MockSelect;
 
@@ -237,6 +237,11 @@ impl Selectable<serde_json::Value> for MockSelect {
         let source = self.source.as_ref().unwrap().as_str();
         expr!("SELECT MIN({}) FROM {}", (column), source)
     }
+
+    fn as_avg(&self, column: impl Expressive<Value>) -> Expression<Value> {
+        let source = self.source.as_ref().unwrap().as_str();
+        expr!("SELECT AVG({}) FROM {}", (column), source)
+    }
 }
 
 impl From<MockSelect> for Expression<serde_json::Value> {
@@ -286,4 +291,60 @@ mod tests {
         let query: Expression<serde_json::Value> = select.into();
         assert_eq!(query.preview(), "SELECT name FROM users WHERE age > 18");
     }
+
+    #[test]
+    fn test_mock_select_with_where_not() {
+        let mut select = MockSelect::new();
+        select.add_source("users", None);
+        select.add_where_not(expr!("archived = {}", true));
+
+        let conditions = select.where_conditions();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].preview(), "NOT (archived = true)");
+    }
+
+    #[test]
+    fn test_mock_select_with_where_exists() {
+        let orders = MockSelect::new()
+            .with_source("orders")
+            .with_condition(expr!("orders.user_id = users.id"));
+        let mut select = MockSelect::new();
+        select.add_source("users", None);
+        select.add_where_exists(orders.clone());
+        select.add_where_not_exists(orders);
+
+        let conditions = select.where_conditions();
+        assert_eq!(
+            conditions[0].preview(),
+            "EXISTS (SELECT * FROM orders WHERE orders.user_id = users.id)"
+        );
+        assert_eq!(
+            conditions[1].preview(),
+            "NOT EXISTS (SELECT * FROM orders WHERE orders.user_id = users.id)"
+        );
+    }
+
+    #[test]
+    fn test_mock_select_with_where_between() {
+        let mut select = MockSelect::new();
+        select.add_source("orders", None);
+        select.add_where_between("total", expr!("{}", 10), expr!("{}", 20));
+
+        let conditions = select.where_conditions();
+        assert_eq!(conditions[0].preview(), "total BETWEEN 10 AND 20");
+    }
+
+    #[test]
+    fn test_mock_select_with_where_eq_null_safe() {
+        let mut select = MockSelect::new();
+        select.add_source("users", None);
+        let null = serde_json::Value::Null;
+        select.add_where_eq_null_safe("manager_id", expr!("{}", null));
+
+        let conditions = select.where_conditions();
+        assert_eq!(
+            conditions[0].preview(),
+            "manager_id IS NOT DISTINCT FROM null"
+        );
+    }
 }
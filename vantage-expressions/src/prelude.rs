@@ -24,4 +24,4 @@ pub use crate::traits::selectable::Selectable;
 pub use crate::{expr, expr_any, expr_as};
 
 // Expression mapping and flattening
-pub use crate::{ExpressionFlattener, ExpressionMap, Flatten};
+pub use crate::{ExpressionFlattener, ExpressionMap, Flatten, ParamStyle, ResolutionContext};
@@ -5,6 +5,7 @@ use crate::Expression;
 use crate::Selectable;
 use crate::traits::associated_expressions::AssociatedExpression;
 use crate::traits::expressive::DeferredFn;
+use crate::traits::selectable::JoinKind;
 use vantage_core::Result;
 
 /// DataSource can be referenced by other objects, and will help associate them
@@ -63,4 +64,18 @@ pub trait SelectableDataSource<T = Value, C = Expression<T>>: DataSource {
         }
         select.add_expression(expression);
     }
+
+    /// Join `table` to a select query, matching rows on `on`. Columns of the
+    /// joined table are reachable as `table.column`. Backends must override
+    /// this if they support joins.
+    fn add_select_join(
+        &self,
+        select: &mut Self::Select,
+        kind: JoinKind,
+        table: &str,
+        on: Expression<T>,
+    ) {
+        let _ = (select, kind, table, on);
+        panic!("add_select_join not implemented for this backend");
+    }
 }
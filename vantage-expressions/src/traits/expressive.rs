@@ -5,9 +5,12 @@ use std::sync::{Arc, Mutex};
 use vantage_core::Result;
 
 use crate::expression::core::Expression;
+use crate::expression::resolve::ResolutionContext;
 
 pub type DeferredFuture<T> = Pin<Box<dyn Future<Output = Result<ExpressiveEnum<T>>> + Send>>;
 pub type DeferredCallback<T> = Arc<dyn Fn() -> DeferredFuture<T> + Send + Sync>;
+pub type DeferredContextCallback<T> =
+    Arc<dyn Fn(&ResolutionContext) -> DeferredFuture<T> + Send + Sync>;
 
 /// A deferred function that can be executed asynchronously within expressions.
 ///
@@ -178,19 +181,48 @@ pub type DeferredCallback<T> = Arc<dyn Fn() -> DeferredFuture<T> + Send + Sync>;
 /// ```
 #[derive(Clone)]
 pub struct DeferredFn<T> {
-    func: DeferredCallback<T>,
+    func: DeferredContextCallback<T>,
 }
 
 impl<T> DeferredFn<T> {
+    /// Address of the shared callback. Clones of one `DeferredFn` share it,
+    /// so it identifies the deferred without calling it.
+    pub(crate) fn identity(&self) -> usize {
+        Arc::as_ptr(&self.func) as *const () as usize
+    }
+
+    /// Whether both share one callback, i.e. have the same identity.
+    pub(crate) fn same_callback(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.func, &other.func)
+    }
+
     pub fn new<F>(f: F) -> Self
     where
         F: Fn() -> DeferredFuture<T> + Send + Sync + 'static,
+    {
+        Self {
+            func: Arc::new(move |_ctx: &ResolutionContext| f()),
+        }
+    }
+
+    /// Create a DeferredFn that reads values from the [`ResolutionContext`]
+    /// passed to [`Expression::resolve_deferred`]. The closure receives the
+    /// context synchronously and must copy what it needs into the future.
+    pub fn with_context<F>(f: F) -> Self
+    where
+        F: Fn(&ResolutionContext) -> DeferredFuture<T> + Send + Sync + 'static,
     {
         Self { func: Arc::new(f) }
     }
 
+    /// Execute with an empty context.
     pub async fn call(&self) -> Result<ExpressiveEnum<T>> {
-        (self.func)().await
+        self.call_with(&ResolutionContext::new()).await
+    }
+
+    /// Execute with the given context.
+    pub async fn call_with(&self, ctx: &ResolutionContext) -> Result<ExpressiveEnum<T>> {
+        (self.func)(ctx).await
     }
 
     /// Create a DeferredFn that reads from an `Arc<Mutex<T>>` when executed
@@ -239,6 +271,10 @@ pub enum ExpressiveEnum<T> {
     Scalar(T),
     Nested(Expression<T>),
     Deferred(DeferredFn<T>),
+    /// Trusted query text, rendered verbatim and never bound as a parameter.
+    /// Built with [`Expression::raw`]; kept as its own variant so callers can
+    /// spot it (see [`Expression::contains_raw`]).
+    Raw(String),
 }
 
 impl<T: Debug + std::fmt::Display> Debug for ExpressiveEnum<T> {
@@ -249,6 +285,7 @@ impl<T: Debug + std::fmt::Display> Debug for ExpressiveEnum<T> {
             ExpressiveEnum::Deferred(deferred) => {
                 f.debug_tuple("Deferred").field(deferred).finish()
             }
+            ExpressiveEnum::Raw(sql) => f.debug_tuple("Raw").field(sql).finish(),
         }
     }
 }
@@ -340,6 +377,7 @@ impl<T: Clone> Clone for ExpressiveEnum<T> {
             ExpressiveEnum::Scalar(val) => ExpressiveEnum::Scalar(val.clone()),
             ExpressiveEnum::Nested(expr) => ExpressiveEnum::Nested(expr.clone()),
             ExpressiveEnum::Deferred(f) => ExpressiveEnum::Deferred(f.clone()),
+            ExpressiveEnum::Raw(sql) => ExpressiveEnum::Raw(sql.clone()),
         }
     }
 }
@@ -363,6 +401,7 @@ impl<T: std::fmt::Debug + std::fmt::Display> ExpressiveEnum<T> {
             ExpressiveEnum::Scalar(val) => format!("{}", val),
             ExpressiveEnum::Nested(expr) => format!("{:?}", expr),
             ExpressiveEnum::Deferred(_) => "**deferred()".to_string(),
+            ExpressiveEnum::Raw(sql) => sql.clone(),
         }
     }
 }
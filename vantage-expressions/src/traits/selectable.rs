@@ -15,6 +15,16 @@ pub struct Order {
     pub nulls: Option<Nulls>,
 }
 
+/// How a joined table's rows combine with the query's own, see
+/// [`SelectableDataSource::add_select_join`](crate::SelectableDataSource::add_select_join).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinKind {
+    /// Keep only rows with a match in the joined table.
+    Inner,
+    /// Keep every row; the joined table's columns are NULL without a match.
+    Left,
+}
+
 /// NULL placement in ORDER BY.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Nulls {
@@ -152,6 +162,9 @@ pub trait Selectable<T, C = Expression<T>>: Send + Sync + Debug + Clone {
     /// Creates a MIN(column) expression from this query configuration.
     fn as_min(&self, column: impl Expressive<T>) -> Expression<T>;
 
+    /// Creates an AVG(column) expression from this query configuration.
+    fn as_avg(&self, column: impl Expressive<T>) -> Expression<T>;
+
     // Default implementations for builder-style methods
 
     /// Builder pattern method identical to [`Self::add_source`] without alias.
@@ -172,6 +185,68 @@ pub trait Selectable<T, C = Expression<T>>: Send + Sync + Debug + Clone {
         self
     }
 
+    /// Adds `NOT (condition)` to the WHERE clause.
+    fn add_where_not(&mut self, condition: Expression<T>)
+    where
+        Expression<T>: Into<C>,
+    {
+        self.add_where_condition(Expression::not(condition));
+    }
+
+    /// Adds `EXISTS (subquery)` to the WHERE clause. Correlate the subquery
+    /// by referring to the outer query's columns in its own conditions.
+    fn add_where_exists(&mut self, subquery: impl Expressive<T>)
+    where
+        Expression<T>: Into<C>,
+    {
+        self.add_where_condition(Expression::new(
+            "EXISTS ({})",
+            vec![ExpressiveEnum::Nested(subquery.expr())],
+        ));
+    }
+
+    /// Adds `NOT EXISTS (subquery)` to the WHERE clause.
+    fn add_where_not_exists(&mut self, subquery: impl Expressive<T>)
+    where
+        Expression<T>: Into<C>,
+    {
+        self.add_where_condition(Expression::new(
+            "NOT EXISTS ({})",
+            vec![ExpressiveEnum::Nested(subquery.expr())],
+        ));
+    }
+
+    /// Adds `column BETWEEN lo AND hi` to the WHERE clause, both bounds
+    /// inclusive. Scalar bounds stay parameters; the column name is used as
+    /// written unless the backend overrides this to quote it.
+    fn add_where_between(&mut self, column: &str, lo: impl Expressive<T>, hi: impl Expressive<T>)
+    where
+        Expression<T>: Into<C>,
+    {
+        self.add_where_condition(Expression::new(
+            "{} BETWEEN {} AND {}",
+            vec![
+                ExpressiveEnum::Nested(Expression::new(column, vec![])),
+                ExpressiveEnum::Nested(lo.expr()),
+                ExpressiveEnum::Nested(hi.expr()),
+            ],
+        ));
+    }
+
+    /// Adds a NULL-safe `column = value` to the WHERE clause: a NULL column
+    /// matches a NULL value instead of dropping the row. The default renders
+    /// [`Expression::eq_null_safe`]; backends override it with their own
+    /// operator and quote the column.
+    fn add_where_eq_null_safe(&mut self, column: &str, value: impl Expressive<T>)
+    where
+        Expression<T>: Into<C>,
+    {
+        self.add_where_condition(Expression::eq_null_safe(
+            Expression::new(column, vec![]),
+            value.expr(),
+        ));
+    }
+
     /// Builder pattern method identical to [`Self::add_where_condition`].
     fn with_condition(mut self, condition: impl Into<C>) -> Self
     where
@@ -65,6 +65,7 @@ pub(crate) fn resolve_param(
                     Ok(CborValue::Text(expr.template.clone()))
                 }
             }
+            ExpressiveEnum::Raw(sql) => Ok(CborValue::Text(sql.clone())),
         }
     })
 }
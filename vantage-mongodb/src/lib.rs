@@ -17,7 +17,7 @@ pub mod vista;
 pub use condition::MongoCondition;
 pub use id::MongoId;
 pub use mongodb::MongoDB;
-pub use select::MongoSelect;
+pub use select::{MongoFind, MongoSelect};
 pub use types::{AnyMongoType, MongoType, MongoTypeVariants};
 #[cfg(feature = "vista")]
 pub use vista::{MongoTableShell, MongoVistaFactory};
@@ -49,7 +49,7 @@ fn select_from_table<E: Entity<AnyMongoType>>(table: &Table<MongoDB, E>) -> Mong
         select.conditions.push(condition.clone());
     }
 
-    for (cond, direction) in table.orders() {
+    for (cond, direction) in table.effective_orders() {
         // Order entries are MongoCondition — extract field name, apply direction
         if let MongoCondition::Doc(doc) = cond
             && let Some((key, _)) = doc.iter().next()
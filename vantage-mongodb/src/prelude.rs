@@ -21,5 +21,5 @@ pub use crate::condition::MongoCondition;
 pub use crate::id::MongoId;
 pub use crate::mongodb::MongoDB;
 pub use crate::operation::MongoOperation;
-pub use crate::select::MongoSelect;
+pub use crate::select::{MongoFind, MongoSelect};
 pub use crate::types::{AnyMongoType, MongoType};
@@ -0,0 +1,157 @@
+//! Typed execution of `MongoSelect`: run the built find against a connection
+//! and deserialize each returned document into a caller-chosen type.
+//!
+//! The connection side is the [`MongoFind`] trait, so the mapping can be
+//! exercised without a server — [`MongoDB`] implements it with the driver.
+
+use std::future::Future;
+
+use bson::Document;
+use futures_util::TryStreamExt;
+use serde::de::DeserializeOwned;
+use vantage_core::{Result, error};
+
+use super::MongoSelect;
+use crate::mongodb::MongoDB;
+
+/// A connection that can run a [`MongoSelect`] as a `find`.
+pub trait MongoFind {
+    /// Run `select` and return the matching documents as stored.
+    fn find_documents(
+        &self,
+        select: &MongoSelect,
+    ) -> impl Future<Output = Result<Vec<Document>>> + Send;
+}
+
+impl MongoFind for MongoDB {
+    async fn find_documents(&self, select: &MongoSelect) -> Result<Vec<Document>> {
+        let coll_name = select
+            .collection
+            .as_deref()
+            .ok_or_else(|| error!("MongoSelect has no collection set"))?;
+
+        let filter = select.build_filter().await?;
+        let options = select.build_find_options();
+        let coll = self.doc_collection(coll_name);
+
+        let cursor = coll
+            .find(filter)
+            .with_options(options)
+            .await
+            .map_err(|e| error!("MongoDB find failed", details = e.to_string()))?;
+
+        cursor
+            .try_collect()
+            .await
+            .map_err(|e| error!("MongoDB find cursor failed", details = e.to_string()))
+    }
+}
+
+impl MongoSelect {
+    /// Execute the find on `conn` and deserialize every document into `T`.
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Product { name: String, price: i64 }
+    ///
+    /// let products: Vec<Product> = MongoSelect::new()
+    ///     .with_source("product")
+    ///     .with_condition(doc! { "price": { "$gt": 100 } })
+    ///     .find_typed(&db)
+    ///     .await?;
+    /// ```
+    pub async fn find_typed<T: DeserializeOwned>(&self, conn: &impl MongoFind) -> Result<Vec<T>> {
+        conn.find_documents(self)
+            .await?
+            .into_iter()
+            .map(|doc| {
+                let id = doc.get("_id").map(|id| id.to_string()).unwrap_or_default();
+                bson::from_document(doc).map_err(|e| {
+                    error!(
+                        "Failed to deserialize MongoDB document",
+                        id = id,
+                        details = e.to_string()
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bson::doc;
+    use serde::Deserialize;
+    use vantage_expressions::Selectable;
+
+    use super::*;
+
+    /// Connection returning canned documents for one collection.
+    struct MockConnection {
+        collection: &'static str,
+        docs: Vec<Document>,
+    }
+
+    impl MongoFind for MockConnection {
+        async fn find_documents(&self, select: &MongoSelect) -> Result<Vec<Document>> {
+            assert_eq!(select.collection.as_deref(), Some(self.collection));
+            Ok(self.docs.clone())
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Product {
+        name: String,
+        price: i64,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    #[tokio::test]
+    async fn test_find_typed() {
+        let conn = MockConnection {
+            collection: "product",
+            docs: vec![
+                doc! { "_id": 1, "name": "Cupcake", "price": 120, "tags": ["sweet"] },
+                doc! { "_id": 2, "name": "Bagel", "price": 80 },
+            ],
+        };
+
+        let products: Vec<Product> = MongoSelect::new()
+            .with_source("product")
+            .find_typed(&conn)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            products,
+            vec![
+                Product {
+                    name: "Cupcake".into(),
+                    price: 120,
+                    tags: vec!["sweet".into()],
+                },
+                Product {
+                    name: "Bagel".into(),
+                    price: 80,
+                    tags: vec![],
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_typed_shape_mismatch() {
+        let conn = MockConnection {
+            collection: "product",
+            docs: vec![doc! { "_id": 7, "name": "Cupcake", "price": "cheap" }],
+        };
+
+        let err = MongoSelect::new()
+            .with_source("product")
+            .find_typed::<Product>(&conn)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("deserialize"), "{}", err);
+    }
+}
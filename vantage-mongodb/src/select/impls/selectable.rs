@@ -131,4 +131,12 @@ impl Selectable<AnyMongoType, MongoCondition> for MongoSelect {
             vec![],
         )
     }
+
+    fn as_avg(&self, column: impl Expressive<AnyMongoType>) -> Expression<AnyMongoType> {
+        let coll = self.collection.as_deref().unwrap_or("?");
+        Expression::new(
+            format!("db.{}.aggregate($avg: {})", coll, column.preview()),
+            vec![],
+        )
+    }
 }
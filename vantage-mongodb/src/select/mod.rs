@@ -5,12 +5,15 @@
 //! `Selectable<AnyMongoType, MongoCondition>` so `table.select()` works.
 
 pub mod builder;
+pub mod find;
 pub mod impls;
 pub mod pipeline;
 pub mod render;
 
 use crate::condition::MongoCondition;
 
+pub use find::MongoFind;
+
 /// MongoDB query builder — the equivalent of `SqliteSelect` / `SurrealSelect`.
 ///
 /// Instead of rendering SQL, it accumulates native `bson::Document` parts
@@ -15,6 +15,7 @@
 //! - Conditions limited to `eq` / `in_` on indexed columns (or the table's
 //!   id column, which short-circuits to a direct main-table lookup).
 //! - No query builder — redb has no query language.
+//! - [`Redb::compact`] and [`Redb::stats`] for long-running databases.
 
 pub mod condition;
 pub mod operation;
@@ -25,4 +26,5 @@ pub mod types;
 pub use condition::RedbCondition;
 pub use operation::RedbOperation;
 pub use redb::Redb;
+pub use redb::maintenance::DbStats;
 pub use types::{AnyRedbType, RedbType, RedbTypeVariants};
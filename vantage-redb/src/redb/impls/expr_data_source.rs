@@ -50,6 +50,9 @@ impl ExprDataSource<AnyRedbType> for Redb {
                         ExpressiveEnum::Nested(_) => Err(vantage_core::error!(
                             "Redb execute: only one level of nesting supported"
                         )),
+                        ExpressiveEnum::Raw(sql) => {
+                            Ok(AnyRedbType::untyped(ciborium::Value::Text(sql.clone())))
+                        }
                     }
                 } else {
                     Err(vantage_core::error!(
@@ -57,6 +60,10 @@ impl ExprDataSource<AnyRedbType> for Redb {
                     ))
                 }
             }
+            // Raw text is returned as-is, like a parameterless expression
+            ExpressiveEnum::Raw(sql) => {
+                Ok(AnyRedbType::untyped(ciborium::Value::Text(sql.clone())))
+            }
         }
     }
 
@@ -0,0 +1,79 @@
+//! Housekeeping for long-running databases: compaction and size reporting.
+//!
+//! redb never shrinks its file on its own — deleted rows leave free pages
+//! behind for reuse. [`Redb::compact`] hands them back to the filesystem;
+//! [`Redb::stats`] gives monitoring a cheap snapshot of what is stored.
+
+use std::sync::Arc;
+
+use redb::{ReadableTableMetadata, TableHandle};
+use vantage_core::{Result, error};
+
+use crate::redb::{INDEX_TABLE_SEPARATOR, Redb};
+
+/// Storage snapshot returned by [`Redb::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbStats {
+    /// Size of the backing file in bytes. `None` when the database was
+    /// wrapped via [`Redb::from_database`] and its path is unknown.
+    pub file_size: Option<u64>,
+    /// Number of row tables. Secondary index tables are not counted.
+    pub table_count: usize,
+    /// Rows across all row tables. Index entries are not counted.
+    pub total_entries: u64,
+}
+
+impl Redb {
+    /// Compact the database file, returning free pages to the filesystem.
+    ///
+    /// Compaction needs exclusive access. It errors while another clone of
+    /// this handle is alive (a `Table` holds one) — any of them could open a
+    /// write transaction mid-compaction — and while a read transaction is
+    /// still open.
+    pub fn compact(&mut self) -> Result<()> {
+        let db = Arc::get_mut(&mut self.db).ok_or_else(|| {
+            error!("Cannot compact redb database while other handles to it are alive")
+        })?;
+        db.compact()
+            .map_err(|e| error!("Failed to compact redb database", details = e.to_string()))?;
+        Ok(())
+    }
+
+    /// Report file size, table count and row count.
+    pub fn stats(&self) -> Result<DbStats> {
+        let file_size = match &self.path {
+            Some(path) => Some(
+                std::fs::metadata(path)
+                    .map_err(|e| error!("Failed to stat redb file", details = e.to_string()))?
+                    .len(),
+            ),
+            None => None,
+        };
+
+        let txn = self.begin_read()?;
+        let handles = txn
+            .list_tables()
+            .map_err(|e| error!("Failed to list redb tables", details = e.to_string()))?;
+
+        let mut table_count = 0;
+        let mut total_entries = 0;
+        for handle in handles {
+            if handle.name().contains(INDEX_TABLE_SEPARATOR) {
+                continue;
+            }
+            let table = txn
+                .open_untyped_table(handle)
+                .map_err(|e| error!("Failed to open redb table", details = e.to_string()))?;
+            table_count += 1;
+            total_entries += table
+                .len()
+                .map_err(|e| error!("Failed to count redb table", details = e.to_string()))?;
+        }
+
+        Ok(DbStats {
+            file_size,
+            table_count,
+            total_entries,
+        })
+    }
+}
@@ -2,13 +2,14 @@
 //! the shared read/write helpers consumed by the trait impls in `impls/`.
 
 pub mod impls;
+pub mod maintenance;
 
 pub(crate) mod helpers;
 pub(crate) mod indexes;
 pub(crate) mod query;
 
 use redb::{Database, ReadTransaction, TableDefinition, WriteTransaction};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use vantage_core::{Result, error};
@@ -17,26 +18,40 @@ use vantage_core::{Result, error};
 #[derive(Clone, Debug)]
 pub struct Redb {
     db: Arc<Database>,
+    /// Backing file, when known — lets [`Self::stats`] report the file size.
+    /// `None` for a database wrapped via [`Self::from_database`].
+    path: Option<PathBuf>,
 }
 
 impl Redb {
     /// Open or create a redb database at the given path.
     pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let db = Database::create(path)
+        let path = path.as_ref().to_path_buf();
+        let db = Database::create(&path)
             .map_err(|e| error!("Failed to create redb database", details = e.to_string()))?;
-        Ok(Self { db: Arc::new(db) })
+        Ok(Self {
+            db: Arc::new(db),
+            path: Some(path),
+        })
     }
 
     /// Open an existing redb database. Errors if the file doesn't exist.
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let db = Database::open(path)
+        let path = path.as_ref().to_path_buf();
+        let db = Database::open(&path)
             .map_err(|e| error!("Failed to open redb database", details = e.to_string()))?;
-        Ok(Self { db: Arc::new(db) })
+        Ok(Self {
+            db: Arc::new(db),
+            path: Some(path),
+        })
     }
 
     /// Wrap an existing `redb::Database` (e.g. for in-memory tests via builder).
     pub fn from_database(db: Database) -> Self {
-        Self { db: Arc::new(db) }
+        Self {
+            db: Arc::new(db),
+            path: None,
+        }
     }
 
     /// Borrow the underlying database.
@@ -72,9 +87,13 @@ pub(crate) fn main_table_def(name: &str) -> TableDefinition<'_, &'static str, &'
 /// Secondary index: `(cbor value bytes, id) → ()`. Composite key gives us
 /// non-unique indexes for free via redb's range scan.
 pub(crate) fn index_table_name(table_name: &str, column_name: &str) -> String {
-    format!("{}__idx__{}", table_name, column_name)
+    format!("{}{}{}", table_name, INDEX_TABLE_SEPARATOR, column_name)
 }
 
+/// Separator between the row table's name and the column in an index
+/// table's name — also how [`Redb::stats`] tells index tables apart.
+pub(crate) const INDEX_TABLE_SEPARATOR: &str = "__idx__";
+
 pub(crate) fn index_table_def(
     name: &str,
 ) -> TableDefinition<'_, (&'static [u8], &'static str), ()> {
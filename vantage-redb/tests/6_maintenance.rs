@@ -0,0 +1,80 @@
+//! Test 6: compaction and size reporting on a long-lived database.
+
+use vantage_dataset::prelude::*;
+use vantage_redb::{AnyRedbType, Redb};
+use vantage_table::table::Table;
+use vantage_types::{EmptyEntity, Record};
+
+fn notes(db: Redb) -> Table<Redb, EmptyEntity> {
+    Table::<Redb, EmptyEntity>::new("notes", db)
+        .with_id_column("id")
+        .with_column_of::<String>("body")
+}
+
+fn body(text: String) -> Record<AnyRedbType> {
+    [("body".to_string(), AnyRedbType::new(text))]
+        .into_iter()
+        .collect()
+}
+
+#[tokio::test]
+async fn test_stats_counts_rows() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let db = Redb::create(tmp.path()).unwrap();
+
+    let table = notes(db.clone());
+    for i in 0..10 {
+        table
+            .insert_value(format!("n{i}"), &body(format!("note {i}")))
+            .await
+            .unwrap();
+    }
+
+    let stats = db.stats().unwrap();
+    assert_eq!(stats.table_count, 1);
+    assert_eq!(stats.total_entries, 10);
+    assert!(stats.file_size.unwrap() > 0);
+}
+
+#[tokio::test]
+async fn test_compact_after_mass_delete() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let mut db = Redb::create(tmp.path()).unwrap();
+
+    {
+        let table = notes(db.clone());
+        let padding = "x".repeat(2048);
+        for i in 0..500 {
+            table
+                .insert_value(format!("n{i}"), &body(format!("{i}{padding}")))
+                .await
+                .unwrap();
+        }
+        for i in 0..500 {
+            table.delete(format!("n{i}")).await.unwrap();
+        }
+    }
+
+    let before = db.stats().unwrap();
+    assert_eq!(before.total_entries, 0);
+
+    db.compact().unwrap();
+
+    let after = db.stats().unwrap();
+    assert_eq!(after.total_entries, 0);
+    assert!(
+        after.file_size.unwrap() <= before.file_size.unwrap(),
+        "compaction grew the file: {:?} -> {:?}",
+        before.file_size,
+        after.file_size
+    );
+}
+
+#[tokio::test]
+async fn test_compact_rejects_shared_handle() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let mut db = Redb::create(tmp.path()).unwrap();
+    let _table = notes(db.clone());
+
+    assert!(db.compact().is_err());
+}
@@ -24,3 +24,17 @@ macro_rules! mysql_expr {
         )
     };
 }
+
+/// [`mysql_expr!`] with a string-literal template whose `{}` placeholder
+/// count is checked against the arguments at compile time, see
+/// `vantage_expressions::checked_expr_as!`.
+///
+/// ```ignore
+/// let expr = mysql_query!("SELECT * FROM product WHERE price > {}", 100i64);
+/// ```
+#[macro_export]
+macro_rules! mysql_query {
+    ($template:literal $(, $param:tt)* $(,)?) => {
+        vantage_expressions::checked_expr_as!($crate::mysql::AnyMysqlType, $template $(, $param)*)
+    };
+}
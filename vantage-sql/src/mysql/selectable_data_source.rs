@@ -1,10 +1,12 @@
 use vantage_expressions::traits::datasource::SelectableDataSource;
-use vantage_expressions::{Expression, Expressive, Selectable};
+use vantage_expressions::{Expression, Expressive, JoinKind, Selectable};
 
 use crate::mysql::MysqlDB;
 use crate::mysql::statements::MysqlSelect;
+use crate::mysql::statements::select::join::MysqlSelectJoin;
 use crate::mysql::types::AnyMysqlType;
 use crate::primitives::alias::AliasExt;
+use crate::primitives::select::{JoinBuilder, SelectBuilder};
 
 impl SelectableDataSource<AnyMysqlType, crate::condition::MysqlCondition> for MysqlDB {
     type Select = MysqlSelect;
@@ -25,6 +27,20 @@ impl SelectableDataSource<AnyMysqlType, crate::condition::MysqlCondition> for My
         }
     }
 
+    fn add_select_join(
+        &self,
+        select: &mut Self::Select,
+        kind: JoinKind,
+        table: &str,
+        on: Expression<AnyMysqlType>,
+    ) {
+        let join = match kind {
+            JoinKind::Inner => MysqlSelectJoin::make_inner(table, table, on),
+            JoinKind::Left => MysqlSelectJoin::make_left(table, table, on),
+        };
+        select.push_join(join);
+    }
+
     async fn execute_select(
         &self,
         select: &Self::Select,
@@ -26,6 +26,23 @@ impl MysqlSelect {
         s.clear_order_by();
         s.render()
     }
+
+    /// Adds `column IN (subquery)` to the WHERE clause. The subquery stays a
+    /// nested expression, so its parameters flatten into this query.
+    pub fn add_where_in_subquery(&mut self, column: &str, subquery: impl Expressive<AnyMysqlType>) {
+        self.where_conditions
+            .push(expr_any!("{} IN ({})", (ident(column)), (subquery.expr())));
+    }
+
+    /// Builder form of [`Self::add_where_in_subquery`].
+    pub fn with_where_in_subquery(
+        mut self,
+        column: &str,
+        subquery: impl Expressive<AnyMysqlType>,
+    ) -> Self {
+        self.add_where_in_subquery(column, subquery);
+        self
+    }
 }
 
 impl Selectable<AnyMysqlType, MysqlCondition> for MysqlSelect {
@@ -65,6 +82,27 @@ impl Selectable<AnyMysqlType, MysqlCondition> for MysqlSelect {
         self.where_conditions.push(condition.into().into_expr());
     }
 
+    /// Same as the default, with the column quoted as an identifier.
+    fn add_where_between(
+        &mut self,
+        column: &str,
+        lo: impl Expressive<AnyMysqlType>,
+        hi: impl Expressive<AnyMysqlType>,
+    ) {
+        self.where_conditions.push(expr_any!(
+            "{} BETWEEN {} AND {}",
+            (ident(column)),
+            (lo.expr()),
+            (hi.expr())
+        ));
+    }
+
+    /// MySQL spells NULL-safe equality `<=>`.
+    fn add_where_eq_null_safe(&mut self, column: &str, value: impl Expressive<AnyMysqlType>) {
+        self.where_conditions
+            .push(expr_any!("{} <=> {}", (ident(column)), (value.expr())));
+    }
+
     fn set_distinct(&mut self, distinct: bool) {
         self.distinct = distinct;
     }
@@ -159,4 +197,8 @@ impl Selectable<AnyMysqlType, MysqlCondition> for MysqlSelect {
     fn as_min(&self, column: impl Expressive<AnyMysqlType>) -> Expr {
         self.as_aggregate("min", column)
     }
+
+    fn as_avg(&self, column: impl Expressive<AnyMysqlType>) -> Expr {
+        self.as_aggregate("avg", column)
+    }
 }
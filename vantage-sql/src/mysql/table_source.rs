@@ -101,6 +101,23 @@ impl TableSource for MysqlDB {
         Ok(MysqlOperation::eq(&column, value))
     }
 
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        let column: Column<AnyMysqlType> = Column::new(field);
+        Ok(MysqlOperation::gt(&column, value))
+    }
+
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        Ok(mysql_expr!("{}", (ident(field))).into())
+    }
+
+    fn qualified_column_expr(
+        &self,
+        qualifier: &str,
+        column: &str,
+    ) -> Option<Expression<Self::Value>> {
+        Some(ident(column).dot_of(qualifier).expr())
+    }
+
     fn create_column<Type: ColumnType>(&self, name: &str) -> Self::Column<Type> {
         Column::new(name)
     }
@@ -234,6 +251,20 @@ impl TableSource for MysqlDB {
         })
     }
 
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+    {
+        let distinct = mysql_expr!("DISTINCT {}", (ident(column)));
+        let result = self.aggregate(&table.select(), "count", distinct).await?;
+        result.try_get::<i64>().ok_or_else(|| {
+            error!(
+                "get_table_count_distinct: expected i64",
+                result = format!("{}", result)
+            )
+        })
+    }
+
     async fn get_table_sum<E>(
         &self,
         table: &Table<Self, E>,
@@ -267,6 +298,17 @@ impl TableSource for MysqlDB {
         self.aggregate(&table.select(), "min", column.expr()).await
     }
 
+    async fn get_table_avg<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &Self::Column<Self::AnyType>,
+    ) -> Result<Self::Value>
+    where
+        E: Entity<Self::Value>,
+    {
+        self.aggregate(&table.select(), "avg", column.expr()).await
+    }
+
     async fn insert_table_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -492,6 +534,10 @@ impl TableSource for MysqlDB {
         true
     }
 
+    fn supports_joins(&self) -> bool {
+        true
+    }
+
     fn related_correlated_condition(
         &self,
         target_table: &str,
@@ -132,6 +132,9 @@ fn prepare_typed_query(
             ExpressiveEnum::Deferred(_) => {
                 unreachable!("deferred expression should have been resolved before prepare");
             }
+            ExpressiveEnum::Raw(_) => {
+                unreachable!("raw text should have been flattened into the template");
+            }
         }
 
         if i + 1 < template_parts.len() {
@@ -1,10 +1,12 @@
 use vantage_expressions::traits::datasource::SelectableDataSource;
-use vantage_expressions::{Expression, Expressive, Selectable};
+use vantage_expressions::{Expression, Expressive, JoinKind, Selectable};
 
 use crate::postgres::PostgresDB;
 use crate::postgres::statements::PostgresSelect;
+use crate::postgres::statements::select::join::PostgresSelectJoin;
 use crate::postgres::types::AnyPostgresType;
 use crate::primitives::alias::AliasExt;
+use crate::primitives::select::{JoinBuilder, SelectBuilder};
 
 impl SelectableDataSource<AnyPostgresType, crate::condition::PostgresCondition> for PostgresDB {
     type Select = PostgresSelect;
@@ -25,6 +27,20 @@ impl SelectableDataSource<AnyPostgresType, crate::condition::PostgresCondition>
         }
     }
 
+    fn add_select_join(
+        &self,
+        select: &mut Self::Select,
+        kind: JoinKind,
+        table: &str,
+        on: Expression<AnyPostgresType>,
+    ) {
+        let join = match kind {
+            JoinKind::Inner => PostgresSelectJoin::make_inner(table, table, on),
+            JoinKind::Left => PostgresSelectJoin::make_left(table, table, on),
+        };
+        select.push_join(join);
+    }
+
     async fn execute_select(
         &self,
         select: &Self::Select,
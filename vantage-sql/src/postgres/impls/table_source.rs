@@ -119,6 +119,23 @@ impl TableSource for PostgresDB {
         Ok(PostgresOperation::eq(&column, value))
     }
 
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        let column: Column<AnyPostgresType> = Column::new(field);
+        Ok(PostgresOperation::gt(&column, value))
+    }
+
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        Ok(postgres_expr!("{}", (ident(field))).into())
+    }
+
+    fn qualified_column_expr(
+        &self,
+        qualifier: &str,
+        column: &str,
+    ) -> Option<Expression<Self::Value>> {
+        Some(ident(column).dot_of(qualifier).expr())
+    }
+
     fn create_column<Type: ColumnType>(&self, name: &str) -> Self::Column<Type> {
         Column::new(name)
     }
@@ -254,6 +271,20 @@ impl TableSource for PostgresDB {
         })
     }
 
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+    {
+        let distinct = postgres_expr!("DISTINCT {}", (ident(column)));
+        let result = self.aggregate(&table.select(), "count", distinct).await?;
+        result.try_get::<i64>().ok_or_else(|| {
+            error!(
+                "get_table_count_distinct: expected i64",
+                result = format!("{}", result)
+            )
+        })
+    }
+
     async fn get_table_sum<E>(
         &self,
         table: &Table<Self, E>,
@@ -287,6 +318,17 @@ impl TableSource for PostgresDB {
         self.aggregate(&table.select(), "min", column.expr()).await
     }
 
+    async fn get_table_avg<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &Self::Column<Self::AnyType>,
+    ) -> Result<Self::Value>
+    where
+        E: Entity<Self::Value>,
+    {
+        self.aggregate(&table.select(), "avg", column.expr()).await
+    }
+
     async fn insert_table_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -438,11 +480,10 @@ impl TableSource for PostgresDB {
             .unwrap_or_else(|| "id".to_string());
 
         let insert = crate::postgres::statements::PostgresInsert::new(table.table_name())
-            .with_record(record);
+            .with_record(record)
+            .returning(vec![&id_field_name]);
 
-        let base = insert.expr();
-        let returning = expr_any!("{} RETURNING {}", (base), (ident(&id_field_name)));
-        let result = self.execute(&returning).await?;
+        let result = self.execute(&insert.expr()).await?;
         let mut rows = parse_rows(result, &id_field_name)?;
         rows.swap_remove_index(0).map(|(id, _)| id).ok_or_else(|| {
             error!(
@@ -472,6 +513,10 @@ impl TableSource for PostgresDB {
         true
     }
 
+    fn supports_joins(&self) -> bool {
+        true
+    }
+
     fn related_correlated_condition(
         &self,
         target_table: &str,
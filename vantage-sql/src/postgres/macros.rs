@@ -27,3 +27,17 @@ macro_rules! postgres_expr {
         )
     };
 }
+
+/// [`postgres_expr!`] with a string-literal template whose `{}` placeholder
+/// count is checked against the arguments at compile time, see
+/// `vantage_expressions::checked_expr_as!`.
+///
+/// ```ignore
+/// let expr = postgres_query!("SELECT * FROM product WHERE price > {}", 100i64);
+/// ```
+#[macro_export]
+macro_rules! postgres_query {
+    ($template:literal $(, $param:tt)* $(,)?) => {
+        vantage_expressions::checked_expr_as!($crate::postgres::AnyPostgresType, $template $(, $param)*)
+    };
+}
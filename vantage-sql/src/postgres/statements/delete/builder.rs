@@ -1,14 +1,16 @@
-use vantage_expressions::Expressive;
+use vantage_expressions::{Expression, Expressive};
 
 use crate::postgres::types::AnyPostgresType;
+use crate::primitives::identifier::ident;
 
-use super::PostgresDelete;
+use super::{Expr, PostgresDelete};
 
 impl PostgresDelete {
     pub fn new(table: &str) -> Self {
         Self {
             table: table.to_string(),
             conditions: Vec::new(),
+            returning: None,
         }
     }
 
@@ -16,4 +18,17 @@ impl PostgresDelete {
         self.conditions.push(condition.expr());
         self
     }
+
+    /// Append `RETURNING` with the given columns, quoted as identifiers.
+    pub fn returning(mut self, columns: Vec<&str>) -> Self {
+        let columns: Vec<Expr> = columns.into_iter().map(|c| ident(c).expr()).collect();
+        self.returning = Some(Expression::from_vec(columns, ", "));
+        self
+    }
+
+    /// Append `RETURNING *`.
+    pub fn returning_all(mut self) -> Self {
+        self.returning = Some(Expression::new("*", vec![]));
+        self
+    }
 }
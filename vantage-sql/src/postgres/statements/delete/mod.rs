@@ -11,4 +11,5 @@ type Expr = Expression<AnyPostgresType>;
 pub struct PostgresDelete {
     pub table: String,
     pub conditions: Vec<Expr>,
+    pub returning: Option<Expr>,
 }
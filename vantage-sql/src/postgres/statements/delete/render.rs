@@ -6,6 +6,16 @@ use crate::primitives::identifier::ident;
 use super::{Expr, PostgresDelete};
 
 impl PostgresDelete {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
+        if self.conditions.is_empty() {
+            return expr_any!("DELETE FROM {}", (ident(&self.table)));
+        }
+
+        let combined = Expression::from_vec(self.conditions.clone(), " AND ");
+        expr_any!("DELETE FROM {} WHERE {}", (ident(&self.table)), (combined))
+    }
+
     pub fn preview(&self) -> String {
         self.expr().preview()
     }
@@ -13,12 +23,13 @@ impl PostgresDelete {
 
 impl Expressive<AnyPostgresType> for PostgresDelete {
     fn expr(&self) -> Expr {
-        if self.conditions.is_empty() {
-            return expr_any!("DELETE FROM {}", (ident(&self.table)));
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
         }
-
-        let combined = Expression::from_vec(self.conditions.clone(), " AND ");
-        expr_any!("DELETE FROM {} WHERE {}", (ident(&self.table)), (combined))
     }
 }
 
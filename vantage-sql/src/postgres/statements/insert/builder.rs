@@ -1,15 +1,18 @@
 use indexmap::IndexMap;
+use vantage_expressions::{Expression, Expressive};
 use vantage_types::Record;
 
 use crate::postgres::types::AnyPostgresType;
+use crate::primitives::identifier::ident;
 
-use super::PostgresInsert;
+use super::{Expr, PostgresInsert};
 
 impl PostgresInsert {
     pub fn new(table: &str) -> Self {
         Self {
             table: table.to_string(),
             fields: IndexMap::new(),
+            returning: None,
         }
     }
 
@@ -24,4 +27,17 @@ impl PostgresInsert {
         }
         self
     }
+
+    /// Append `RETURNING` with the given columns, quoted as identifiers.
+    pub fn returning(mut self, columns: Vec<&str>) -> Self {
+        let columns: Vec<Expr> = columns.into_iter().map(|c| ident(c).expr()).collect();
+        self.returning = Some(Expression::from_vec(columns, ", "));
+        self
+    }
+
+    /// Append `RETURNING *`.
+    pub fn returning_all(mut self) -> Self {
+        self.returning = Some(Expression::new("*", vec![]));
+        self
+    }
 }
@@ -12,4 +12,5 @@ type Expr = Expression<AnyPostgresType>;
 pub struct PostgresInsert {
     pub table: String,
     pub fields: IndexMap<String, AnyPostgresType>,
+    pub returning: Option<Expr>,
 }
@@ -6,13 +6,8 @@ use crate::primitives::identifier::ident;
 use super::{Expr, PostgresInsert};
 
 impl PostgresInsert {
-    pub fn preview(&self) -> String {
-        self.expr().preview()
-    }
-}
-
-impl Expressive<AnyPostgresType> for PostgresInsert {
-    fn expr(&self) -> Expr {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
         if self.fields.is_empty() {
             return expr_any!("INSERT INTO {} DEFAULT VALUES", (ident(&self.table)));
         }
@@ -34,6 +29,22 @@ impl Expressive<AnyPostgresType> for PostgresInsert {
             (vals)
         )
     }
+
+    pub fn preview(&self) -> String {
+        self.expr().preview()
+    }
+}
+
+impl Expressive<AnyPostgresType> for PostgresInsert {
+    fn expr(&self) -> Expr {
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
+        }
+    }
 }
 
 impl From<PostgresInsert> for Expr {
@@ -41,3 +52,19 @@ impl From<PostgresInsert> for Expr {
         insert.expr()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_returning_column() {
+        let insert = PostgresInsert::new("product")
+            .with_field("name", "Tart".to_string())
+            .returning(vec!["id"]);
+        assert_eq!(
+            insert.preview(),
+            r#"INSERT INTO "product" ("name") VALUES ('Tart') RETURNING "id""#
+        );
+    }
+}
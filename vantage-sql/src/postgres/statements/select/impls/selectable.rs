@@ -26,6 +26,27 @@ impl PostgresSelect {
         s.clear_order_by();
         s.render()
     }
+
+    /// Adds `column IN (subquery)` to the WHERE clause. The subquery stays a
+    /// nested expression, so its parameters flatten into this query.
+    pub fn add_where_in_subquery(
+        &mut self,
+        column: &str,
+        subquery: impl Expressive<AnyPostgresType>,
+    ) {
+        self.where_conditions
+            .push(expr_any!("{} IN ({})", (ident(column)), (subquery.expr())));
+    }
+
+    /// Builder form of [`Self::add_where_in_subquery`].
+    pub fn with_where_in_subquery(
+        mut self,
+        column: &str,
+        subquery: impl Expressive<AnyPostgresType>,
+    ) -> Self {
+        self.add_where_in_subquery(column, subquery);
+        self
+    }
 }
 
 impl Selectable<AnyPostgresType, PostgresCondition> for PostgresSelect {
@@ -65,6 +86,30 @@ impl Selectable<AnyPostgresType, PostgresCondition> for PostgresSelect {
         self.where_conditions.push(condition.into().into_expr());
     }
 
+    /// Same as the default, with the column quoted as an identifier.
+    fn add_where_between(
+        &mut self,
+        column: &str,
+        lo: impl Expressive<AnyPostgresType>,
+        hi: impl Expressive<AnyPostgresType>,
+    ) {
+        self.where_conditions.push(expr_any!(
+            "{} BETWEEN {} AND {}",
+            (ident(column)),
+            (lo.expr()),
+            (hi.expr())
+        ));
+    }
+
+    /// Same as the default, with the column quoted as an identifier.
+    fn add_where_eq_null_safe(&mut self, column: &str, value: impl Expressive<AnyPostgresType>) {
+        self.where_conditions.push(expr_any!(
+            "{} IS NOT DISTINCT FROM {}",
+            (ident(column)),
+            (value.expr())
+        ));
+    }
+
     fn set_distinct(&mut self, distinct: bool) {
         self.distinct = distinct;
     }
@@ -159,4 +204,8 @@ impl Selectable<AnyPostgresType, PostgresCondition> for PostgresSelect {
     fn as_min(&self, column: impl Expressive<AnyPostgresType>) -> Expr {
         self.as_aggregate("min", column)
     }
+
+    fn as_avg(&self, column: impl Expressive<AnyPostgresType>) -> Expr {
+        self.as_aggregate("avg", column)
+    }
 }
@@ -1,10 +1,11 @@
 use indexmap::IndexMap;
-use vantage_expressions::Expressive;
+use vantage_expressions::{Expression, Expressive};
 use vantage_types::Record;
 
 use crate::postgres::types::AnyPostgresType;
+use crate::primitives::identifier::ident;
 
-use super::PostgresUpdate;
+use super::{Expr, PostgresUpdate};
 
 impl PostgresUpdate {
     pub fn new(table: &str) -> Self {
@@ -12,6 +13,7 @@ impl PostgresUpdate {
             table: table.to_string(),
             fields: IndexMap::new(),
             conditions: Vec::new(),
+            returning: None,
         }
     }
 
@@ -31,4 +33,17 @@ impl PostgresUpdate {
         self.conditions.push(condition.expr());
         self
     }
+
+    /// Append `RETURNING` with the given columns, quoted as identifiers.
+    pub fn returning(mut self, columns: Vec<&str>) -> Self {
+        let columns: Vec<Expr> = columns.into_iter().map(|c| ident(c).expr()).collect();
+        self.returning = Some(Expression::from_vec(columns, ", "));
+        self
+    }
+
+    /// Append `RETURNING *`.
+    pub fn returning_all(mut self) -> Self {
+        self.returning = Some(Expression::new("*", vec![]));
+        self
+    }
 }
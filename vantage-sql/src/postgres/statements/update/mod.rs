@@ -13,4 +13,5 @@ pub struct PostgresUpdate {
     pub table: String,
     pub fields: IndexMap<String, AnyPostgresType>,
     pub conditions: Vec<Expr>,
+    pub returning: Option<Expr>,
 }
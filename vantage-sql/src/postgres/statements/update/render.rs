@@ -6,20 +6,8 @@ use crate::primitives::identifier::ident;
 use super::{Expr, PostgresUpdate};
 
 impl PostgresUpdate {
-    fn render_where(&self) -> Option<Expr> {
-        if self.conditions.is_empty() {
-            return None;
-        }
-        Some(Expression::from_vec(self.conditions.clone(), " AND "))
-    }
-
-    pub fn preview(&self) -> String {
-        self.expr().preview()
-    }
-}
-
-impl Expressive<AnyPostgresType> for PostgresUpdate {
-    fn expr(&self) -> Expr {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
         if self.fields.is_empty() {
             return Expression::new("SELECT 1 WHERE FALSE", vec![]);
         }
@@ -49,6 +37,29 @@ impl Expressive<AnyPostgresType> for PostgresUpdate {
             None => expr_any!("UPDATE {} SET {}", (ident(&self.table)), (set_list)),
         }
     }
+
+    fn render_where(&self) -> Option<Expr> {
+        if self.conditions.is_empty() {
+            return None;
+        }
+        Some(Expression::from_vec(self.conditions.clone(), " AND "))
+    }
+
+    pub fn preview(&self) -> String {
+        self.expr().preview()
+    }
+}
+
+impl Expressive<AnyPostgresType> for PostgresUpdate {
+    fn expr(&self) -> Expr {
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) if !self.fields.is_empty() => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
+        }
+    }
 }
 
 impl From<PostgresUpdate> for Expr {
@@ -56,3 +67,24 @@ impl From<PostgresUpdate> for Expr {
         update.expr()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_returning_all() {
+        let update = PostgresUpdate::new("product")
+            .with_field("price", 250i64)
+            .with_condition(postgres_expr!("{} = {}", (ident("id")), 1i64))
+            .returning_all();
+        assert_eq!(
+            update.preview(),
+            r#"UPDATE "product" SET "price" = 250 WHERE "id" = 1 RETURNING *"#
+        );
+
+        // No fields renders a no-op, which must stay free of RETURNING
+        let noop = PostgresUpdate::new("product").returning_all();
+        assert_eq!(noop.preview(), "SELECT 1 WHERE FALSE");
+    }
+}
@@ -0,0 +1,50 @@
+//! PostgreSQL array helpers.
+//!
+//! - [`array_contains`] renders `value = ANY(column)`
+//! - [`array_len`] renders `cardinality(column)`
+//!
+//! SQLite and MySQL have no array columns, so these only exist with the
+//! `postgres` feature. SurrealDB has its own versions in
+//! `vantage_surrealdb::primitives`.
+
+use vantage_expressions::Expressive;
+
+use crate::postgres::AnyPostgresType;
+use crate::postgres_expr;
+
+/// `value = ANY(column)` — true when the array `column` holds `value`. A
+/// scalar `value` is bound as a parameter.
+pub fn array_contains(
+    column: impl Expressive<AnyPostgresType>,
+    value: impl Expressive<AnyPostgresType>,
+) -> vantage_expressions::Expression<AnyPostgresType> {
+    postgres_expr!("{} = ANY({})", (value), (column))
+}
+
+/// `cardinality(column)` — total number of elements in the array. Unlike
+/// `array_length(column, 1)` it returns 0, not NULL, for an empty array.
+pub fn array_len(
+    column: impl Expressive<AnyPostgresType>,
+) -> vantage_expressions::Expression<AnyPostgresType> {
+    postgres_expr!("cardinality({})", (column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postgres::pg_ident as ident;
+    use vantage_expressions::{ExpressionFlattener, ExpressiveEnum, Flatten};
+
+    #[test]
+    fn test_array_helpers() {
+        let e = array_contains(ident("skills"), "rust");
+        assert_eq!(e.preview(), r#"'rust' = ANY("skills")"#);
+        let flat = ExpressionFlattener::new().flatten(&e);
+        assert_eq!(flat.template, r#"{} = ANY("skills")"#);
+        assert!(matches!(flat.parameters[..], [ExpressiveEnum::Scalar(_)]));
+        assert_eq!(
+            array_len(ident("skills")).preview(),
+            r#"cardinality("skills")"#
+        );
+    }
+}
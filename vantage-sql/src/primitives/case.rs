@@ -42,6 +42,12 @@ impl<T: Debug + Display + Clone> Case<T> {
         self.else_branch = Some(value.expr());
         self
     }
+
+    /// Finish the builder into an expression. Without `else_` the `ELSE`
+    /// clause is omitted and unmatched rows yield NULL.
+    pub fn end(self) -> Expression<T> {
+        self.expr()
+    }
 }
 
 impl<T: Debug + Display + Clone> Expressive<T> for Case<T> {
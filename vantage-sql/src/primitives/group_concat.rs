@@ -29,6 +29,11 @@ impl<T: Debug + Display + Clone> GroupConcat<T> {
         self.distinct = true;
         self
     }
+
+    /// Separator as the body of a single-quoted SQL literal.
+    fn quoted_separator(&self) -> String {
+        self.separator.replace('\'', "''")
+    }
 }
 
 // SQLite: GROUP_CONCAT(DISTINCT expr, separator)
@@ -38,7 +43,11 @@ impl Expressive<crate::sqlite::types::AnySqliteType>
 {
     fn expr(&self) -> Expression<crate::sqlite::types::AnySqliteType> {
         let distinct_kw = if self.distinct { "DISTINCT " } else { "" };
-        let template = format!("GROUP_CONCAT({}{{}}, '{}')", distinct_kw, self.separator);
+        let template = format!(
+            "GROUP_CONCAT({}{{}}, '{}')",
+            distinct_kw,
+            self.quoted_separator()
+        );
         Expression::new(&template, vec![ExpressiveEnum::Nested(self.expr.clone())])
     }
 }
@@ -50,7 +59,11 @@ impl Expressive<crate::postgres::types::AnyPostgresType>
 {
     fn expr(&self) -> Expression<crate::postgres::types::AnyPostgresType> {
         let distinct_kw = if self.distinct { "DISTINCT " } else { "" };
-        let template = format!("STRING_AGG({}{{}}, '{}')", distinct_kw, self.separator);
+        let template = format!(
+            "STRING_AGG({}{{}}, '{}')",
+            distinct_kw,
+            self.quoted_separator()
+        );
         Expression::new(&template, vec![ExpressiveEnum::Nested(self.expr.clone())])
     }
 }
@@ -62,10 +75,52 @@ impl Expressive<crate::mysql::types::AnyMysqlType>
 {
     fn expr(&self) -> Expression<crate::mysql::types::AnyMysqlType> {
         let distinct_kw = if self.distinct { "DISTINCT " } else { "" };
+        // MySQL also treats backslash as an escape inside string literals.
+        let separator = self.quoted_separator().replace('\\', "\\\\");
         let template = format!(
             "GROUP_CONCAT({}{{}} SEPARATOR '{}')",
-            distinct_kw, self.separator
+            distinct_kw, separator
         );
         Expression::new(&template, vec![ExpressiveEnum::Nested(self.expr.clone())])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::identifier::ident;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_separator() {
+        use crate::sqlite::types::AnySqliteType;
+        let e: Expression<AnySqliteType> = GroupConcat::new(ident("name")).separator("; ").expr();
+        assert_eq!(e.preview(), r#"GROUP_CONCAT("name", '; ')"#);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_separator() {
+        use crate::postgres::types::AnyPostgresType;
+        let e: Expression<AnyPostgresType> = GroupConcat::new(ident("name"))
+            .separator(" | ")
+            .distinct()
+            .expr();
+        assert_eq!(e.preview(), r#"STRING_AGG(DISTINCT "name", ' | ')"#);
+
+        // A quote in the separator can't end the literal
+        let e: Expression<AnyPostgresType> = GroupConcat::new(ident("name")).separator("'").expr();
+        assert_eq!(e.preview(), r#"STRING_AGG("name", '''')"#);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_mysql_separator() {
+        use crate::mysql::types::AnyMysqlType;
+        let e: Expression<AnyMysqlType> = GroupConcat::new(ident("name")).separator(", ").expr();
+        assert_eq!(e.preview(), "GROUP_CONCAT(`name` SEPARATOR ', ')");
+
+        let e: Expression<AnyMysqlType> = GroupConcat::new(ident("name")).separator(r"\'").expr();
+        assert_eq!(e.preview(), r"GROUP_CONCAT(`name` SEPARATOR '\\''')");
+    }
+}
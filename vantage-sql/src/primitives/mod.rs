@@ -1,4 +1,6 @@
 pub mod alias;
+#[cfg(feature = "postgres")]
+pub mod array;
 pub mod case;
 pub mod concat;
 pub mod date_format;
@@ -16,6 +18,8 @@ pub mod union;
 
 // Convenience re-exports
 pub use alias::AliasExt;
+#[cfg(feature = "postgres")]
+pub use array::{array_contains, array_len};
 pub use case::Case;
 pub use concat::Concat;
 pub use date_format::{DateFormat, date_format};
@@ -1,9 +1,11 @@
 use vantage_expressions::traits::datasource::SelectableDataSource;
-use vantage_expressions::{Expression, Expressive, Selectable};
+use vantage_expressions::{Expression, Expressive, JoinKind, Selectable};
 
 use crate::primitives::alias::AliasExt;
+use crate::primitives::select::{JoinBuilder, SelectBuilder};
 use crate::sqlite::SqliteDB;
 use crate::sqlite::statements::SqliteSelect;
+use crate::sqlite::statements::select::join::SqliteSelectJoin;
 use crate::sqlite::types::AnySqliteType;
 
 impl SelectableDataSource<AnySqliteType, crate::condition::SqliteCondition> for SqliteDB {
@@ -25,6 +27,20 @@ impl SelectableDataSource<AnySqliteType, crate::condition::SqliteCondition> for
         }
     }
 
+    fn add_select_join(
+        &self,
+        select: &mut Self::Select,
+        kind: JoinKind,
+        table: &str,
+        on: Expression<AnySqliteType>,
+    ) {
+        let join = match kind {
+            JoinKind::Inner => SqliteSelectJoin::make_inner(table, table, on),
+            JoinKind::Left => SqliteSelectJoin::make_left(table, table, on),
+        };
+        select.push_join(join);
+    }
+
     async fn execute_select(
         &self,
         select: &Self::Select,
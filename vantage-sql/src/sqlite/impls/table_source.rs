@@ -15,7 +15,6 @@ use vantage_types::{Entity, Record};
 use crate::primitives::identifier::ident;
 use crate::sqlite::SqliteDB;
 use crate::sqlite::types::AnySqliteType;
-use vantage_expressions::expr_any;
 
 /// Parse the CBOR array result from execute() into an IndexMap of id → Record.
 fn parse_rows(
@@ -94,6 +93,23 @@ impl TableSource for SqliteDB {
         Ok(SqliteOperation::eq(&column, value))
     }
 
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        let column: Column<AnySqliteType> = Column::new(field);
+        Ok(SqliteOperation::gt(&column, value))
+    }
+
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        Ok(sqlite_expr!("{}", (ident(field))).into())
+    }
+
+    fn qualified_column_expr(
+        &self,
+        qualifier: &str,
+        column: &str,
+    ) -> Option<Expression<Self::Value>> {
+        Some(ident(column).dot_of(qualifier).expr())
+    }
+
     fn create_column<Type: ColumnType>(&self, name: &str) -> Self::Column<Type> {
         Column::new(name)
     }
@@ -226,6 +242,20 @@ impl TableSource for SqliteDB {
         })
     }
 
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+    {
+        let distinct = sqlite_expr!("DISTINCT {}", (ident(column)));
+        let result = self.aggregate(&table.select(), "count", distinct).await?;
+        result.try_get::<i64>().ok_or_else(|| {
+            error!(
+                "get_table_count_distinct: expected i64",
+                result = format!("{}", result)
+            )
+        })
+    }
+
     async fn get_table_sum<E>(
         &self,
         table: &Table<Self, E>,
@@ -259,6 +289,17 @@ impl TableSource for SqliteDB {
         self.aggregate(&table.select(), "min", column.expr()).await
     }
 
+    async fn get_table_avg<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &Self::Column<Self::AnyType>,
+    ) -> Result<Self::Value>
+    where
+        E: Entity<Self::Value>,
+    {
+        self.aggregate(&table.select(), "avg", column.expr()).await
+    }
+
     async fn insert_table_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -386,13 +427,12 @@ impl TableSource for SqliteDB {
             .map(|c| c.name().to_string())
             .unwrap_or_else(|| "id".to_string());
 
-        let insert =
-            crate::sqlite::statements::SqliteInsert::new(table.table_name()).with_record(record);
+        // RETURNING id_field to get the generated ID back
+        let insert = crate::sqlite::statements::SqliteInsert::new(table.table_name())
+            .with_record(record)
+            .returning(vec![&id_field_name]);
 
-        // Append RETURNING id_field to get the generated ID back
-        let base = insert.expr();
-        let returning = expr_any!("{} RETURNING {}", (base), (ident(&id_field_name)));
-        let result = self.execute(&returning).await?;
+        let result = self.execute(&insert.expr()).await?;
         let mut rows = parse_rows(result, &id_field_name)?;
         rows.swap_remove_index(0).map(|(id, _)| id).ok_or_else(|| {
             error!(
@@ -425,6 +465,10 @@ impl TableSource for SqliteDB {
         true
     }
 
+    fn supports_joins(&self) -> bool {
+        true
+    }
+
     fn related_correlated_condition(
         &self,
         target_table: &str,
@@ -55,3 +55,17 @@ macro_rules! sqlite_expr {
         )
     };
 }
+
+/// [`sqlite_expr!`] with a string-literal template whose `{}` placeholder
+/// count is checked against the arguments at compile time, see
+/// `vantage_expressions::checked_expr_as!`.
+///
+/// ```ignore
+/// let expr = sqlite_query!("SELECT * FROM product WHERE price > {}", 100i64);
+/// ```
+#[macro_export]
+macro_rules! sqlite_query {
+    ($template:literal $(, $param:tt)* $(,)?) => {
+        vantage_expressions::checked_expr_as!($crate::sqlite::AnySqliteType, $template $(, $param)*)
+    };
+}
@@ -11,4 +11,5 @@ type Expr = Expression<AnySqliteType>;
 pub struct SqliteDelete {
     pub table: String,
     pub conditions: Vec<Expr>,
+    pub returning: Option<Expr>,
 }
@@ -6,6 +6,16 @@ use crate::sqlite::types::AnySqliteType;
 use super::{Expr, SqliteDelete};
 
 impl SqliteDelete {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
+        if self.conditions.is_empty() {
+            return expr_any!("DELETE FROM {}", (ident(&self.table)));
+        }
+
+        let combined = Expression::from_vec(self.conditions.clone(), " AND ");
+        expr_any!("DELETE FROM {} WHERE {}", (ident(&self.table)), (combined))
+    }
+
     pub fn preview(&self) -> String {
         self.expr().preview()
     }
@@ -13,12 +23,13 @@ impl SqliteDelete {
 
 impl Expressive<AnySqliteType> for SqliteDelete {
     fn expr(&self) -> Expr {
-        if self.conditions.is_empty() {
-            return expr_any!("DELETE FROM {}", (ident(&self.table)));
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
         }
-
-        let combined = Expression::from_vec(self.conditions.clone(), " AND ");
-        expr_any!("DELETE FROM {} WHERE {}", (ident(&self.table)), (combined))
     }
 }
 
@@ -27,3 +38,19 @@ impl From<SqliteDelete> for Expr {
         delete.expr()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delete_returning_columns() {
+        let delete = SqliteDelete::new("product")
+            .with_condition(sqlite_expr!("{} = {}", (ident("id")), 1i64))
+            .returning(vec!["id", "updated_at"]);
+        assert_eq!(
+            delete.preview(),
+            r#"DELETE FROM "product" WHERE "id" = 1 RETURNING "id", "updated_at""#
+        );
+    }
+}
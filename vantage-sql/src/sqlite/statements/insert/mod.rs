@@ -12,4 +12,5 @@ type Expr = Expression<AnySqliteType>;
 pub struct SqliteInsert {
     pub table: String,
     pub fields: IndexMap<String, AnySqliteType>,
+    pub returning: Option<Expr>,
 }
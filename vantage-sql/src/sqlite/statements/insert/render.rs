@@ -6,13 +6,8 @@ use crate::sqlite::types::AnySqliteType;
 use super::{Expr, SqliteInsert};
 
 impl SqliteInsert {
-    pub fn preview(&self) -> String {
-        self.expr().preview()
-    }
-}
-
-impl Expressive<AnySqliteType> for SqliteInsert {
-    fn expr(&self) -> Expr {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
         if self.fields.is_empty() {
             return expr_any!("INSERT INTO {} DEFAULT VALUES", (ident(&self.table)));
         }
@@ -34,6 +29,22 @@ impl Expressive<AnySqliteType> for SqliteInsert {
             (vals)
         )
     }
+
+    pub fn preview(&self) -> String {
+        self.expr().preview()
+    }
+}
+
+impl Expressive<AnySqliteType> for SqliteInsert {
+    fn expr(&self) -> Expr {
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
+        }
+    }
 }
 
 impl From<SqliteInsert> for Expr {
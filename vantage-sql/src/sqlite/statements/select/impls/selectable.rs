@@ -17,6 +17,27 @@ impl SqliteSelect {
         s.clear_order_by();
         s.render()
     }
+
+    /// Adds `column IN (subquery)` to the WHERE clause. The subquery stays a
+    /// nested expression, so its parameters flatten into this query.
+    pub fn add_where_in_subquery(
+        &mut self,
+        column: &str,
+        subquery: impl Expressive<AnySqliteType>,
+    ) {
+        self.where_conditions
+            .push(expr_any!("{} IN ({})", (ident(column)), (subquery.expr())));
+    }
+
+    /// Builder form of [`Self::add_where_in_subquery`].
+    pub fn with_where_in_subquery(
+        mut self,
+        column: &str,
+        subquery: impl Expressive<AnySqliteType>,
+    ) -> Self {
+        self.add_where_in_subquery(column, subquery);
+        self
+    }
 }
 
 impl Selectable<AnySqliteType, SqliteCondition> for SqliteSelect {
@@ -56,6 +77,28 @@ impl Selectable<AnySqliteType, SqliteCondition> for SqliteSelect {
         self.where_conditions.push(condition.into().into_expr());
     }
 
+    /// Same as the default, with the column quoted as an identifier.
+    fn add_where_between(
+        &mut self,
+        column: &str,
+        lo: impl Expressive<AnySqliteType>,
+        hi: impl Expressive<AnySqliteType>,
+    ) {
+        self.where_conditions.push(expr_any!(
+            "{} BETWEEN {} AND {}",
+            (ident(column)),
+            (lo.expr()),
+            (hi.expr())
+        ));
+    }
+
+    /// SQLite's `IS` compares NULLs as equal, and unlike `IS NOT DISTINCT
+    /// FROM` works on versions before 3.39.
+    fn add_where_eq_null_safe(&mut self, column: &str, value: impl Expressive<AnySqliteType>) {
+        self.where_conditions
+            .push(expr_any!("{} IS {}", (ident(column)), (value.expr())));
+    }
+
     fn set_distinct(&mut self, distinct: bool) {
         self.distinct = distinct;
     }
@@ -150,4 +193,8 @@ impl Selectable<AnySqliteType, SqliteCondition> for SqliteSelect {
     fn as_min(&self, column: impl Expressive<AnySqliteType>) -> Expr {
         self.as_aggregate("min", column)
     }
+
+    fn as_avg(&self, column: impl Expressive<AnySqliteType>) -> Expr {
+        self.as_aggregate("avg", column)
+    }
 }
@@ -13,4 +13,5 @@ pub struct SqliteUpdate {
     pub table: String,
     pub fields: IndexMap<String, AnySqliteType>,
     pub conditions: Vec<Expr>,
+    pub returning: Option<Expr>,
 }
@@ -6,20 +6,8 @@ use crate::sqlite::types::AnySqliteType;
 use super::{Expr, SqliteUpdate};
 
 impl SqliteUpdate {
-    fn render_where(&self) -> Option<Expr> {
-        if self.conditions.is_empty() {
-            return None;
-        }
-        Some(Expression::from_vec(self.conditions.clone(), " AND "))
-    }
-
-    pub fn preview(&self) -> String {
-        self.expr().preview()
-    }
-}
-
-impl Expressive<AnySqliteType> for SqliteUpdate {
-    fn expr(&self) -> Expr {
+    /// The statement without its `RETURNING` clause.
+    fn render_statement(&self) -> Expr {
         if self.fields.is_empty() {
             return expr_any!("UPDATE {}", (ident(&self.table)));
         }
@@ -49,6 +37,29 @@ impl Expressive<AnySqliteType> for SqliteUpdate {
             None => expr_any!("UPDATE {} SET {}", (ident(&self.table)), (set_list)),
         }
     }
+
+    fn render_where(&self) -> Option<Expr> {
+        if self.conditions.is_empty() {
+            return None;
+        }
+        Some(Expression::from_vec(self.conditions.clone(), " AND "))
+    }
+
+    pub fn preview(&self) -> String {
+        self.expr().preview()
+    }
+}
+
+impl Expressive<AnySqliteType> for SqliteUpdate {
+    fn expr(&self) -> Expr {
+        let statement = self.render_statement();
+        match &self.returning {
+            Some(columns) if !self.fields.is_empty() => {
+                expr_any!("{} RETURNING {}", (statement), (columns.clone()))
+            }
+            _ => statement,
+        }
+    }
 }
 
 impl From<SqliteUpdate> for Expr {
@@ -1,6 +1,8 @@
 //! Test 3a: MysqlSelect via Selectable trait + SelectableDataSource execution.
 
-use vantage_expressions::{ExprDataSource, Expressive, Order, Selectable};
+use vantage_expressions::{
+    ExprDataSource, ExpressionFlattener, Expressive, Flatten, Order, Selectable,
+};
 #[allow(unused_imports)]
 use vantage_sql::mysql::MysqlType;
 use vantage_sql::mysql::statements::MysqlSelect;
@@ -131,6 +133,44 @@ fn test_as_sum() {
     );
 }
 
+#[test]
+fn test_where_in_subquery() {
+    let orders = MysqlSelect::new()
+        .with_source("client_order")
+        .with_field("client_id")
+        .with_condition(mysql_expr!("`total` > {}", 100i64));
+    let s = MysqlSelect::new()
+        .with_source("client")
+        .with_condition(mysql_expr!("`is_paying` = {}", true))
+        .with_where_in_subquery("id", orders);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM `client` WHERE `is_paying` = 1 AND `id` IN (SELECT `client_id` FROM `client_order` WHERE `total` > 100)"
+    );
+
+    // Both the parent's and the subquery's parameters flatten into one list
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(flat.parameters.len(), 2);
+}
+
+#[test]
+fn test_where_eq_null_safe() {
+    let mut s = MysqlSelect::new().with_source("client");
+    s.add_where_eq_null_safe("manager_id", AnyMysqlType::new(None::<String>));
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM `client` WHERE `manager_id` <=> NULL"
+    );
+
+    let mut s = MysqlSelect::new().with_source("client");
+    s.add_where_eq_null_safe("manager_id", "doc");
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(
+        flat.template,
+        "SELECT * FROM `client` WHERE `manager_id` <=> {}"
+    );
+}
+
 // ── Live execution via ExprDataSource ──────────────────────────────────────
 
 #[tokio::test]
@@ -1,6 +1,8 @@
 //! Test 3a: PostgresSelect via Selectable trait + SelectableDataSource execution.
 
-use vantage_expressions::{ExprDataSource, Expressive, Order, Selectable};
+use vantage_expressions::{
+    ExprDataSource, ExpressionFlattener, Expressive, Flatten, Order, Selectable,
+};
 #[allow(unused_imports)]
 use vantage_sql::postgres::PostgresType;
 use vantage_sql::postgres::statements::PostgresSelect;
@@ -136,6 +138,44 @@ fn test_as_sum() {
     );
 }
 
+#[test]
+fn test_where_in_subquery() {
+    let orders = PostgresSelect::new()
+        .with_source("client_order")
+        .with_field("client_id")
+        .with_condition(postgres_expr!("\"total\" > {}", 100i64));
+    let s = PostgresSelect::new()
+        .with_source("client")
+        .with_condition(postgres_expr!("\"is_paying\" = {}", true))
+        .with_where_in_subquery("id", orders);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"client\" WHERE \"is_paying\" = true AND \"id\" IN (SELECT \"client_id\" FROM \"client_order\" WHERE \"total\" > 100)"
+    );
+
+    // Both the parent's and the subquery's parameters flatten into one list
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(flat.parameters.len(), 2);
+}
+
+#[test]
+fn test_where_eq_null_safe() {
+    let mut s = PostgresSelect::new().with_source("client");
+    s.add_where_eq_null_safe("manager_id", AnyPostgresType::new(None::<String>));
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"client\" WHERE \"manager_id\" IS NOT DISTINCT FROM NULL"
+    );
+
+    let mut s = PostgresSelect::new().with_source("client");
+    s.add_where_eq_null_safe("manager_id", "doc");
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(
+        flat.template,
+        "SELECT * FROM \"client\" WHERE \"manager_id\" IS NOT DISTINCT FROM {}"
+    );
+}
+
 // ── Live execution via ExprDataSource ──────────────────────────────────────
 
 #[tokio::test]
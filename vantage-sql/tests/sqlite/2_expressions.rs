@@ -77,6 +77,23 @@ async fn test_parameterized_integer() {
     );
 }
 
+#[tokio::test]
+async fn test_checked_query_macro() {
+    let db = setup("expr_checked_query").await;
+    let expr = vantage_sql::sqlite_query!(
+        "SELECT name FROM \"expr_checked_query\" WHERE price > {} AND active = {}",
+        60i64,
+        true
+    );
+    let result = records(db.execute(&expr).await.unwrap());
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(
+        result[0]["name"].try_get::<String>(),
+        Some("Apple".to_string())
+    );
+}
+
 #[tokio::test]
 async fn test_parameterized_text() {
     let db = setup("expr_param_text").await;
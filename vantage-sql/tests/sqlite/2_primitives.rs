@@ -131,6 +131,32 @@ fn test_case_bare_strings() {
     );
 }
 
+#[test]
+fn test_case_branches_are_parameterized() {
+    use vantage_expressions::{ExpressionFlattener, Flatten};
+
+    let expr = Case::new()
+        .when(ident("price").gt(250i64), "premium")
+        .when(ident("price").gt(150i64), "mid")
+        .else_("value")
+        .end();
+    assert_eq!(
+        expr.preview(),
+        "CASE WHEN \"price\" > 250 THEN 'premium' WHEN \"price\" > 150 THEN 'mid' ELSE 'value' END"
+    );
+    let flat = ExpressionFlattener::new().flatten(&expr);
+    assert_eq!(flat.parameters.len(), 5);
+}
+
+#[test]
+fn test_case_without_else() {
+    let expr = Case::new().when(ident("stock").eq(0i64), "sold out").end();
+    assert_eq!(
+        expr.preview(),
+        "CASE WHEN \"stock\" = 0 THEN 'sold out' END"
+    );
+}
+
 // ── Concat ──────────────────────────────────────────────────────────
 
 #[test]
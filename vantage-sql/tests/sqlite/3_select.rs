@@ -2,7 +2,9 @@
 //!
 //! All queries built using the Selectable trait methods, not custom builders.
 
-use vantage_expressions::{ExprDataSource, Expressive, Order, Selectable};
+use vantage_expressions::{
+    ExprDataSource, ExpressionFlattener, Expressive, Flatten, Order, Selectable,
+};
 use vantage_sql::primitives::alias::AliasExt;
 #[allow(unused_imports)]
 use vantage_sql::sqlite::SqliteType;
@@ -136,6 +138,106 @@ fn test_as_sum() {
     assert_eq!(sum_expr.preview(), "SELECT SUM(\"price\") FROM \"product\"");
 }
 
+#[test]
+fn test_where_in_subquery() {
+    let orders = SqliteSelect::new()
+        .with_source("client_order")
+        .with_field("client_id")
+        .with_condition(sqlite_expr!("\"total\" > {}", 100i64));
+    let s = SqliteSelect::new()
+        .with_source("client")
+        .with_condition(sqlite_expr!("\"is_paying\" = {}", true))
+        .with_where_in_subquery("id", orders);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"client\" WHERE \"is_paying\" = 1 AND \"id\" IN (SELECT \"client_id\" FROM \"client_order\" WHERE \"total\" > 100)"
+    );
+
+    // Both the parent's and the subquery's parameters flatten into one list
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(flat.parameters.len(), 2);
+}
+
+#[test]
+fn test_where_exists() {
+    let orders = SqliteSelect::new()
+        .with_source("client_order")
+        .with_condition(sqlite_expr!(
+            "\"client_order\".\"client_id\" = \"client\".\"id\""
+        ));
+    let mut s = SqliteSelect::new().with_source("client");
+    s.add_where_exists(orders.clone());
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"client\" WHERE EXISTS (SELECT * FROM \"client_order\" WHERE \"client_order\".\"client_id\" = \"client\".\"id\")"
+    );
+
+    let mut s = SqliteSelect::new().with_source("client");
+    s.add_where_not_exists(orders);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"client\" WHERE NOT EXISTS (SELECT * FROM \"client_order\" WHERE \"client_order\".\"client_id\" = \"client\".\"id\")"
+    );
+}
+
+#[test]
+fn test_where_between() {
+    let mut s = SqliteSelect::new().with_source("product");
+    s.add_where_between("price", 100i64, 200i64);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"product\" WHERE \"price\" BETWEEN 100 AND 200"
+    );
+
+    let from = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let to = chrono::NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    let mut s = SqliteSelect::new().with_source("client_order");
+    s.add_where_between("created_at", from, to);
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(
+        flat.template,
+        "SELECT * FROM \"client_order\" WHERE \"created_at\" BETWEEN {} AND {}"
+    );
+    assert_eq!(flat.parameters.len(), 2);
+}
+
+#[test]
+fn test_where_eq_null_safe() {
+    let mut s = SqliteSelect::new().with_source("product");
+    s.add_where_eq_null_safe("price", 150i64);
+    assert_eq!(
+        s.preview(),
+        "SELECT * FROM \"product\" WHERE \"price\" IS 150"
+    );
+}
+
+#[test]
+fn test_cast_bound_string_to_date() {
+    let due = vantage_expressions::Expression::cast(sqlite_expr!("{}", "2024-01-31"), "date");
+    let s = SqliteSelect::new()
+        .with_source("client_order")
+        .with_condition(sqlite_expr!("\"due\" < {}", (due)));
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(
+        flat.template,
+        "SELECT * FROM \"client_order\" WHERE \"due\" < CAST({} AS date)"
+    );
+    assert_eq!(flat.parameters.len(), 1);
+}
+
+#[test]
+fn test_contains_renders_escaped_like() {
+    let s = SqliteSelect::new().with_source("product").with_condition(
+        vantage_expressions::Expression::contains(sqlite_expr!("\"name\""), "50%"),
+    );
+    let flat = ExpressionFlattener::new().flatten(&s.expr());
+    assert_eq!(
+        flat.template,
+        "SELECT * FROM \"product\" WHERE \"name\" LIKE {} ESCAPE '$'"
+    );
+    assert_eq!(flat.parameters.len(), 1);
+}
+
 // ── Live execution via ExprDataSource ──────────────────────────────────────
 
 #[tokio::test]
@@ -215,3 +317,136 @@ async fn test_execute_order_and_limit() {
     assert_eq!(product.name, "Expensive");
     assert_eq!(product.price, 300);
 }
+
+#[tokio::test]
+async fn test_execute_where_in_subquery() {
+    let db = setup().await;
+
+    let pricey = SqliteSelect::new()
+        .with_source("product")
+        .with_field("id")
+        .with_condition(sqlite_expr!("\"price\" > {}", 100i64));
+    let select = SqliteSelect::new()
+        .with_source("product")
+        .with_condition(sqlite_expr!("\"is_deleted\" = {}", false))
+        .with_where_in_subquery("id", pricey);
+
+    let result = db.execute(&select.expr()).await.unwrap();
+    let json: serde_json::Value = result.into();
+    let rows = json.as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["name"], "Mid");
+}
+
+#[tokio::test]
+async fn test_execute_where_not_exists_correlated() {
+    let db = setup().await;
+
+    // Products nothing is more expensive than
+    let pricier = SqliteSelect::new()
+        .with_source_as("product", "other")
+        .with_condition(sqlite_expr!("\"other\".\"price\" > \"product\".\"price\""));
+    let mut select = SqliteSelect::new().with_source("product");
+    select.add_where_not_exists(pricier.clone());
+
+    let result = db.execute(&select.expr()).await.unwrap();
+    let json: serde_json::Value = result.into();
+    let rows = json.as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["name"], "Expensive");
+
+    let mut select = SqliteSelect::new().with_source("product");
+    select.add_where_exists(pricier);
+    let result = db.execute(&select.expr()).await.unwrap();
+    let json: serde_json::Value = result.into();
+    assert_eq!(json.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_execute_where_between() {
+    let db = setup().await;
+
+    let mut select = SqliteSelect::new().with_source("product");
+    select.add_where_between("price", 50i64, 150i64);
+    let result = db.execute(&select.expr()).await.unwrap();
+    let json: serde_json::Value = result.into();
+    let names: Vec<_> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["name"].clone())
+        .collect();
+    assert_eq!(names, vec!["Cheap", "Mid"]);
+}
+
+#[tokio::test]
+async fn test_execute_where_eq_null_safe_matches_null() {
+    let db = SqliteDB::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE employee (name TEXT NOT NULL, manager_id TEXT)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    let insert = sqlite_expr!(
+        "INSERT INTO employee VALUES ({}, {}), ({}, NULL)",
+        "Marty",
+        "doc",
+        "Doc"
+    );
+    db.execute(&insert).await.unwrap();
+
+    let names = |select: SqliteSelect| {
+        let db = db.clone();
+        async move {
+            let result: serde_json::Value = db.execute(&select.expr()).await.unwrap().into();
+            result
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|r| r["name"].clone())
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // `=` against NULL matches nothing; the NULL-safe form finds the NULL row.
+    let null = AnySqliteType::new(None::<String>);
+    let plain = SqliteSelect::new()
+        .with_source("employee")
+        .with_condition(sqlite_expr!("manager_id = {}", (null.clone())));
+    assert!(names(plain).await.is_empty());
+
+    let mut null_safe = SqliteSelect::new().with_source("employee");
+    null_safe.add_where_eq_null_safe("manager_id", null);
+    assert_eq!(names(null_safe).await, vec!["Doc"]);
+
+    let mut by_value = SqliteSelect::new().with_source("employee");
+    by_value.add_where_eq_null_safe("manager_id", "doc");
+    assert_eq!(names(by_value).await, vec!["Marty"]);
+}
+
+#[tokio::test]
+async fn test_execute_contains_matches_percent_literally() {
+    let db = setup().await;
+    let insert = sqlite_expr!(
+        "INSERT INTO product (id, name, price) VALUES ({}, {}, {}), ({}, {}, {})",
+        "d",
+        "50% off",
+        25i64,
+        "e",
+        "500 off",
+        250i64
+    );
+    db.execute(&insert).await.unwrap();
+
+    let select = SqliteSelect::new().with_source("product").with_condition(
+        vantage_expressions::Expression::contains(sqlite_expr!("\"name\""), "50%"),
+    );
+    let result = db.execute(&select.expr()).await.unwrap();
+    let json: serde_json::Value = result.into();
+    let names: Vec<_> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["name"].clone())
+        .collect();
+    assert_eq!(names, vec!["50% off"]);
+}
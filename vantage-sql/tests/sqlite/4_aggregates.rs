@@ -78,3 +78,40 @@ async fn test_get_min() {
     let result = table.get_min(&table["price"]).await.unwrap();
     assert_eq!(result.try_get::<i64>().unwrap(), 120);
 }
+
+// 973 / 5 = 194.6 — SQLite's AVG always yields a REAL
+#[tokio::test]
+async fn test_get_avg() {
+    let db = get_db().await;
+    let table = Product::sqlite_table(db);
+    let result = table.get_avg(&table["price"]).await.unwrap();
+    assert_eq!(result.try_get::<f64>().unwrap(), 194.6);
+}
+
+// In-memory: clients 1, 2, 2, 3 and a NULL; NULL isn't counted, and a
+// plain COUNT would give 4
+#[tokio::test]
+async fn test_count_distinct() {
+    let db = SqliteDB::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE orders (id TEXT PRIMARY KEY, client_id INTEGER, total INTEGER)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO orders VALUES ('a', 1, 10), ('b', 2, 50), ('c', 2, 70), ('d', 3, 5), ('e', NULL, 80)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let mut table = Table::<SqliteDB, vantage_types::EmptyEntity>::new("orders", db)
+        .with_id_column("id")
+        .with_column_of::<i64>("client_id")
+        .with_column_of::<i64>("total");
+    assert_eq!(table.count_distinct("client_id").await.unwrap(), 3);
+
+    // A plain COUNT would see clients 2, 2 here
+    table.add_condition(sqlite_expr!("{} > {}", (table["total"]), 20));
+    assert_eq!(table.count_distinct("client_id").await.unwrap(), 1);
+    assert!(table.count_distinct("missing").await.is_err());
+}
@@ -207,3 +207,27 @@ async fn test_generated_id_kept_on_explicit_insert() {
     let fetched = table.get("z").await.unwrap().expect("row z");
     assert_eq!(fetched.name, "Explicit");
 }
+
+// ── Change tracking ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_tracked_save_updates_dirty_column_only() {
+    let (db, table) = setup().await;
+    let mut item = table.get_tracked("a").await.unwrap().expect("row a");
+    item.price = 11;
+
+    let update = vantage_sql::sqlite::statements::SqliteUpdate::new("item")
+        .with_record(&item.changes().unwrap());
+    assert_eq!(update.preview(), "UPDATE \"item\" SET \"price\" = 11");
+
+    // A concurrent rename survives the save
+    sqlx::query("UPDATE item SET name = 'Renamed' WHERE id = 'a'")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    item.save().await.unwrap();
+
+    let fetched = table.get("a").await.unwrap().expect("row a");
+    assert_eq!(fetched.name, "Renamed");
+    assert_eq!(fetched.price, 11);
+}
@@ -3,9 +3,12 @@
 //! Verifies that `Table<SqliteDB, Entity>` builds correct SELECT queries
 //! using column definitions and the Selectable trait.
 
+use vantage_dataset::ReadableValueSet;
+use vantage_expressions::JoinKind;
 #[allow(unused_imports)]
 use vantage_sql::sqlite::SqliteType;
 use vantage_sql::sqlite::{AnySqliteType, SqliteDB};
+use vantage_sql::sqlite_expr;
 use vantage_table::table::Table;
 use vantage_types::entity;
 
@@ -43,3 +46,78 @@ async fn test_product_select() {
         "SELECT \"id\", \"name\", \"calories\", \"price\", \"bakery_id\", \"is_deleted\", \"inventory_stock\" FROM \"product\""
     );
 }
+
+#[tokio::test]
+async fn test_aliased_table_qualifies_columns() {
+    let db = SqliteDB::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE product (id TEXT PRIMARY KEY, name TEXT)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO product VALUES ('p1', 'Flux Cupcake')")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    let table = Table::<SqliteDB, Product>::new("product", db)
+        .with_id_column("id")
+        .with_column_of::<String>("name")
+        .with_table_alias("p");
+    assert_eq!(table.table_alias(), Some("p"));
+    assert_eq!(
+        table.select().preview(),
+        "SELECT \"p\".\"id\", \"p\".\"name\" FROM \"product\" AS \"p\""
+    );
+
+    // Qualified columns still come back under their plain names
+    let rows = table.list_values().await.unwrap();
+    assert_eq!(
+        rows["p1"]["name"].try_get::<String>().as_deref(),
+        Some("Flux Cupcake")
+    );
+}
+
+#[tokio::test]
+async fn test_inner_join_exposes_joined_columns() {
+    let db = SqliteDB::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE bakery (id TEXT PRIMARY KEY, name TEXT)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE product (id TEXT PRIMARY KEY, name TEXT, bakery_id TEXT)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO bakery VALUES ('b1', 'Hill Valley Bakery');
+         INSERT INTO product VALUES ('p1', 'Flux Cupcake', 'b1'), ('p2', 'Orphan Roll', 'gone')",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let table = Table::<SqliteDB, Product>::new("product", db)
+        .with_id_column("id")
+        .with_column_of::<String>("name")
+        .with_table_alias("p")
+        .with_expression("bakery_name", |_| sqlite_expr!("\"bakery\".\"name\""))
+        .with_join(
+            "bakery",
+            JoinKind::Inner,
+            sqlite_expr!("\"bakery\".\"id\" = \"p\".\"bakery_id\""),
+        )
+        .unwrap();
+    assert_eq!(
+        table.select().preview(),
+        "SELECT \"p\".\"id\", \"p\".\"name\", (\"bakery\".\"name\") AS \"bakery_name\" \
+         FROM \"product\" AS \"p\" \
+         INNER JOIN \"bakery\" AS \"bakery\" ON \"bakery\".\"id\" = \"p\".\"bakery_id\""
+    );
+
+    // The inner join drops the product whose bakery doesn't exist
+    let rows = table.list_values().await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(
+        rows["p1"]["bakery_name"].try_get::<String>().as_deref(),
+        Some("Hill Valley Bakery")
+    );
+}
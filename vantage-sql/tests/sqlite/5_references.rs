@@ -6,10 +6,11 @@
 use vantage_sql::sqlite::SqliteType;
 use vantage_sql::sqlite::{AnySqliteType, SqliteDB};
 use vantage_sql::sqlite_expr;
+use vantage_table::aggregate::AggExpr;
 use vantage_table::table::Table;
-use vantage_types::entity;
+use vantage_types::{EmptyEntity, entity};
 
-use vantage_dataset::ReadableDataSet;
+use vantage_dataset::{ReadableDataSet, ReadableValueSet};
 
 const DB_PATH: &str = "sqlite:../target/bakery.sqlite?mode=ro";
 
@@ -124,3 +125,47 @@ async fn test_has_one_client_for_order() {
     assert_eq!(client_list.len(), 1);
     assert_eq!(client_list.values().next().unwrap().name, "Marty McFly");
 }
+
+/// Aggregate columns over a has_many render as correlated subqueries and
+/// come back with each row
+#[tokio::test]
+async fn test_aggregate_columns_over_has_many() {
+    let db = SqliteDB::connect("sqlite::memory:").await.unwrap();
+    sqlx::query(
+        "CREATE TABLE client (id TEXT PRIMARY KEY, name TEXT);
+         CREATE TABLE client_order (id TEXT PRIMARY KEY, client_id TEXT, total INTEGER);
+         INSERT INTO client VALUES ('marty', 'Marty'), ('doc', 'Doc');
+         INSERT INTO client_order VALUES ('o1', 'marty', 10), ('o2', 'marty', 15), ('o3', 'doc', 7)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let clients = Table::<SqliteDB, EmptyEntity>::new("client", db)
+        .with_id_column("id")
+        .with_column_of::<String>("name")
+        .with_many("orders", "client_id", |db| {
+            Table::<SqliteDB, EmptyEntity>::new("client_order", db)
+                .with_id_column("id")
+                .with_column_of::<String>("client_id")
+                .with_column_of::<i64>("total")
+        })
+        .with_aggregate("total_orders", "orders", AggExpr::Count)
+        .with_aggregate("spent", "orders", AggExpr::sum("total"));
+
+    assert_eq!(
+        clients.select().preview(),
+        "SELECT \"id\", \"name\", \
+         (SELECT COUNT(*) FROM \"client_order\" \
+         WHERE \"client_order\".\"client_id\" = \"client\".\"id\") AS \"total_orders\", \
+         (SELECT SUM(total) FROM \"client_order\" \
+         WHERE \"client_order\".\"client_id\" = \"client\".\"id\") AS \"spent\" \
+         FROM \"client\""
+    );
+    assert!(clients.is_calculated_column("total_orders"));
+
+    let rows = clients.list_values().await.unwrap();
+    assert_eq!(rows["marty"]["total_orders"].try_get::<i64>(), Some(2));
+    assert_eq!(rows["marty"]["spent"].try_get::<i64>(), Some(25));
+    assert_eq!(rows["doc"]["spent"].try_get::<i64>(), Some(7));
+}
@@ -1,6 +1,7 @@
 //! Test 7: tables sourced from an arbitrary SELECT query.
 //!
-//! Covers `Table::from_select` (a raw query as a read-only source) and
+//! Covers `Table::from_select` (a raw query as a read-only source, filtered
+//! in the outer scope) and
 //! `Table::derive_from` (deriving a table from another, inheriting columns and
 //! relations). Uses an in-memory database, so no ingress setup is required.
 
@@ -95,6 +96,34 @@ async fn from_select_renders_subquery_source() {
     );
 }
 
+/// Conditions on a query-sourced table filter the derived rows: they render
+/// in the outer WHERE, against the alias, not inside the subquery.
+#[tokio::test]
+async fn from_select_conditions_apply_to_outer_scope() {
+    let db = setup().await;
+
+    let query = SqliteSelect::new()
+        .with_source("client")
+        .with_field("id")
+        .with_field("name")
+        .with_field("is_paying_client");
+
+    let mut paying: Table<SqliteDB, EmptyEntity> = Table::from_select(db, "paying", query)
+        .with_id_column("id")
+        .with_column_of::<String>("name")
+        .with_column_of::<bool>("is_paying_client");
+    paying.add_condition(sqlite_expr!("{} = {}", (paying["is_paying_client"]), true));
+
+    assert_eq!(
+        paying.select().preview(),
+        "SELECT \"id\", \"name\", \"is_paying_client\" FROM (SELECT \"id\", \"name\", \
+         \"is_paying_client\" FROM \"client\") AS \"paying\" WHERE is_paying_client = 1"
+    );
+
+    let rows = paying.list_values().await.unwrap();
+    assert_eq!(rows.len(), 2);
+}
+
 /// Derive a table from another: filter via the modifier, inherit columns.
 #[tokio::test]
 async fn derive_from_inherits_columns_and_filters() {
@@ -41,12 +41,14 @@ pub mod rhai_engine;
 // Re-export statement builders at crate root for convenience
 pub use statements::SurrealDelete;
 pub use statements::SurrealInsert;
+pub use statements::SurrealRelate;
 pub use statements::SurrealSelect;
-pub use statements::SurrealUpdate;
+pub use statements::{PatchOp, SurrealUpdate};
 
 // Backwards-compat module aliases
 pub use statements::delete;
 pub use statements::insert;
+pub use statements::relate;
 pub use statements::select;
 pub use statements::update;
 
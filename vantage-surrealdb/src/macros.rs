@@ -28,3 +28,25 @@ macro_rules! surreal_expr {
         )
     };
 }
+
+/// [`surreal_expr!`] with a string-literal template whose `{}` placeholder
+/// count is checked against the arguments at compile time, see
+/// `vantage_expressions::checked_expr_as!`.
+///
+/// ```rust
+/// use vantage_surrealdb::surreal_query;
+///
+/// let query = surreal_query!("SELECT * FROM client WHERE age > {}", 18);
+/// ```
+///
+/// ```rust,compile_fail
+/// use vantage_surrealdb::surreal_query;
+///
+/// let query = surreal_query!("SELECT * FROM client WHERE age > {} AND name = {}", 18);
+/// ```
+#[macro_export]
+macro_rules! surreal_query {
+    ($template:literal $(, $param:tt)* $(,)?) => {
+        vantage_expressions::checked_expr_as!($crate::AnySurrealType, $template $(, $param)*)
+    };
+}
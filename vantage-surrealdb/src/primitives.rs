@@ -57,6 +57,12 @@ pub fn coalesce(a: impl Expressive<AnySurrealType>, b: impl Expressive<AnySurrea
     )
 }
 
+/// `coalesce_all([a, b, c])` → `a ?? b ?? c` — the n-ary form of
+/// [`coalesce`], SurrealDB's stand-in for SQL `COALESCE(a, b, c)`.
+pub fn coalesce_all(args: Vec<Expr>) -> Expr {
+    Expression::from_vec(args, " ?? ")
+}
+
 /// `nullif(a, b)` → `IF a = b THEN NONE ELSE a END`.
 pub fn nullif(a: impl Expressive<AnySurrealType>, b: impl Expressive<AnySurrealType>) -> Expr {
     Expression::new(
@@ -75,6 +81,52 @@ pub fn cast(expr: impl Expressive<AnySurrealType>, ty: &str) -> Expr {
     Fx::new(format!("type::{ty}"), vec![expr.expr()]).expr()
 }
 
+/// `cast_as(expr, ty)` → `<ty> expr`, SurrealQL's cast operator — the
+/// counterpart of SQL's `CAST(expr AS ty)` ([`Expression::cast`]). Unlike
+/// [`cast`], it takes any SurrealQL type, e.g. `datetime` or `record<user>`.
+pub fn cast_as(expr: impl Expressive<AnySurrealType>, ty: &str) -> Expr {
+    Expression::new(
+        format!("<{ty}> {{}}"),
+        vec![ExpressiveEnum::Nested(expr.expr())],
+    )
+}
+
+/// `contains(expr, term)` → `string::contains(expr, term)` — substring match,
+/// the SurrealQL counterpart of [`Expression::contains`]. No pattern syntax is
+/// involved, so `%` and `_` in `term` need no escaping; `term` is a bound
+/// parameter.
+pub fn contains(expr: impl Expressive<AnySurrealType>, term: &str) -> Expr {
+    crate::surreal_expr!("string::contains({}, {})", (expr), term)
+}
+
+/// `array_contains(column, value)` → `value IN column` — membership test on
+/// an array field. A scalar `value` is bound as a parameter.
+pub fn array_contains(
+    column: impl Expressive<AnySurrealType>,
+    value: impl Expressive<AnySurrealType>,
+) -> Expr {
+    crate::surreal_expr!("{} IN {}", (value), (column))
+}
+
+/// `array_len(column)` → `array::len(column)`.
+pub fn array_len(column: impl Expressive<AnySurrealType>) -> Expr {
+    crate::surreal_expr!("array::len({})", (column))
+}
+
+/// `group_concat(column, separator)` → `array::join(array::group(column), $sep)`
+/// — the group's distinct values joined into one string, the SurrealQL
+/// counterpart of SQL `GROUP_CONCAT` / `STRING_AGG`. Use in a `GROUP BY`
+/// select; `separator` is bound as a parameter.
+pub fn group_concat(column: impl Expressive<AnySurrealType>, separator: &str) -> Expr {
+    Expression::new(
+        "array::join(array::group({}), {})",
+        vec![
+            ExpressiveEnum::Nested(column.expr()),
+            ExpressiveEnum::Scalar(AnySurrealType::from(separator.to_string())),
+        ],
+    )
+}
+
 /// `date_format(expr, fmt)` → `time::format(expr, "fmt")`.
 pub fn date_format(expr: impl Expressive<AnySurrealType>, fmt: &str) -> Expr {
     Expression::new(
@@ -189,6 +241,21 @@ pub fn lower(expr: impl Expressive<AnySurrealType>) -> Expr {
     Fx::new("string::lowercase", vec![expr.expr()]).expr()
 }
 
+/// `upper(expr)` → `string::uppercase(expr)`.
+pub fn upper(expr: impl Expressive<AnySurrealType>) -> Expr {
+    Fx::new("string::uppercase", vec![expr.expr()]).expr()
+}
+
+/// `trim(expr)` → `string::trim(expr)`.
+pub fn trim(expr: impl Expressive<AnySurrealType>) -> Expr {
+    Fx::new("string::trim", vec![expr.expr()]).expr()
+}
+
+/// `concat([a, b, c])` → `string::concat(a, b, c)`.
+pub fn concat(parts: Vec<Expr>) -> Expr {
+    Fx::new("string::concat", parts).expr()
+}
+
 /// `words(expr)` → `string::words(expr)`.
 pub fn words(expr: impl Expressive<AnySurrealType>) -> Expr {
     Fx::new("string::words", vec![expr.expr()]).expr()
@@ -204,6 +271,18 @@ pub fn object_values(expr: impl Expressive<AnySurrealType>) -> Expr {
     Fx::new("object::values", vec![expr.expr()]).expr()
 }
 
+/// `date_add(dt, dur)` → `dt + $dur`. The duration is bound as a SurrealDB
+/// `duration` (CBOR compact duration), so the sum stays a `datetime`.
+pub fn date_add(dt: impl Expressive<AnySurrealType>, dur: std::time::Duration) -> Expr {
+    crate::surreal_expr!("{} + {}", (dt), dur)
+}
+
+/// `date_diff(a, b)` → `a - b`. Subtracting two datetimes yields a
+/// `duration` in SurrealQL.
+pub fn date_diff(a: impl Expressive<AnySurrealType>, b: impl Expressive<AnySurrealType>) -> Expr {
+    crate::surreal_expr!("{} - {}", (a), (b))
+}
+
 /// `time_group(expr, unit)` → `time::group(expr, $unit)`. `unit` is the bucket
 /// token (`year`/`month`/`day`/…), bound as a parameter so it cannot break out of
 /// the SurrealQL string literal.
@@ -370,6 +449,12 @@ impl Case {
         self.otherwise = Some(value.expr());
         self
     }
+
+    /// Finish the builder into an expression. Without `else_` the `ELSE`
+    /// branch is omitted and unmatched records yield `NONE`.
+    pub fn end(self) -> Expr {
+        self.expr()
+    }
 }
 
 impl Expressive<AnySurrealType> for Case {
@@ -436,6 +521,15 @@ mod tests {
             coalesce(surreal_expr!("array::first(x)"), "n/a".to_string()).preview(),
             r#"array::first(x) ?? "n/a""#
         );
+        assert_eq!(
+            coalesce_all(vec![
+                Identifier::new("nickname").expr(),
+                Identifier::new("name").expr(),
+                surreal_expr!("{}", ("anonymous".to_string())),
+            ])
+            .preview(),
+            r#"nickname ?? name ?? "anonymous""#
+        );
         assert_eq!(
             nullif(Identifier::new("qty"), 0i64).preview(),
             "IF qty = 0 THEN NONE ELSE qty END"
@@ -445,12 +539,74 @@ mod tests {
     #[test]
     fn cast_and_date_format() {
         assert_eq!(cast(Identifier::new("x"), "int").preview(), "type::int(x)");
+        let due = cast_as("2024-01-31", "datetime");
+        assert_eq!(due.preview(), r#"<datetime> "2024-01-31""#);
+        let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&due);
+        assert_eq!(query, "<datetime> $_arg1");
+        assert_eq!(
+            params["_arg1"].try_get::<String>().as_deref(),
+            Some("2024-01-31")
+        );
         assert_eq!(
             date_format(Identifier::new("created_at"), "%Y-%m").preview(),
             r#"time::format(created_at, "%Y-%m")"#
         );
     }
 
+    #[test]
+    fn contains_binds_term() {
+        let e = contains(Identifier::new("name"), "50%_off");
+        assert_eq!(e.preview(), r#"string::contains(name, "50%_off")"#);
+        let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&e);
+        assert_eq!(query, "string::contains(name, $_arg1)");
+        assert_eq!(
+            params["_arg1"].try_get::<String>().as_deref(),
+            Some("50%_off")
+        );
+    }
+
+    #[test]
+    fn array_helpers() {
+        let e = array_contains(Identifier::new("tags"), "rust".to_string());
+        let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&e);
+        assert_eq!(query, "$_arg1 IN tags");
+        assert_eq!(params["_arg1"].try_get::<String>().as_deref(), Some("rust"));
+        assert_eq!(
+            array_len(Identifier::new("tags")).preview(),
+            "array::len(tags)"
+        );
+    }
+
+    #[test]
+    fn group_concat_binds_separator() {
+        let e = group_concat(Identifier::new("name"), "; ");
+        assert_eq!(e.preview(), r#"array::join(array::group(name), "; ")"#);
+        let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&e);
+        assert_eq!(query, "array::join(array::group(name), $_arg1)");
+        assert_eq!(params["_arg1"].try_get::<String>().as_deref(), Some("; "));
+    }
+
+    #[test]
+    fn date_add_binds_duration() {
+        use std::time::Duration;
+
+        let two_weeks = Duration::from_secs(14 * 24 * 3600);
+        let due = date_add(Identifier::new("created_at"), two_weeks);
+        let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&due);
+        assert_eq!(query, "created_at + $_arg1");
+        assert!(matches!(
+            params["_arg1"].value(),
+            ciborium::Value::Tag(14, _)
+        ));
+        assert_eq!(params["_arg1"].try_get::<Duration>(), Some(two_weeks));
+    }
+
+    #[test]
+    fn date_diff_subtracts_datetimes() {
+        let age = date_diff(Identifier::new("shipped_at"), Identifier::new("created_at"));
+        assert_eq!(age.preview(), "shipped_at - created_at");
+    }
+
     fn segs(names: &[&str]) -> Vec<String> {
         names.iter().map(|s| s.to_string()).collect()
     }
@@ -523,6 +679,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn case_without_else_omits_else() {
+        use vantage_expressions::{ExpressionFlattener, Flatten};
+
+        let c = Case::new()
+            .when(surreal_expr!("price >= {}", 250), "premium".to_string())
+            .end();
+        assert_eq!(c.preview(), r#"IF price >= 250 THEN "premium" END"#);
+
+        let flat = ExpressionFlattener::new().flatten(&c);
+        assert_eq!(flat.parameters.len(), 2);
+    }
+
     #[test]
     fn tier2_fns_lower_to_surreal() {
         let f = Identifier::new("salary");
@@ -538,6 +707,19 @@ mod tests {
             words(Identifier::new("name")).preview(),
             "string::words(name)"
         );
+        assert_eq!(
+            upper(trim(Identifier::new("name"))).preview(),
+            "string::uppercase(string::trim(name))"
+        );
+        assert_eq!(
+            concat(vec![
+                lower(Identifier::new("first")).expr(),
+                surreal_expr!("{}", (" ".to_string())),
+                Identifier::new("last").expr(),
+            ])
+            .preview(),
+            r#"string::concat(string::lowercase(first), " ", last)"#
+        );
         assert_eq!(
             object_entries(Identifier::new("nutrition")).preview(),
             "object::entries(nutrition)"
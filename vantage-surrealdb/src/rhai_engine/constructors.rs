@@ -310,6 +310,16 @@ pub fn fn_lower(arg: rhai::Dynamic) -> Result<RhaiExpr, Box<rhai::EvalAltResult>
     Ok(RhaiExpr(primitives::lower(unwrap_expr(arg)?)))
 }
 
+/// `upper(expr)` → `string::uppercase(expr)`.
+pub fn fn_upper(arg: rhai::Dynamic) -> Result<RhaiExpr, Box<rhai::EvalAltResult>> {
+    Ok(RhaiExpr(primitives::upper(unwrap_expr(arg)?)))
+}
+
+/// `trim(expr)` → `string::trim(expr)`.
+pub fn fn_trim(arg: rhai::Dynamic) -> Result<RhaiExpr, Box<rhai::EvalAltResult>> {
+    Ok(RhaiExpr(primitives::trim(unwrap_expr(arg)?)))
+}
+
 /// `words(expr)` → `string::words(expr)`.
 pub fn fn_words(arg: rhai::Dynamic) -> Result<RhaiExpr, Box<rhai::EvalAltResult>> {
     Ok(RhaiExpr(primitives::words(unwrap_expr(arg)?)))
@@ -75,6 +75,8 @@ pub fn register_surreal_onto(engine: &mut rhai::Engine) {
         engine.register_fn("stddev", crate::rhai_engine::constructors::fn_stddev);
         engine.register_fn("median", crate::rhai_engine::constructors::fn_median);
         engine.register_fn("lower", crate::rhai_engine::constructors::fn_lower);
+        engine.register_fn("upper", crate::rhai_engine::constructors::fn_upper);
+        engine.register_fn("trim", crate::rhai_engine::constructors::fn_trim);
         engine.register_fn("words", crate::rhai_engine::constructors::fn_words);
         engine.register_fn(
             "object_entries",
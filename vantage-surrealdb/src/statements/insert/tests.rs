@@ -8,10 +8,29 @@ fn test_basic_insert() {
         .with_field("name", "John".to_string())
         .with_field("age", 30i64);
 
-    let rendered = insert.preview();
-    assert!(rendered.starts_with("CREATE users SET"));
-    assert!(rendered.contains("name = \"John\""));
-    assert!(rendered.contains("age = 30"));
+    assert_eq!(
+        insert.preview(),
+        "CREATE users SET name = \"John\", age = 30"
+    );
+}
+
+#[test]
+fn test_fields_render_in_insertion_order() {
+    let zip_first = SurrealInsert::new("users")
+        .with_field("zip", "E1".to_string())
+        .with_field("age", 30i64);
+    assert_eq!(
+        zip_first.preview(),
+        "CREATE users SET zip = \"E1\", age = 30"
+    );
+
+    let age_first = SurrealInsert::new("users")
+        .with_field("age", 30i64)
+        .with_field("zip", "E1".to_string());
+    assert_eq!(
+        age_first.preview(),
+        "CREATE users SET age = 30, zip = \"E1\""
+    );
 }
 
 #[test]
@@ -20,9 +39,7 @@ fn test_insert_with_id() {
         .with_id("john")
         .with_field("name", "John".to_string());
 
-    let rendered = insert.preview();
-    assert!(rendered.starts_with("CREATE users:john SET"));
-    assert!(rendered.contains("name = \"John\""));
+    assert_eq!(insert.preview(), "CREATE users:john SET name = \"John\"");
 }
 
 #[test]
@@ -41,9 +58,7 @@ fn test_empty_insert_with_id() {
 fn test_identifier_escaping() {
     let insert = SurrealInsert::new("SELECT").with_field("FROM", "value".to_string());
 
-    let rendered = insert.preview();
-    assert!(rendered.contains("CREATE ⟨SELECT⟩"));
-    assert!(rendered.contains("⟨FROM⟩ = \"value\""));
+    assert_eq!(insert.preview(), "CREATE ⟨SELECT⟩ SET ⟨FROM⟩ = \"value\"");
 }
 
 #[test]
@@ -61,8 +76,7 @@ fn test_insert_produces_parameterized_expression() {
 fn test_with_any_field() {
     let val = AnySurrealType::new(42i64);
     let insert = SurrealInsert::new("data").with_any_field("count", val);
-    let rendered = insert.preview();
-    assert!(rendered.contains("count = 42"));
+    assert_eq!(insert.preview(), "CREATE data SET count = 42");
 }
 
 #[test]
@@ -72,9 +86,7 @@ fn test_with_record() {
     record.insert("b".to_string(), AnySurrealType::new("hi".to_string()));
 
     let insert = SurrealInsert::new("t").with_id("1").with_record(&record);
-    let p = insert.preview();
-    assert!(p.contains("a = 1"));
-    assert!(p.contains("b = \"hi\""));
+    assert_eq!(insert.preview(), "CREATE t:⟨1⟩ SET a = 1, b = \"hi\"");
 }
 
 #[test]
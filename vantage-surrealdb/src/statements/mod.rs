@@ -1,11 +1,12 @@
 //! SurrealDB statement builders.
 //!
-//! Provides type-safe builders for the four core SurrealDB statements:
+//! Provides type-safe builders for the core SurrealDB statements:
 //!
 //! - [`SurrealSelect`] — `SELECT` queries with fields, conditions, ordering, grouping, limits
 //! - [`SurrealInsert`] — `CREATE` statements with typed fields and optional record IDs
 //! - [`SurrealUpdate`] — `UPDATE` statements in `SET`, `CONTENT`, or `MERGE` mode
 //! - [`SurrealDelete`] — `DELETE` statements targeting records or whole tables
+//! - [`SurrealRelate`] — `RELATE from->edge->to` graph edges with optional properties
 //!
 //! All builders implement [`Expressive<AnySurrealType>`](vantage_expressions::Expressive),
 //! producing parameterized expressions safe for execution via
@@ -28,10 +29,12 @@
 
 pub mod delete;
 pub mod insert;
+pub mod relate;
 pub mod select;
 pub mod update;
 
 pub use delete::SurrealDelete;
 pub use insert::SurrealInsert;
+pub use relate::SurrealRelate;
 pub use select::SurrealSelect;
-pub use update::SurrealUpdate;
+pub use update::{PatchOp, SurrealUpdate};
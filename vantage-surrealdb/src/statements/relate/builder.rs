@@ -0,0 +1,32 @@
+use crate::thing::Thing;
+use crate::types::{AnySurrealType, SurrealType};
+
+use super::SurrealRelate;
+
+impl SurrealRelate {
+    /// Create a RELATE statement: `RELATE from->edge->to`
+    pub fn new(from: Thing, edge: &str, to: Thing) -> Self {
+        Self {
+            from,
+            edge: edge.to_string(),
+            to,
+            fields: indexmap::IndexMap::new(),
+        }
+    }
+
+    /// Set a typed edge property. The value is converted to [`AnySurrealType`] via [`SurrealType`].
+    pub fn set_field<K: Into<String>, T: SurrealType + 'static>(
+        mut self,
+        key: K,
+        value: T,
+    ) -> Self {
+        self.fields.insert(key.into(), AnySurrealType::new(value));
+        self
+    }
+
+    /// Set a pre-built [`AnySurrealType`] edge property.
+    pub fn set_any_field<K: Into<String>>(mut self, key: K, value: AnySurrealType) -> Self {
+        self.fields.insert(key.into(), value);
+        self
+    }
+}
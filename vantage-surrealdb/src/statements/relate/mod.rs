@@ -0,0 +1,55 @@
+//! SurrealDB `RELATE` statement builder.
+//!
+//! Builds parameterized `RELATE from->edge->to [SET ...]` expressions that
+//! create a graph edge record between two existing records.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use vantage_surrealdb::{SurrealRelate, thing::Thing};
+//!
+//! // Bare edge
+//! let rel = SurrealRelate::new(
+//!     Thing::new("person", "alice"),
+//!     "knows",
+//!     Thing::new("person", "bob"),
+//! );
+//!
+//! // Edge with properties
+//! let rel = SurrealRelate::new(
+//!     Thing::new("person", "alice"),
+//!     "purchased",
+//!     Thing::new("product", "tea"),
+//! )
+//! .set_field("quantity", 2i64);
+//!
+//! // Execute
+//! rel.execute(&db).await?;
+//! ```
+
+pub mod builder;
+pub mod render;
+
+#[cfg(test)]
+mod tests;
+
+use indexmap::IndexMap;
+
+use crate::thing::Thing;
+use crate::types::AnySurrealType;
+
+/// Builder for SurrealDB `RELATE` statements.
+///
+/// Produces `RELATE from->edge->to [SET key = val, ...]`. Both record ids
+/// and all field values are passed as parameterized CBOR values.
+#[derive(Debug, Clone)]
+pub struct SurrealRelate {
+    /// Record the edge starts from.
+    pub from: Thing,
+    /// Edge table name.
+    pub edge: String,
+    /// Record the edge points to.
+    pub to: Thing,
+    /// Edge properties in insertion order.
+    pub fields: IndexMap<String, AnySurrealType>,
+}
@@ -0,0 +1,63 @@
+use crate::Expr;
+use crate::identifier::Identifier;
+use crate::surrealdb::SurrealDB;
+use crate::types::{AnySurrealType, SurrealType};
+use vantage_core::Result;
+use vantage_expressions::{ExprDataSource, Expression, Expressive, ExpressiveEnum};
+
+use super::SurrealRelate;
+
+impl SurrealRelate {
+    /// Render the statement as a parameterized expression.
+    pub fn render(&self) -> Expr {
+        let edge = Identifier::new(&self.edge).expr().preview();
+        let mut template = format!("RELATE {{}}->{edge}->{{}}");
+        let mut params: Vec<ExpressiveEnum<AnySurrealType>> = vec![
+            ExpressiveEnum::Scalar(AnySurrealType::new(self.from.clone())),
+            ExpressiveEnum::Scalar(AnySurrealType::new(self.to.clone())),
+        ];
+
+        if !self.fields.is_empty() {
+            let placeholders: Vec<String> = self
+                .fields
+                .keys()
+                .map(|k| format!("{} = {{}}", Identifier::new(k).expr().preview()))
+                .collect();
+            template.push_str(&format!(" SET {}", placeholders.join(", ")));
+            for value in self.fields.values() {
+                params.push(ExpressiveEnum::Scalar(value.clone()));
+            }
+        }
+
+        Expression::new(template, params)
+    }
+
+    /// Render into the query string and CBOR parameter map sent to
+    /// SurrealDB (`$_arg1`, `$_arg2`, ...).
+    pub fn render_cbor(&self) -> (String, ciborium::Value) {
+        let (query, params) = SurrealDB::prepare_query(&self.render());
+        (query, params.to_cbor())
+    }
+
+    /// Render the statement as a string (for debugging — never use in queries).
+    pub fn preview(&self) -> String {
+        self.render().preview()
+    }
+
+    /// Execute against `db`, returning the created edge record(s).
+    pub async fn execute(&self, db: &SurrealDB) -> Result<AnySurrealType> {
+        db.execute(&self.render()).await
+    }
+}
+
+impl Expressive<AnySurrealType> for SurrealRelate {
+    fn expr(&self) -> Expr {
+        self.render()
+    }
+}
+
+impl From<SurrealRelate> for Expr {
+    fn from(relate: SurrealRelate) -> Self {
+        relate.render()
+    }
+}
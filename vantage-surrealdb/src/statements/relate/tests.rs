@@ -0,0 +1,86 @@
+use crate::statements::relate::SurrealRelate;
+use crate::thing::Thing;
+use ciborium::Value as CborValue;
+
+fn thing_cbor(table: &str, id: &str) -> CborValue {
+    CborValue::Tag(
+        8,
+        Box::new(CborValue::Array(vec![
+            CborValue::Text(table.to_string()),
+            CborValue::Text(id.to_string()),
+        ])),
+    )
+}
+
+#[test]
+fn test_relate_bare() {
+    let relate = SurrealRelate::new(
+        Thing::new("person", "alice"),
+        "knows",
+        Thing::new("person", "bob"),
+    );
+
+    assert_eq!(relate.preview(), "RELATE person:alice->knows->person:bob");
+
+    let (query, params) = relate.render_cbor();
+    assert_eq!(query, "RELATE $_arg1->knows->$_arg2");
+    assert_eq!(
+        params,
+        CborValue::Map(vec![
+            (
+                CborValue::Text("_arg1".into()),
+                thing_cbor("person", "alice")
+            ),
+            (CborValue::Text("_arg2".into()), thing_cbor("person", "bob")),
+        ])
+    );
+}
+
+#[test]
+fn test_relate_with_properties() {
+    let relate = SurrealRelate::new(
+        Thing::new("person", "alice"),
+        "purchased",
+        Thing::new("product", "tea"),
+    )
+    .set_field("quantity", 2i64)
+    .set_field("note", "gift".to_string());
+
+    assert_eq!(
+        relate.preview(),
+        "RELATE person:alice->purchased->product:tea SET quantity = 2, note = \"gift\""
+    );
+
+    let (query, params) = relate.render_cbor();
+    assert_eq!(
+        query,
+        "RELATE $_arg1->purchased->$_arg2 SET quantity = $_arg3, note = $_arg4"
+    );
+    assert_eq!(
+        params,
+        CborValue::Map(vec![
+            (
+                CborValue::Text("_arg1".into()),
+                thing_cbor("person", "alice")
+            ),
+            (
+                CborValue::Text("_arg2".into()),
+                thing_cbor("product", "tea")
+            ),
+            (
+                CborValue::Text("_arg3".into()),
+                CborValue::Integer(2.into())
+            ),
+            (
+                CborValue::Text("_arg4".into()),
+                CborValue::Text("gift".into())
+            ),
+        ])
+    );
+}
+
+#[test]
+fn test_relate_edge_escaping() {
+    let relate = SurrealRelate::new(Thing::new("a", "1"), "SELECT", Thing::new("b", "2"));
+    assert_eq!(relate.preview(), "RELATE a:1->⟨SELECT⟩->b:2");
+}
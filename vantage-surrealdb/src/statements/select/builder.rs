@@ -1,4 +1,6 @@
 use crate::identifier::Identifier;
+use crate::operation::SurrealOperation;
+use crate::thing::Thing;
 use crate::{AnySurrealType, Expr};
 use vantage_expressions::ExpressiveOr;
 use vantage_expressions::result::QueryResult;
@@ -57,6 +59,40 @@ impl<T: QueryResult> SurrealSelect<T> {
         self
     }
 
+    /// Adds `column IN (subquery)` to the WHERE clause. The subquery stays a
+    /// nested expression, so its parameters flatten into this query.
+    pub fn add_where_in_subquery(
+        &mut self,
+        column: &str,
+        subquery: impl Expressive<AnySurrealType>,
+    ) {
+        self.where_conditions
+            .push(Identifier::new(column).in_(subquery));
+    }
+
+    /// Builder form of [`Self::add_where_in_subquery`].
+    pub fn with_where_in_subquery(
+        mut self,
+        column: &str,
+        subquery: impl Expressive<AnySurrealType>,
+    ) -> Self {
+        self.add_where_in_subquery(column, subquery);
+        self
+    }
+
+    /// Adds `column = thing` to the WHERE clause. The record id is bound as
+    /// a typed parameter (a CBOR record id), never spliced in as text.
+    pub fn add_where_record_eq(&mut self, column: &str, thing: Thing) {
+        self.where_conditions
+            .push(Identifier::new(column).eq(AnySurrealType::new(thing)));
+    }
+
+    /// Builder form of [`Self::add_where_record_eq`].
+    pub fn with_where_record_eq(mut self, column: &str, thing: Thing) -> Self {
+        self.add_where_record_eq(column, thing);
+        self
+    }
+
     pub fn with_order_by(
         mut self,
         field: impl ExpressiveOr<AnySurrealType, Identifier>,
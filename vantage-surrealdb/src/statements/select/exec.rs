@@ -1,9 +1,22 @@
-use crate::{AnySurrealType, surrealdb::SurrealDB};
+use crate::{AnySurrealType, SurrealType, surrealdb::SurrealDB};
 use vantage_core::Result;
-use vantage_expressions::{ExprDataSource, Expressive, result};
+use vantage_expressions::{ExprDataSource, Expressive, result, result::QueryResult};
 
 use super::SurrealSelect;
 
+impl<T: QueryResult> SurrealSelect<T> {
+    /// Execute with `EXPLAIN` and return the query plan. See
+    /// [`SurrealSelect::explained`] to preview the statement.
+    pub async fn explain(&self, db: &SurrealDB) -> Result<AnySurrealType> {
+        db.execute(&self.explained(false)).await
+    }
+
+    /// Same as [`explain`](Self::explain), using `EXPLAIN FULL`.
+    pub async fn explain_full(&self, db: &SurrealDB) -> Result<AnySurrealType> {
+        db.execute(&self.explained(true)).await
+    }
+}
+
 impl SurrealSelect<result::Single> {
     pub async fn get(&self, db: &SurrealDB) -> Result<AnySurrealType> {
         db.execute(&self.expr()).await
@@ -29,6 +42,27 @@ impl SurrealSelect<result::Rows> {
             .try_get()
             .ok_or_else(|| vantage_core::error!("Expected array of objects from database query"))
     }
+
+    /// Fetch one column as a flat list: runs `SELECT VALUE column` and
+    /// converts every value to `T`. Errors on the first value that isn't a `T`
+    /// (a missing field comes back as `NONE`, which fails for non-`Option` types).
+    pub async fn pluck<T: SurrealType>(self, column: &str, db: &SurrealDB) -> Result<Vec<T>> {
+        let values = self.only(column).get(db).await?;
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(row, value)| {
+                value.try_get::<T>().ok_or_else(|| {
+                    vantage_core::error!(
+                        "Plucked value does not convert to the requested type",
+                        column = column,
+                        row = row,
+                        value = format!("{:?}", value)
+                    )
+                })
+            })
+            .collect()
+    }
 }
 
 impl SurrealSelect<result::SingleRow> {
@@ -1,5 +1,6 @@
 use crate::identifier::Identifier;
 use crate::sum::{Fx, Sum};
+use crate::surreal_expr;
 use crate::{AnySurrealType, Expr};
 use vantage_expressions::result::QueryResult;
 use vantage_expressions::traits::expressive::Expressive;
@@ -25,6 +26,42 @@ impl<T: QueryResult> Selectable<AnySurrealType> for SurrealSelect<T> {
         self.where_conditions.push(condition.into());
     }
 
+    /// SurrealQL has no `BETWEEN`, so this compares against each bound.
+    fn add_where_between(
+        &mut self,
+        column: &str,
+        lo: impl Expressive<AnySurrealType>,
+        hi: impl Expressive<AnySurrealType>,
+    ) {
+        let column = Identifier::new(column);
+        self.add_where_condition(surreal_expr!(
+            "{} >= {} AND {} <= {}",
+            (column.expr()),
+            (lo.expr()),
+            (column.expr()),
+            (hi.expr())
+        ));
+    }
+
+    /// SurrealQL's `=` already matches NULL to NULL. A missing field is
+    /// NONE, not NULL, so it doesn't match a NULL value.
+    fn add_where_eq_null_safe(&mut self, column: &str, value: impl Expressive<AnySurrealType>) {
+        self.add_where_condition(surreal_expr!(
+            "{} = {}",
+            (Identifier::new(column)),
+            (value.expr())
+        ));
+    }
+
+    /// SurrealQL has no `EXISTS`; a subquery returns an array, so test its length.
+    fn add_where_exists(&mut self, subquery: impl Expressive<AnySurrealType>) {
+        self.add_where_condition(surreal_expr!("array::len({}) > 0", (subquery.expr())));
+    }
+
+    fn add_where_not_exists(&mut self, subquery: impl Expressive<AnySurrealType>) {
+        self.add_where_condition(surreal_expr!("array::len({}) = 0", (subquery.expr())));
+    }
+
     fn set_distinct(&mut self, distinct: bool) {
         self.distinct = distinct;
     }
@@ -106,14 +143,31 @@ impl<T: QueryResult> Selectable<AnySurrealType> for SurrealSelect<T> {
     }
 
     fn as_sum(&self, column: impl Expressive<AnySurrealType>) -> Expr {
-        Sum::new(column.expr()).into()
+        Sum::new(self.as_values(column)).into()
     }
 
     fn as_max(&self, column: impl Expressive<AnySurrealType>) -> Expr {
-        Fx::new("math::max", vec![column.expr()]).into()
+        Fx::new("math::max", vec![self.as_values(column)]).into()
     }
 
     fn as_min(&self, column: impl Expressive<AnySurrealType>) -> Expr {
-        Fx::new("math::min", vec![column.expr()]).into()
+        Fx::new("math::min", vec![self.as_values(column)]).into()
+    }
+
+    fn as_avg(&self, column: impl Expressive<AnySurrealType>) -> Expr {
+        Fx::new("math::mean", vec![self.as_values(column)]).into()
+    }
+}
+
+impl<T: QueryResult> SurrealSelect<T> {
+    /// `SELECT VALUE column ...` under this query's conditions, the array the
+    /// `math::` aggregates above fold.
+    fn as_values(&self, column: impl Expressive<AnySurrealType>) -> Expr {
+        let mut s = self.clone();
+        s.fields.clear();
+        s.fields.push(SelectField::new(column.expr()));
+        s.single_value = true;
+        s.order_by.clear();
+        s.render()
     }
 }
@@ -128,6 +128,16 @@ impl<T: QueryResult> SurrealSelect<T> {
     pub fn preview(&self) -> String {
         self.render().preview()
     }
+
+    /// Renders the statement with SurrealDB's `EXPLAIN` clause appended, so
+    /// executing it returns the query plan instead of rows. With `full`, the
+    /// plan also reports how many records each step fetched (`EXPLAIN FULL`).
+    pub fn explained(&self, full: bool) -> Expr {
+        surreal_expr!(
+            format!("{{}} EXPLAIN{}", if full { " FULL" } else { "" }),
+            (self.render())
+        )
+    }
 }
 
 impl<T: QueryResult> Expressive<AnySurrealType> for SurrealSelect<T> {
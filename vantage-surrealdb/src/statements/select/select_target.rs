@@ -79,6 +79,7 @@ impl From<SourceRef<AnySurrealType>> for SelectTarget {
                 Identifier::new(source).expr()
             }
             ExpressiveEnum::Nested(expr) => surreal_expr!("({})", (expr)),
+            ExpressiveEnum::Raw(sql) => surreal_expr!(sql),
             ExpressiveEnum::Deferred(_) => {
                 panic!("Cannot use deferred as select source")
             }
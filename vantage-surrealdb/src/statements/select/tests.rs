@@ -223,3 +223,225 @@ fn test_selectable_trait_methods() {
     assert!(!select.has_order_by());
     assert!(!select.has_group_by());
 }
+
+#[test]
+fn test_from_only_renders_only() {
+    let select = SurrealSelect::new()
+        .from(surreal_expr!("users:alice"))
+        .from_only()
+        .unwrap();
+    assert_eq!(select.preview(), "SELECT * FROM ONLY users:alice");
+
+    let two = SurrealSelect::new().from("users").from("admins");
+    assert!(two.from_only().is_err());
+}
+
+#[tokio::test]
+async fn test_from_only_get_returns_map() {
+    use crate::surrealdb::SurrealDB;
+    use serde_json::json;
+    use surreal_client::SurrealMockBuilder;
+
+    let client = SurrealMockBuilder::new()
+        .with_exact_response(
+            "query",
+            json!(["SELECT * FROM ONLY users:alice", {}]),
+            json!([{"status": "OK", "result": {"id": "users:alice", "name": "Alice"}}]),
+        )
+        .build();
+    let db = SurrealDB::new(client);
+
+    let row = SurrealSelect::new()
+        .from(surreal_expr!("users:alice"))
+        .from_only()
+        .unwrap()
+        .get(&db)
+        .await
+        .unwrap();
+    assert_eq!(row["name"].try_get::<String>().as_deref(), Some("Alice"));
+}
+
+#[test]
+fn test_where_record_eq_binds_thing() {
+    use crate::surrealdb::SurrealDB;
+    use crate::thing::Thing;
+    use vantage_expressions::Expressive;
+
+    let bakery = Thing::new("bakery", "hill_valley");
+    let select = SurrealSelect::new()
+        .from("client")
+        .with_where(surreal_expr!("is_active = {}", true))
+        .with_where_record_eq("bakery", bakery.clone());
+
+    let (query, params) = SurrealDB::prepare_query(&select.expr());
+    assert_eq!(
+        query,
+        "SELECT * FROM client WHERE is_active = $_arg1 AND bakery = $_arg2"
+    );
+    assert_eq!(params["_arg2"].try_get::<Thing>(), Some(bakery));
+    assert_eq!(params["_arg2"].try_get::<String>(), None);
+}
+
+#[test]
+fn test_where_exists_uses_array_len() {
+    let orders = SurrealSelect::new()
+        .from("order")
+        .with_where(surreal_expr!("customer = $parent.id"));
+    let mut select = SurrealSelect::new().from("customer");
+    select.add_where_exists(orders.clone());
+    assert_eq!(
+        select.preview(),
+        "SELECT * FROM customer WHERE array::len(SELECT * FROM order WHERE customer = $parent.id) > 0"
+    );
+
+    let mut select = SurrealSelect::new().from("customer");
+    select.add_where_not_exists(orders);
+    assert_eq!(
+        select.preview(),
+        "SELECT * FROM customer WHERE array::len(SELECT * FROM order WHERE customer = $parent.id) = 0"
+    );
+}
+
+#[tokio::test]
+async fn test_pluck_flat_values() {
+    use crate::surrealdb::SurrealDB;
+    use serde_json::json;
+    use surreal_client::SurrealMockBuilder;
+
+    let client = SurrealMockBuilder::new()
+        .with_exact_response(
+            "query",
+            json!(["SELECT VALUE name FROM users", {}]),
+            json!([{"status": "OK", "result": ["Alice", "Bob"]}]),
+        )
+        .with_exact_response(
+            "query",
+            json!(["SELECT VALUE age FROM users", {}]),
+            json!([{"status": "OK", "result": [31, 42]}]),
+        )
+        .build();
+    let db = SurrealDB::new(client);
+    let users = SurrealSelect::new().from("users");
+
+    let names = users.clone().pluck::<String>("name", &db).await.unwrap();
+    assert_eq!(names, vec!["Alice", "Bob"]);
+    let ages = users.clone().pluck::<i64>("age", &db).await.unwrap();
+    assert_eq!(ages, vec![31, 42]);
+
+    // Ages are not strings
+    let err = users.pluck::<String>("age", &db).await.unwrap_err();
+    assert!(err.to_string().contains("does not convert"));
+}
+
+#[test]
+fn test_where_between_compares_bounds() {
+    use crate::surrealdb::SurrealDB;
+    use vantage_expressions::Expressive;
+
+    let mut select = SurrealSelect::new().from("order");
+    select.add_where_between("total", 10i64, 20i64);
+    let (query, params) = SurrealDB::prepare_query(&select.expr());
+    assert_eq!(
+        query,
+        "SELECT * FROM order WHERE total >= $_arg1 AND total <= $_arg2"
+    );
+    assert_eq!(params["_arg2"].try_get::<i64>(), Some(20));
+
+    let mut select = SurrealSelect::new().from("order");
+    select.add_where_between(
+        "created_at",
+        surreal_expr!("<datetime>{}", "2024-01-01T00:00:00Z"),
+        surreal_expr!("<datetime>{}", "2024-03-31T23:59:59Z"),
+    );
+    let (query, params) = SurrealDB::prepare_query(&select.expr());
+    assert_eq!(
+        query,
+        "SELECT * FROM order WHERE created_at >= <datetime>$_arg1 AND created_at <= <datetime>$_arg2"
+    );
+    assert_eq!(
+        params["_arg1"].try_get::<String>().as_deref(),
+        Some("2024-01-01T00:00:00Z")
+    );
+}
+
+#[test]
+fn test_subquery_parameters_number_depth_first() {
+    use crate::primitives::subquery;
+    use crate::surrealdb::SurrealDB;
+    use vantage_expressions::Expressive;
+
+    let orders = SurrealSelect::new()
+        .with_value()
+        .with_field("customer")
+        .from("order")
+        .with_where(surreal_expr!("total > {}", 100i64))
+        .with_where(surreal_expr!("status = {}", "paid"));
+    let select = SurrealSelect::new()
+        .from("customer")
+        .with_where(surreal_expr!("country = {}", "UK"))
+        .with_where(surreal_expr!("id IN {}", (subquery(orders))))
+        .with_where(surreal_expr!("age > {}", 18i64));
+
+    let (query, params) = SurrealDB::prepare_query(&select.expr());
+    assert_eq!(
+        query,
+        "SELECT * FROM customer WHERE country = $_arg1 AND id IN \
+         (SELECT VALUE customer FROM order WHERE total > $_arg2 AND status = $_arg3) \
+         AND age > $_arg4"
+    );
+    assert_eq!(
+        params.keys().collect::<Vec<_>>(),
+        vec!["_arg1", "_arg2", "_arg3", "_arg4"]
+    );
+    assert_eq!(params["_arg1"].try_get::<String>().as_deref(), Some("UK"));
+    assert_eq!(params["_arg2"].try_get::<i64>(), Some(100));
+    assert_eq!(params["_arg3"].try_get::<String>().as_deref(), Some("paid"));
+    assert_eq!(params["_arg4"].try_get::<i64>(), Some(18));
+}
+
+#[test]
+fn test_where_eq_null_safe_uses_equals() {
+    use crate::surrealdb::SurrealDB;
+    use vantage_expressions::Expressive;
+
+    let mut select = SurrealSelect::new().from("employee");
+    select.add_where_eq_null_safe("manager", surreal_expr!("NULL"));
+    assert_eq!(
+        select.preview(),
+        "SELECT * FROM employee WHERE manager = NULL"
+    );
+
+    let mut select = SurrealSelect::new().from("employee");
+    select.add_where_eq_null_safe("manager", "doc".to_string());
+    let (query, params) = SurrealDB::prepare_query(&select.expr());
+    assert_eq!(query, "SELECT * FROM employee WHERE manager = $_arg1");
+    assert_eq!(params["_arg1"].try_get::<String>().as_deref(), Some("doc"));
+}
+
+#[test]
+fn test_selectable_aggregates_fold_select_values() {
+    // Through the trait, the aggregates fold the filtered values rather than
+    // rendering a bare `math::sum(col)`; ordering is dropped.
+    let select = SurrealSelect::new()
+        .from("orders")
+        .with_where(surreal_expr!("status = {}", "paid"))
+        .with_order_by(Field::new("total"), Order::Desc);
+    let total = Field::new("total");
+
+    assert_eq!(
+        Selectable::as_sum(&select, total.clone()).preview(),
+        "math::sum(SELECT VALUE total FROM orders WHERE status = \"paid\")"
+    );
+    assert_eq!(
+        Selectable::as_max(&select, total.clone()).preview(),
+        "math::max(SELECT VALUE total FROM orders WHERE status = \"paid\")"
+    );
+    assert_eq!(
+        Selectable::as_min(&select, total.clone()).preview(),
+        "math::min(SELECT VALUE total FROM orders WHERE status = \"paid\")"
+    );
+    assert_eq!(
+        Selectable::as_avg(&select, total).preview(),
+        "math::mean(SELECT VALUE total FROM orders WHERE status = \"paid\")"
+    );
+}
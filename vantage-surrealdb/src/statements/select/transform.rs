@@ -4,6 +4,7 @@ use crate::AnySurrealType;
 use crate::identifier::Identifier;
 use crate::sum::{Fx, Sum};
 use crate::surreal_return::SurrealReturn;
+use vantage_core::{Result, error};
 use vantage_expressions::{Expressive, ExpressiveOr, result};
 
 use super::SurrealSelect;
@@ -30,6 +31,33 @@ impl SurrealSelect<result::Rows> {
         let query = self.only(field_or_expr);
         SurrealReturn::new(Fx::new("math::min", vec![query.expr()]).into())
     }
+    pub fn as_avg(
+        self,
+        field_or_expr: impl ExpressiveOr<AnySurrealType, Identifier>,
+    ) -> SurrealReturn {
+        let query = self.only(field_or_expr);
+        SurrealReturn::new(Fx::new("math::mean", vec![query.expr()]).into())
+    }
+    /// `RETURN array::distinct(SELECT VALUE field ...)` — the field's values
+    /// with duplicates removed, in first-seen order. SurrealQL has no
+    /// `SELECT DISTINCT`.
+    pub fn as_distinct(
+        self,
+        field_or_expr: impl ExpressiveOr<AnySurrealType, Identifier>,
+    ) -> SurrealReturn {
+        let query = self.only(field_or_expr);
+        SurrealReturn::new(Fx::new("array::distinct", vec![query.expr()]).into())
+    }
+    /// `RETURN array::len(array::distinct(SELECT VALUE field ...))` — how many
+    /// different values the field takes. NONE and NULL count as values.
+    pub fn as_count_distinct(
+        self,
+        field_or_expr: impl ExpressiveOr<AnySurrealType, Identifier>,
+    ) -> SurrealReturn {
+        let query = self.only(field_or_expr);
+        let distinct = Fx::new("array::distinct", vec![query.expr()]);
+        SurrealReturn::new(Fx::new("array::len", vec![distinct.expr()]).into())
+    }
     pub fn as_count(self) -> SurrealReturn {
         let result = self.only("id");
         SurrealReturn::new(Fx::new("count", vec![result.expr()]).into())
@@ -67,6 +95,20 @@ impl SurrealSelect<result::Rows> {
             single_value: true,
         }
     }
+    /// `SELECT ... FROM ONLY target` — SurrealDB returns the record itself
+    /// instead of a one-element array. `ONLY` accepts a single target, so
+    /// this errors if the query selects from more than one.
+    ///
+    /// The name `only` is taken by the single-field projection above.
+    pub fn from_only(self) -> Result<SurrealSelect<result::SingleRow>> {
+        if self.from.len() > 1 {
+            return Err(error!(
+                "FROM ONLY needs a single target",
+                targets = self.from.len()
+            ));
+        }
+        Ok(self.only_first_row())
+    }
     pub fn only_first_row(self) -> SurrealSelect<result::SingleRow> {
         if self.from_only {
             panic!("SelectQuery<Rows>::as_one_row() must not have from_only=true");
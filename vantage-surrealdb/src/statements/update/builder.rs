@@ -2,7 +2,7 @@ use crate::identifier::Identifier;
 use crate::types::{AnySurrealType, SurrealType};
 use vantage_expressions::Expressive;
 
-use super::{SurrealUpdate, UpdateMode};
+use super::{PatchOp, SurrealUpdate, UpdateMode};
 
 impl SurrealUpdate {
     /// Create an UPDATE targeting a whole table by name: `UPDATE tablename ...`
@@ -13,6 +13,7 @@ impl SurrealUpdate {
             target: Identifier::new(table).expr(),
             mode: UpdateMode::Set,
             fields: indexmap::IndexMap::new(),
+            patches: Vec::new(),
             conditions: Vec::new(),
             upsert: false,
         }
@@ -24,6 +25,7 @@ impl SurrealUpdate {
             target: target.expr(),
             mode: UpdateMode::Set,
             fields: indexmap::IndexMap::new(),
+            patches: Vec::new(),
             conditions: Vec::new(),
             upsert: false,
         }
@@ -49,6 +51,14 @@ impl SurrealUpdate {
         self
     }
 
+    /// Switch to PATCH mode and append `ops`: `UPDATE target PATCH [...]`.
+    ///
+    /// Operations accumulate across calls and are applied in order.
+    pub fn patch(&mut self, ops: Vec<PatchOp>) {
+        self.mode = UpdateMode::Patch;
+        self.patches.extend(ops);
+    }
+
     /// Switch to SET mode (the default): `UPDATE target SET key = val, ...`
     pub fn set(mut self) -> Self {
         self.mode = UpdateMode::Set;
@@ -5,13 +5,14 @@
 //! - **SET** (default) — `UPDATE target SET key = val, ...`
 //! - **CONTENT** — `UPDATE target CONTENT {...}` (replaces all fields)
 //! - **MERGE** — `UPDATE target MERGE {...}` (partial update, keeps unmentioned fields)
+//! - **PATCH** — `UPDATE target PATCH [{op, path, value}, ...]` (JSON Patch operations)
 //!
 //! Supports optional `WHERE` conditions for bulk updates.
 //!
 //! # Examples
 //!
 //! ```rust,ignore
-//! use vantage_surrealdb::{SurrealUpdate, thing::Thing};
+//! use vantage_surrealdb::{PatchOp, SurrealUpdate, thing::Thing};
 //!
 //! // SET mode (default) — update specific fields
 //! let upd = SurrealUpdate::new(Thing::new("users", "alice"))
@@ -27,6 +28,13 @@
 //!     .merge()
 //!     .with_field("verified", true);
 //!
+//! // PATCH mode — JSON Patch operations
+//! let mut upd = SurrealUpdate::new(Thing::new("users", "alice"));
+//! upd.patch(vec![
+//!     PatchOp::replace("/name", "Alice".to_string()),
+//!     PatchOp::remove("/nickname"),
+//! ]);
+//!
 //! // Bulk update with WHERE
 //! let upd = SurrealUpdate::table("users")
 //!     .with_field("active", false)
@@ -45,7 +53,7 @@ mod tests;
 use indexmap::IndexMap;
 
 use crate::Expr;
-use crate::types::AnySurrealType;
+use crate::types::{AnySurrealType, SurrealType};
 
 /// Update mode determines the SurrealDB update strategy.
 #[derive(Debug, Clone)]
@@ -56,19 +64,56 @@ pub enum UpdateMode {
     Content,
     /// `UPDATE target MERGE {...}` — partial update, keeps unmentioned fields
     Merge,
+    /// `UPDATE target PATCH [...]` — apply [`PatchOp`]s in order
+    Patch,
+}
+
+/// A single JSON Patch operation for [`SurrealUpdate::patch`].
+///
+/// Paths are JSON Pointers into the record, e.g. `/address/city`.
+#[derive(Debug, Clone)]
+pub enum PatchOp {
+    /// Add `value` at `path`, creating the field (or inserting into an array).
+    Add { path: String, value: AnySurrealType },
+    /// Remove whatever is at `path`.
+    Remove { path: String },
+    /// Replace the existing value at `path`.
+    Replace { path: String, value: AnySurrealType },
+}
+
+impl PatchOp {
+    pub fn add<T: SurrealType + 'static>(path: impl Into<String>, value: T) -> Self {
+        PatchOp::Add {
+            path: path.into(),
+            value: AnySurrealType::new(value),
+        }
+    }
+
+    pub fn remove(path: impl Into<String>) -> Self {
+        PatchOp::Remove { path: path.into() }
+    }
+
+    pub fn replace<T: SurrealType + 'static>(path: impl Into<String>, value: T) -> Self {
+        PatchOp::Replace {
+            path: path.into(),
+            value: AnySurrealType::new(value),
+        }
+    }
 }
 
 /// Builder for SurrealDB `UPDATE` statements.
 ///
-/// Produces `UPDATE target SET/CONTENT/MERGE ... [WHERE ...]`.
+/// Produces `UPDATE target SET/CONTENT/MERGE/PATCH ... [WHERE ...]`.
 /// All field values are passed as parameterized CBOR values, not inlined strings.
 pub struct SurrealUpdate {
     /// Target expression (table name, `Thing`, or arbitrary expression).
     pub target: Expr,
-    /// Update strategy: SET, CONTENT, or MERGE.
+    /// Update strategy: SET, CONTENT, MERGE, or PATCH.
     pub mode: UpdateMode,
     /// Field key-value pairs in insertion order.
     pub fields: IndexMap<String, AnySurrealType>,
+    /// Patch operations, rendered in PATCH mode.
+    pub patches: Vec<PatchOp>,
     /// Optional WHERE conditions (combined with AND).
     pub conditions: Vec<Expr>,
     /// Render as `UPSERT` rather than `UPDATE`. Since SurrealDB 2.0 a plain
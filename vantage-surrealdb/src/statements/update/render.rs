@@ -4,7 +4,7 @@ use crate::types::AnySurrealType;
 use indexmap::IndexMap;
 use vantage_expressions::Expressive;
 
-use super::{SurrealUpdate, UpdateMode};
+use super::{PatchOp, SurrealUpdate, UpdateMode};
 
 impl SurrealUpdate {
     /// Render the statement as a string (for debugging — never use in queries).
@@ -23,6 +23,29 @@ impl SurrealUpdate {
             .unwrap_or_else(|| AnySurrealType::new(IndexMap::<String, AnySurrealType>::new()))
     }
 
+    /// Build a CBOR array of `{op, path, value}` objects (for PATCH).
+    fn patches_as_array(&self) -> AnySurrealType {
+        let text = |s: &str| ciborium::Value::Text(s.to_string());
+        let ops: Vec<ciborium::Value> = self
+            .patches
+            .iter()
+            .map(|op| {
+                let (name, path, value) = match op {
+                    PatchOp::Add { path, value } => ("add", path, Some(value)),
+                    PatchOp::Remove { path } => ("remove", path, None),
+                    PatchOp::Replace { path, value } => ("replace", path, Some(value)),
+                };
+                let mut entry = vec![(text("op"), text(name)), (text("path"), text(path))];
+                if let Some(value) = value {
+                    entry.push((text("value"), value.value().clone()));
+                }
+                ciborium::Value::Map(entry)
+            })
+            .collect();
+        AnySurrealType::from_cbor(&ciborium::Value::Array(ops))
+            .unwrap_or_else(|| AnySurrealType::new(Vec::<AnySurrealType>::new()))
+    }
+
     fn render_where(&self) -> Option<Expr> {
         if self.conditions.is_empty() {
             return None;
@@ -97,6 +120,17 @@ impl Expressive<AnySurrealType> for SurrealUpdate {
                     ],
                 )
             }
+            UpdateMode::Patch => {
+                let ops = self.patches_as_array();
+                let template = format!("{verb} {{}} PATCH {{}}");
+                vantage_expressions::Expression::new(
+                    template,
+                    vec![
+                        ExpressiveEnum::Nested(self.target.clone()),
+                        ExpressiveEnum::Scalar(ops),
+                    ],
+                )
+            }
         };
         self.append_where(raw)
     }
@@ -170,3 +170,55 @@ fn test_merge_with_condition() {
     assert!(p.contains("MERGE"));
     assert!(p.contains("WHERE email_confirmed = true"));
 }
+
+#[test]
+fn test_patch_replace_and_add() {
+    use crate::statements::update::PatchOp;
+
+    let mut update = SurrealUpdate::new(Thing::new("users", "john"));
+    update.patch(vec![
+        PatchOp::replace("/name", "Johnny".to_string()),
+        PatchOp::add("/tags/-", "admin".to_string()),
+    ]);
+    update.patch(vec![PatchOp::remove("/nickname")]);
+
+    let (query, params) = crate::surrealdb::SurrealDB::prepare_query(&update.expr());
+    assert_eq!(query, "UPDATE users:john PATCH $_arg1");
+
+    let ops = match params["_arg1"].value() {
+        ciborium::Value::Array(ops) => ops.clone(),
+        other => panic!("expected an array of patch ops, got {other:?}"),
+    };
+    let text = |s: &str| ciborium::Value::Text(s.to_string());
+    assert_eq!(
+        ops,
+        vec![
+            ciborium::Value::Map(vec![
+                (text("op"), text("replace")),
+                (text("path"), text("/name")),
+                (text("value"), text("Johnny")),
+            ]),
+            ciborium::Value::Map(vec![
+                (text("op"), text("add")),
+                (text("path"), text("/tags/-")),
+                (text("value"), text("admin")),
+            ]),
+            ciborium::Value::Map(vec![
+                (text("op"), text("remove")),
+                (text("path"), text("/nickname")),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn test_patch_with_condition() {
+    use crate::statements::update::PatchOp;
+
+    let mut update = SurrealUpdate::table("users");
+    update.patch(vec![PatchOp::replace("/active", false)]);
+    let update = update.with_condition(crate::surreal_expr!("age > {}", 30i64));
+
+    let (query, _) = crate::surrealdb::SurrealDB::prepare_query(&update.expr());
+    assert_eq!(query, "UPDATE users PATCH $_arg1 WHERE age > $_arg2");
+}
@@ -30,8 +30,7 @@ impl SurrealDB {
 
     /// Convert {} placeholders to $_arg1, $_arg2, etc. and extract parameters
     /// which is the preferred way for Surreal querying
-    pub(super) fn prepare_query(
-        &self,
+    pub(crate) fn prepare_query(
         expr: &Expression<AnySurrealType>,
     ) -> (String, IndexMap<String, AnySurrealType>) {
         let flattener = ExpressionFlattener::new();
@@ -59,6 +58,9 @@ impl SurrealDB {
                 vantage_expressions::ExpressiveEnum::Nested(_) => {
                     unreachable!("Nested params should be flattened before prepare_query");
                 }
+                vantage_expressions::ExpressiveEnum::Raw(_) => {
+                    unreachable!("Raw text should be flattened before prepare_query");
+                }
             }
 
             if i + 1 < template_parts.len() {
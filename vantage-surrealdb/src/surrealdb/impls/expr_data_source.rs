@@ -26,10 +26,14 @@ async fn resolve_deferred(expr: &Expression<AnySurrealType>) -> Result<Expressio
     Ok(Expression::new(expr.template.clone(), resolved_params))
 }
 
-impl ExprDataSource<AnySurrealType> for SurrealDB {
-    async fn execute(&self, expr: &Expression<AnySurrealType>) -> Result<AnySurrealType> {
+impl SurrealDB {
+    /// Send `expr` as one request and return the raw per-statement responses.
+    async fn query_responses(
+        &self,
+        expr: &Expression<AnySurrealType>,
+    ) -> Result<Vec<ciborium::Value>> {
         let resolved = resolve_deferred(expr).await?;
-        let (query_str, params) = self.prepare_query(&resolved);
+        let (query_str, params) = Self::prepare_query(&resolved);
         let params_cbor = params.to_cbor();
         let client = self.inner.lock().await;
         let result = client
@@ -55,66 +59,93 @@ impl ExprDataSource<AnySurrealType> for SurrealDB {
                 expression = query_str.clone()
             ))?;
 
-        // Ensure we have exactly one response (we only send single queries)
-        let responses = result
-            .as_array()
-            .ok_or_else(|| vantage_core::error!("Expected array response from SurrealDB"))?;
-
-        if responses.len() != 1 {
-            return Err(vantage_core::error!(
-                "Expected single response from SurrealDB",
-                response_count = responses.len()
-            ));
+        match result {
+            ciborium::Value::Array(responses) => Ok(responses),
+            _ => Err(vantage_core::error!(
+                "Expected array response from SurrealDB"
+            )),
         }
+    }
+
+    /// Execute a script of `;`-separated statements in a single round-trip,
+    /// returning each statement's result in order. Fails on the first
+    /// statement that did not succeed.
+    pub async fn execute_statements(
+        &self,
+        expr: &Expression<AnySurrealType>,
+    ) -> Result<Vec<AnySurrealType>> {
+        self.query_responses(expr)
+            .await?
+            .iter()
+            .map(statement_result)
+            .collect()
+    }
+}
 
-        let response = responses.first().unwrap();
-        let response_map = response
-            .as_map()
-            .ok_or_else(|| vantage_core::error!("Expected map response from SurrealDB"))?;
+/// Unwrap one `{status, result}` statement response.
+fn statement_result(response: &ciborium::Value) -> Result<AnySurrealType> {
+    let response_map = response
+        .as_map()
+        .ok_or_else(|| vantage_core::error!("Expected map response from SurrealDB"))?;
 
-        // Check if query was successful by looking at status
-        let is_success = response_map
+    // Check if query was successful by looking at status
+    let is_success = response_map
+        .iter()
+        .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "status"))
+        .and_then(|(_, v)| match v {
+            ciborium::Value::Text(status) => Some(status == "OK"),
+            _ => None,
+        })
+        .unwrap_or(false);
+
+    if is_success {
+        // Extract "result" key from successful response
+        let extracted_result = response_map
             .iter()
-            .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "status"))
+            .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "result"))
+            .map(|(_, v)| v)
+            .ok_or_else(|| {
+                vantage_core::error!(
+                    "Successful SurrealDB response missing result field",
+                    response_payload = format!("{:?}", response)
+                )
+            })?;
+
+        AnySurrealType::from_cbor(extracted_result).ok_or_else(|| {
+            vantage_core::error!("Failed to convert SurrealDB result to AnySurrealType")
+        })
+    } else {
+        // Extract error message from "result" key in failed response
+        let error_message = response_map
+            .iter()
+            .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "result"))
             .and_then(|(_, v)| match v {
-                ciborium::Value::Text(status) => Some(status == "OK"),
+                ciborium::Value::Text(msg) => Some(msg.clone()),
                 _ => None,
             })
-            .unwrap_or(false);
-
-        if is_success {
-            // Extract "result" key from successful response
-            let extracted_result = response_map
-                .iter()
-                .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "result"))
-                .map(|(_, v)| v)
-                .ok_or_else(|| {
-                    vantage_core::error!(
-                        "Successful SurrealDB response missing result field",
-                        response_payload = format!("{:?}", response)
-                    )
-                })?;
+            .unwrap_or_else(|| "Unknown SurrealDB error".to_string());
 
-            AnySurrealType::from_cbor(extracted_result).ok_or_else(|| {
-                vantage_core::error!("Failed to convert SurrealDB result to AnySurrealType")
-            })
-        } else {
-            // Extract error message from "result" key in failed response
-            let error_message = response_map
-                .iter()
-                .find(|(k, _)| matches!(k, ciborium::Value::Text(key) if key == "result"))
-                .and_then(|(_, v)| match v {
-                    ciborium::Value::Text(msg) => Some(msg.clone()),
-                    _ => None,
-                })
-                .unwrap_or_else(|| "Unknown SurrealDB error".to_string());
-
-            Err(vantage_core::error!(
-                "SurrealDB query failed",
-                query_error = error_message,
-                error_type = "query_execution"
-            ))
+        Err(vantage_core::error!(
+            "SurrealDB query failed",
+            query_error = error_message,
+            error_type = "query_execution"
+        ))
+    }
+}
+
+impl ExprDataSource<AnySurrealType> for SurrealDB {
+    async fn execute(&self, expr: &Expression<AnySurrealType>) -> Result<AnySurrealType> {
+        let responses = self.query_responses(expr).await?;
+
+        // Ensure we have exactly one response (we only send single queries)
+        if responses.len() != 1 {
+            return Err(vantage_core::error!(
+                "Expected single response from SurrealDB",
+                response_count = responses.len()
+            ));
         }
+
+        statement_result(&responses[0])
     }
 
     fn defer(&self, expr: Expression<AnySurrealType>) -> DeferredFn<AnySurrealType> {
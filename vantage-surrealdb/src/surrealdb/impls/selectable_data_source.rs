@@ -1,9 +1,11 @@
 use vantage_core::Result;
 use vantage_expressions::traits::datasource::SelectableDataSource;
-use vantage_expressions::{Expression, Expressive};
+use vantage_expressions::{Expression, Expressive, JoinKind, Selectable};
 
+use crate::identifier::Identifier;
 use crate::select::SurrealSelect;
 use crate::select::select_field::SelectField;
+use crate::surreal_expr;
 use crate::surrealdb::SurrealDB;
 use crate::types::AnySurrealType;
 
@@ -27,6 +29,32 @@ impl SelectableDataSource<AnySurrealType> for SurrealDB {
         select.fields.push(field);
     }
 
+    /// SurrealQL has no JOIN. The joined table's first matching record is
+    /// projected under the table's name, via a subquery where `on` reaches
+    /// the outer row as `$parent`. An inner join also keeps only rows that
+    /// have a match. Without declared fields the row's own `*` stays in the
+    /// projection.
+    fn add_select_join(
+        &self,
+        select: &mut Self::Select,
+        kind: JoinKind,
+        table: &str,
+        on: Expression<AnySurrealType>,
+    ) {
+        let matches = surreal_expr!("SELECT * FROM {} WHERE {}", (Identifier::new(table)), (on));
+        if select.fields.is_empty() {
+            select.fields.push(SelectField::new(surreal_expr!("*")));
+        }
+        self.add_select_column(
+            select,
+            surreal_expr!("({})[0]", (matches.clone())),
+            Some(table),
+        );
+        if kind == JoinKind::Inner {
+            select.add_where_exists(matches);
+        }
+    }
+
     async fn execute_select(&self, select: &Self::Select) -> Result<Vec<AnySurrealType>> {
         use vantage_expressions::ExprDataSource;
 
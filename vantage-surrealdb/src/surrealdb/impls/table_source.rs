@@ -8,15 +8,18 @@ use vantage_expressions::traits::associated_expressions::AssociatedExpression;
 use vantage_expressions::traits::datasource::ExprDataSource;
 use vantage_expressions::traits::expressive::ExpressiveEnum;
 use vantage_expressions::{Expression, Expressive};
+use vantage_table::aggregate::AggExpr;
 use vantage_table::column::core::{Column, ColumnType};
-use vantage_table::table::Table;
+use vantage_table::table::{Table, UpsertOutcome};
 use vantage_table::traits::table_source::TableSource;
 use vantage_types::{Entity, Record};
 
 use crate::identifier::Identifier;
 use crate::statements::delete::SurrealDelete;
 use crate::statements::insert::SurrealInsert;
+use crate::statements::select::select_target::SelectTarget;
 use crate::statements::update::SurrealUpdate;
+use crate::sum::Fx;
 
 use crate::surrealdb::SurrealDB;
 use crate::thing::Thing;
@@ -66,6 +69,37 @@ fn parse_cbor_row(
     (thing, Record::from_indexmap(fields))
 }
 
+/// Parse a `SELECT` result (an array of row maps) into records keyed by id.
+fn parse_cbor_rows(
+    result: AnySurrealType,
+    id_field_name: &str,
+    table_name: &str,
+) -> Result<IndexMap<Thing, Record<AnySurrealType>>> {
+    let arr = result
+        .into_value()
+        .into_array()
+        .map_err(|_| error!("list_table_values: expected array result"))?;
+
+    let mut records = IndexMap::new();
+    for item in arr {
+        let map = match item {
+            ciborium::Value::Map(m) => m,
+            _ => continue,
+        };
+
+        let (thing, record) = parse_cbor_row(map, id_field_name, table_name);
+        let id = thing.ok_or_else(|| {
+            error!(
+                "list_table_values: row missing id field",
+                id_field = id_field_name
+            )
+        })?;
+        records.insert(id, record);
+    }
+
+    Ok(records)
+}
+
 /// Extract the first CBOR map from a result that may be a map or an array-of-maps.
 fn extract_first_map(
     result: AnySurrealType,
@@ -105,6 +139,31 @@ impl TableSource for SurrealDB {
         Ok(SurrealOperation::eq(&column, value))
     }
 
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        let column: Column<AnySurrealType> = Column::new(field);
+        Ok(SurrealOperation::gt(&column, value))
+    }
+
+    fn in_values_condition(
+        &self,
+        field: &str,
+        values: Vec<Self::Value>,
+    ) -> Result<Self::Condition> {
+        let values = values
+            .into_iter()
+            .map(|value| crate::surreal_expr!("{}", value))
+            .collect();
+        Ok(crate::surreal_expr!(
+            "{} IN [{}]",
+            (Identifier::new(field)),
+            (Expression::from_vec(values, ", "))
+        ))
+    }
+
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        Ok(Identifier::new(field).expr())
+    }
+
     /// A join value in `"table:key"` string form (a record id that
     /// round-tripped through JSON or a script) is re-tagged into a record
     /// id, so narrowing renders a record literal and inserts store a link.
@@ -192,30 +251,45 @@ impl TableSource for SurrealDB {
 
         let select = table.select();
         let result = self.execute(&select.expr()).await?;
+        parse_cbor_rows(result, &id_field_name, table.table_name())
+    }
 
-        let arr = result
-            .into_value()
-            .into_array()
-            .map_err(|_| error!("list_table_values: expected array result"))?;
-
-        let mut records = IndexMap::new();
-        for item in arr {
-            let map = match item {
-                ciborium::Value::Map(m) => m,
-                _ => continue,
-            };
+    /// `SELECT …; RETURN count(…)` sent as one script, so the page and the
+    /// total cost a single round-trip. The count drops the page's LIMIT/START
+    /// and ordering.
+    async fn list_table_values_with_count<E>(
+        &self,
+        table: &Table<Self, E>,
+    ) -> Result<(IndexMap<Self::Id, Record<Self::Value>>, i64)>
+    where
+        E: Entity<Self::Value>,
+    {
+        let id_field_name = table
+            .id_field()
+            .map(|c| c.name().to_string())
+            .unwrap_or_else(|| "id".to_string());
 
-            let (thing, record) = parse_cbor_row(map, &id_field_name, table.table_name());
-            let id = thing.ok_or_else(|| {
-                error!(
-                    "list_table_values: row missing id field",
-                    id_field = &id_field_name
-                )
-            })?;
-            records.insert(id, record);
-        }
+        let mut count = table.select();
+        count.order_by.clear();
+        count.limit = None;
+        count.skip = None;
+        let script = crate::surreal_expr!("{}; {}", (table.select()), (count.as_count()));
 
-        Ok(records)
+        let [rows, count]: [AnySurrealType; 2] = self
+            .execute_statements(&script)
+            .await?
+            .try_into()
+            .map_err(|_| error!("list_table_values_with_count: expected two results"))?;
+        let count = count.try_get::<i64>().ok_or_else(|| {
+            error!(
+                "list_table_values_with_count: expected i64 count",
+                result = format!("{}", count)
+            )
+        })?;
+        Ok((
+            parse_cbor_rows(rows, &id_field_name, table.table_name())?,
+            count,
+        ))
     }
 
     async fn get_table_value<E>(
@@ -262,6 +336,104 @@ impl TableSource for SurrealDB {
         Ok(Some(record))
     }
 
+    /// `SELECT … FROM type::thing(table, id)` — a record-id target, so the
+    /// lookup never scans the table. Still the table's own select, keeping
+    /// computed columns and conditions (a soft-deleted record stays hidden).
+    async fn find_table_value_by_id<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+    ) -> Result<Option<Record<Self::Value>>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let id_field_name = table
+            .id_field()
+            .map(|c| c.name().to_string())
+            .unwrap_or_else(|| "id".to_string());
+
+        let mut select = table.select();
+        select.from = vec![SelectTarget::new(crate::surreal_expr!(
+            "type::thing({}, {})",
+            (table.table_name()),
+            id
+        ))];
+        let result = self.execute(&select.expr()).await?;
+
+        let arr = result
+            .into_value()
+            .into_array()
+            .map_err(|_| error!("find_table_value_by_id: expected array result"))?;
+        let Some(item) = arr.into_iter().next() else {
+            return Ok(None);
+        };
+        let map = item
+            .into_map()
+            .map_err(|_| error!("find_table_value_by_id: expected map result", id = id))?;
+
+        let (_thing, record) = parse_cbor_row(map, &id_field_name, table.table_name());
+        Ok(Some(record))
+    }
+
+    /// One `UPSERT type::thing(table, id) CONTENT … RETURN BEFORE`: the row
+    /// as it was before the write is absent for a new record, so the outcome
+    /// comes from the same atomic statement.
+    async fn upsert_table_value<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+        record: &Record<Self::Value>,
+    ) -> Result<UpsertOutcome>
+    where
+        E: Entity<Self::Value>,
+    {
+        let update = SurrealUpdate::new(Thing::new(table.table_name(), id))
+            .upsert()
+            .content()
+            .with_record(record);
+        let query = crate::surreal_expr!("{} RETURN BEFORE", (update));
+        let before = self.execute(&query).await?.into_value();
+        let existed = match before {
+            ciborium::Value::Array(rows) => rows.iter().any(|row| row.is_map()),
+            other => other.is_map(),
+        };
+        Ok(if existed {
+            UpsertOutcome::Updated
+        } else {
+            UpsertOutcome::Inserted
+        })
+    }
+
+    /// Top-level fields from `INFO FOR TABLE`. Only `DEFINE FIELD`s show up,
+    /// so a schemaless table describes no columns; nested paths
+    /// (`address.city`, `tags[*]`) and `id` are skipped.
+    async fn describe_table_columns<E>(
+        &self,
+        table: &Table<Self, E>,
+    ) -> Result<Vec<Self::Column<Self::AnyType>>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let info = crate::surreal_expr!("INFO FOR TABLE {}", (Identifier::new(table.table_name())));
+        let info = extract_first_map(self.execute(&info).await?)?;
+        let Some((_, ciborium::Value::Map(fields))) = info
+            .into_iter()
+            .find(|(k, _)| k.as_text() == Some("fields"))
+        else {
+            return Err(error!(
+                "describe_table_columns: INFO result has no fields map",
+                table = table.table_name()
+            ));
+        };
+
+        Ok(fields
+            .into_iter()
+            .filter_map(|(k, _)| k.into_text().ok())
+            .filter(|name| name != "id" && !name.contains(['.', '[']))
+            .map(Column::new)
+            .collect())
+    }
+
     async fn get_table_some_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -313,6 +485,153 @@ impl TableSource for SurrealDB {
         })
     }
 
+    async fn get_table_count_by<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<(Self::Value, i64)>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let mut select = table.select();
+        select.order_by.clear();
+        let select = select
+            .without_fields()
+            .field(column)
+            .with_expression(crate::surreal_expr!("count()"), Some("count".to_string()))
+            .with_group_by(column);
+
+        let rows = self
+            .execute(&select.expr())
+            .await?
+            .into_value()
+            .into_array()
+            .map_err(|_| error!("count_by: expected array result"))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let ciborium::Value::Map(fields) = row else {
+                    return Err(error!("count_by: expected object row"));
+                };
+                let field = |name: &str| {
+                    fields
+                        .iter()
+                        .find(|(k, _)| k.as_text() == Some(name))
+                        .map(|(_, v)| v)
+                };
+                // SurrealDB leaves the field out of the row for a NONE group.
+                let group = field(column).unwrap_or(&ciborium::Value::Null);
+                let group = AnySurrealType::from_cbor(group)
+                    .ok_or_else(|| error!("count_by: unsupported group value", column = column))?;
+                let count = field("count")
+                    .and_then(|v| v.as_integer())
+                    .and_then(|i| i64::try_from(i).ok())
+                    .ok_or_else(|| error!("count_by: row has no integer count"))?;
+                Ok((group, count))
+            })
+            .collect()
+    }
+
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+    {
+        let mut select = table.select();
+        select.order_by.clear();
+        let query = select.as_count_distinct(Identifier::new(column));
+        let result = self.execute(&query.expr()).await?;
+        result.try_get::<i64>().ok_or_else(|| {
+            error!(
+                "count_distinct: expected i64",
+                result = format!("{}", result)
+            )
+        })
+    }
+
+    async fn get_table_distinct_values<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<Self::Value>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let query = table.select().as_distinct(Identifier::new(column));
+        self.execute(&query.expr())
+            .await?
+            .into_value()
+            .into_array()
+            .map_err(|_| error!("distinct_values: expected array result"))?
+            .iter()
+            .map(|value| {
+                AnySurrealType::from_cbor(value)
+                    .ok_or_else(|| error!("distinct_values: unsupported value", column = column))
+            })
+            .collect()
+    }
+
+    async fn get_table_report<E>(
+        &self,
+        table: &Table<Self, E>,
+        group: &[&str],
+        aggregates: &[(&str, AggExpr)],
+    ) -> Result<Vec<Record<Self::Value>>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let mut select = table.select();
+        select.order_by.clear();
+        let mut select = select.without_fields();
+        for column in group {
+            select = select.field(*column);
+        }
+        for (alias, agg) in aggregates {
+            let expr = match agg {
+                AggExpr::Count => crate::surreal_expr!("count()"),
+                AggExpr::Sum(c) => Fx::new("math::sum", vec![Identifier::new(c).expr()]).expr(),
+                AggExpr::Max(c) => Fx::new("math::max", vec![Identifier::new(c).expr()]).expr(),
+                AggExpr::Min(c) => Fx::new("math::min", vec![Identifier::new(c).expr()]).expr(),
+                AggExpr::Avg(c) => crate::primitives::avg(Identifier::new(c)),
+            };
+            select = select.with_expression(expr, Some(alias.to_string()));
+        }
+        for column in group {
+            select = select.with_group_by(*column);
+        }
+
+        self.execute(&select.expr())
+            .await?
+            .into_value()
+            .into_array()
+            .map_err(|_| error!("report: expected array result"))?
+            .into_iter()
+            .map(|row| {
+                let ciborium::Value::Map(fields) = row else {
+                    return Err(error!("report: expected object row"));
+                };
+                let field = |name: &str| {
+                    fields
+                        .iter()
+                        .find(|(k, _)| k.as_text() == Some(name))
+                        .map(|(_, v)| v)
+                };
+                let names = group
+                    .iter()
+                    .copied()
+                    .chain(aggregates.iter().map(|(a, _)| *a));
+                names
+                    .map(|name| {
+                        // SurrealDB leaves the field out of the row for a NONE group.
+                        let value = field(name).unwrap_or(&ciborium::Value::Null);
+                        let value = AnySurrealType::from_cbor(value)
+                            .ok_or_else(|| error!("report: unsupported value", field = name))?;
+                        Ok((name.to_string(), value))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     async fn get_table_sum<E>(
         &self,
         table: &Table<Self, E>,
@@ -355,6 +674,20 @@ impl TableSource for SurrealDB {
         self.execute(&min_query.expr()).await
     }
 
+    async fn get_table_avg<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &Self::Column<Self::AnyType>,
+    ) -> Result<Self::Value>
+    where
+        E: Entity<Self::Value>,
+    {
+        let mut select = table.select();
+        select.order_by.clear();
+        let avg_query = select.as_avg(column.clone());
+        self.execute(&avg_query.expr()).await
+    }
+
     async fn insert_table_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -443,6 +776,30 @@ impl TableSource for SurrealDB {
         Ok(())
     }
 
+    /// One `DELETE table WHERE id IN [..] RETURN BEFORE`; the returned rows
+    /// are the ones that existed.
+    async fn delete_table_values_by_ids<E>(
+        &self,
+        table: &Table<Self, E>,
+        ids: &[Self::Id],
+    ) -> Result<u64>
+    where
+        E: Entity<Self::Value>,
+    {
+        let ids = ids.iter().map(|id| id.expr()).collect();
+        let delete = SurrealDelete::table(table.table_name()).with_condition(crate::surreal_expr!(
+            "id IN [{}]",
+            (Expression::from_vec(ids, ", "))
+        ));
+        let base = delete.expr();
+        let query = Expression::new(format!("{} RETURN BEFORE", base.template), base.parameters);
+        let result = self.execute(&query).await?;
+        let ciborium::Value::Array(deleted) = result.into_value() else {
+            return Err(error!("delete_table_values_by_ids: expected array result"));
+        };
+        Ok(deleted.len() as u64)
+    }
+
     async fn insert_table_return_id_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -464,6 +821,53 @@ impl TableSource for SurrealDB {
         thing.ok_or_else(|| error!("insert_table_return_id_value: no id returned"))
     }
 
+    /// One `INSERT INTO table [{..}, {..}] RETURN id` for the whole batch;
+    /// SurrealDB returns the created rows in input order.
+    async fn insert_table_return_id_values<E>(
+        &self,
+        table: &Table<Self, E>,
+        records: &[Record<Self::Value>],
+    ) -> Result<Vec<Self::Id>>
+    where
+        E: Entity<Self::Value>,
+    {
+        let rows = records
+            .iter()
+            .map(|record| {
+                ciborium::Value::Map(
+                    record
+                        .iter()
+                        .map(|(k, v)| (ciborium::Value::Text(k.clone()), v.clone().into_value()))
+                        .collect(),
+                )
+            })
+            .collect();
+        let rows = AnySurrealType::from_cbor(&ciborium::Value::Array(rows))
+            .ok_or_else(|| error!("insert_table_return_id_values: rows are not valid CBOR"))?;
+        let query = crate::surreal_expr!(
+            "INSERT INTO {} {} RETURN id",
+            (Identifier::new(table.table_name())),
+            rows
+        );
+
+        let result = self.execute(&query).await?;
+        let ciborium::Value::Array(created) = result.into_value() else {
+            return Err(error!(
+                "insert_table_return_id_values: expected array result"
+            ));
+        };
+        created
+            .into_iter()
+            .map(|row| {
+                let ciborium::Value::Map(map) = row else {
+                    return Err(error!("insert_table_return_id_values: expected map row"));
+                };
+                let (thing, _rec) = parse_cbor_row(map, "id", table.table_name());
+                thing.ok_or_else(|| error!("insert_table_return_id_values: no id returned"))
+            })
+            .collect()
+    }
+
     fn related_in_condition<SourceE: Entity<Self::Value> + 'static>(
         &self,
         target_field: &str,
@@ -498,6 +902,10 @@ impl TableSource for SurrealDB {
         true
     }
 
+    fn supports_joins(&self) -> bool {
+        true
+    }
+
     fn traversal_path_expr(&self, hops: &[&str], column: &str) -> Option<Expression<Self::Value>> {
         // A SurrealQL idiom path: each segment escaped on its own, joined by
         // literal dots so SurrealDB traverses the record links
@@ -539,3 +947,675 @@ impl TableSource for SurrealDB {
         AssociatedExpression::new(deferred_expr, self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use surreal_client::SurrealMockBuilder;
+    use vantage_types::{EmptyEntity, entity};
+
+    fn named(name: &str) -> Record<AnySurrealType> {
+        let mut record = Record::new();
+        record.insert("name".to_string(), AnySurrealType::new(name.to_string()));
+        record
+    }
+
+    #[tokio::test]
+    async fn test_insert_many_sends_one_statement() {
+        // The mock panics on any request it wasn't given, so a per-row
+        // fallback would fail here.
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "INSERT INTO product $_arg1 RETURN id",
+                    {"_arg1": [{"name": "Scone"}, {"name": "Bagel"}, {"name": "Rye"}]}
+                ]),
+                json!([{"status": "OK", "result": [
+                    {"id": "product:k2"}, {"id": "product:k7"}, {"id": "product:k1"}
+                ]}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("product", SurrealDB::new(client));
+
+        let ids = table
+            .insert_many_values(vec![named("Scone"), named("Bagel"), named("Rye")])
+            .await
+            .unwrap();
+        let ids: Vec<String> = ids.iter().map(|t| t.to_string()).collect();
+        assert_eq!(ids, vec!["product:k2", "product:k7", "product:k1"]);
+
+        assert!(table.insert_many_values(vec![]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_ids_sends_one_statement() {
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "DELETE product WHERE id IN [product:k2, product:gone] RETURN BEFORE",
+                    {}
+                ]),
+                json!([{"status": "OK", "result": [{"id": "product:k2", "name": "Scone"}]}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("product", SurrealDB::new(client));
+
+        let ids = [Thing::new("product", "k2"), Thing::new("product", "gone")];
+        assert_eq!(table.delete_by_ids(&ids).await.unwrap(), 1);
+        assert_eq!(table.delete_by_ids(&[]).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_groups_per_value() {
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "SELECT status, count() AS count FROM order GROUP BY status",
+                    {}
+                ]),
+                json!([{"status": "OK", "result": [
+                    {"status": "paid", "count": 5}, {"status": "open", "count": 2}
+                ]}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("order", SurrealDB::new(client))
+            .with_column_of::<String>("status");
+
+        let counts: Vec<(Option<String>, i64)> = table
+            .count_by("status")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(status, count)| (status.try_get::<String>(), count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![(Some("paid".to_string()), 5), (Some("open".to_string()), 2)]
+        );
+
+        assert!(table.count_by("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_report_groups_and_aggregates() {
+        #[derive(Debug, Clone, PartialEq)]
+        #[entity(SurrealType)]
+        struct Revenue {
+            region: String,
+            status: String,
+            total: i64,
+        }
+
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "SELECT region, status, math::sum(amount) AS total FROM order GROUP BY region, status",
+                    {}
+                ]),
+                json!([{"status": "OK", "result": [
+                    {"region": "eu", "status": "paid", "total": 17},
+                    {"region": "us", "status": "paid", "total": 5}
+                ]}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("order", SurrealDB::new(client))
+            .with_column_of::<String>("region")
+            .with_column_of::<String>("status")
+            .with_column_of::<i64>("amount");
+
+        let report: Vec<Revenue> = table
+            .report(
+                vec!["region", "status"],
+                vec![("total", AggExpr::sum("amount"))],
+            )
+            .await
+            .unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(
+            report[0],
+            Revenue {
+                region: "eu".into(),
+                status: "paid".into(),
+                total: 17
+            }
+        );
+        assert_eq!(report[1].region, "us");
+    }
+
+    #[test]
+    fn test_table_alias_is_ignored() {
+        // SurrealQL has no table aliases: FROM and fields stay unqualified
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let table = Table::<SurrealDB, EmptyEntity>::new("order", db)
+            .with_column_of::<String>("status")
+            .with_table_alias("o");
+        assert_eq!(table.table_alias(), Some("o"));
+        assert_eq!(table.select().preview(), "SELECT status FROM order");
+    }
+
+    #[test]
+    fn test_role_omits_unreadable_columns() {
+        use vantage_table::table::{Permission, Role};
+
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let clerk = Role::new("clerk").allow(Permission::Read, "client.name");
+        let table = Table::<SurrealDB, EmptyEntity>::new("client", db)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_column_of::<i64>("salary")
+            .with_role(&clerk);
+        assert_eq!(table.select().preview(), "SELECT id, name FROM client");
+    }
+
+    #[test]
+    fn test_inner_join_projects_matching_record() {
+        use crate::surreal_expr;
+        use vantage_expressions::JoinKind;
+
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let table = Table::<SurrealDB, EmptyEntity>::new("product", db)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_join(
+                "bakery",
+                JoinKind::Inner,
+                surreal_expr!("id = $parent.bakery"),
+            )
+            .unwrap();
+        assert_eq!(
+            table.select().preview(),
+            "SELECT id, name, (SELECT * FROM bakery WHERE id = $parent.bakery)[0] AS bakery \
+             FROM product WHERE array::len(SELECT * FROM bakery WHERE id = $parent.bakery) > 0"
+        );
+    }
+
+    #[test]
+    fn test_aggregate_columns_select_correlated_subqueries() {
+        use vantage_table::aggregate::AggExpr;
+
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let clients = Table::<SurrealDB, EmptyEntity>::new("client", db)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_many("orders", "client", |db| {
+                Table::<SurrealDB, EmptyEntity>::new("order", db)
+                    .with_column_of::<Thing>("client")
+                    .with_column_of::<i64>("total")
+            })
+            .with_aggregate("total_orders", "orders", AggExpr::Count)
+            .with_aggregate("spent", "orders", AggExpr::sum("total"));
+        assert_eq!(
+            clients.select().preview(),
+            "SELECT id, name, \
+             (count(SELECT id FROM order WHERE client = $parent.id)) AS total_orders, \
+             (math::sum(SELECT VALUE total FROM order WHERE client = $parent.id)) AS spent \
+             FROM client"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_with_unknown_name_fails_the_read() {
+        use vantage_dataset::prelude::ReadableValueSet;
+        use vantage_table::aggregate::AggExpr;
+
+        // The mock panics on any request, so both reads must fail up front.
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let clients = Table::<SurrealDB, EmptyEntity>::new("client", db)
+            .with_id_column("id")
+            .with_many("orders", "client", |db| {
+                Table::<SurrealDB, EmptyEntity>::new("order", db)
+                    .with_column_of::<Thing>("client")
+                    .with_column_of::<i64>("total")
+            });
+
+        let bad_relation = clients
+            .clone()
+            .with_aggregate("n", "invoices", AggExpr::Count);
+        let err = bad_relation.list_values().await.unwrap_err();
+        assert!(err.to_string().contains("Reference not found"), "{err}");
+        assert_eq!(
+            bad_relation.select().preview(),
+            "SELECT id, (NULL) AS n FROM client"
+        );
+
+        let bad_column = clients.with_aggregate("spent", "orders", AggExpr::sum("totl"));
+        let err = bad_column
+            .get_value(Thing::new("client", "1"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not a column"), "{err}");
+    }
+
+    fn json_filter_table() -> Table<SurrealDB, EmptyEntity> {
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        Table::<SurrealDB, EmptyEntity>::new("client", db)
+            .with_column_of::<String>("name")
+            .with_column_of::<i64>("age")
+    }
+
+    #[test]
+    fn test_condition_from_json() {
+        let table = json_filter_table()
+            .with_condition_from_json(json!({"field": "age", "op": ">", "value": 18}))
+            .unwrap();
+        assert_eq!(
+            table.select().preview(),
+            "SELECT name, age FROM client WHERE age > 18"
+        );
+
+        let nested = json!({"or": [
+            {"field": "name", "op": "=", "value": "Marty"},
+            {"and": [
+                {"field": "age", "op": ">=", "value": 18},
+                {"field": "age", "op": "<", "value": 65}
+            ]}
+        ]});
+        let table = json_filter_table()
+            .with_condition_from_json(nested)
+            .unwrap();
+        assert_eq!(
+            table.select().preview(),
+            "SELECT name, age FROM client WHERE (name = \"Marty\" OR (age >= 18 AND age < 65))"
+        );
+    }
+
+    #[test]
+    fn test_condition_from_json_errors() {
+        let mut table = json_filter_table();
+        let err = table
+            .add_condition_from_json(json!({"field": "age", "op": "LIKE", "value": 1}))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported JSON filter operator"),
+            "{err}"
+        );
+
+        let err = table
+            .add_condition_from_json(json!({"and": [
+                {"field": "age", "op": ">", "value": 1},
+                {"field": "salary", "op": ">", "value": 1}
+            ]}))
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown field"), "{err}");
+        assert_eq!(table.conditions().count(), 0);
+    }
+
+    #[test]
+    fn test_condition_template_on_two_tables() {
+        use vantage_table::table::impls::condition_template::ConditionTemplate;
+
+        let recently_active = ConditionTemplate::new("{last_seen} > {} AND {status} != {}");
+        let args = [
+            AnySurrealType::new("2024-06-01".to_string()),
+            AnySurrealType::new("blocked".to_string()),
+        ];
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+
+        let clients = Table::<SurrealDB, EmptyEntity>::new("client", db.clone())
+            .with_column_of::<String>("name")
+            .with_column_of::<String>("last_seen")
+            .with_column_of::<String>("status")
+            .with_template(&recently_active, &args)
+            .unwrap();
+        assert_eq!(
+            clients.select().preview(),
+            "SELECT name, last_seen, status FROM client \
+             WHERE (last_seen > \"2024-06-01\" AND status != \"blocked\")"
+        );
+
+        let devices = Table::<SurrealDB, EmptyEntity>::new("device", db)
+            .with_column_of::<String>("serial")
+            .with_column_of::<String>("status")
+            .with_column_of::<String>("last_seen")
+            .with_condition(crate::surreal_expr!("paired = true"))
+            .with_template(&recently_active, &args)
+            .unwrap();
+        assert_eq!(
+            devices.select().preview(),
+            "SELECT serial, status, last_seen FROM device \
+             WHERE paired = true AND (last_seen > \"2024-06-01\" AND status != \"blocked\")"
+        );
+
+        // An OR template can't loosen the table's own conditions
+        let either = ConditionTemplate::new("{status} = {} OR {serial} = {}");
+        let devices = Table::<SurrealDB, EmptyEntity>::new(
+            "device",
+            SurrealDB::new(SurrealMockBuilder::new().build()),
+        )
+        .with_column_of::<String>("serial")
+        .with_column_of::<String>("status")
+        .with_condition(crate::surreal_expr!("owner = 1"))
+        .with_template(
+            &either,
+            &[
+                AnySurrealType::new("lost".to_string()),
+                AnySurrealType::new("A1".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            devices.select().preview(),
+            "SELECT serial, status FROM device \
+             WHERE owner = 1 AND (status = \"lost\" OR serial = \"A1\")"
+        );
+    }
+
+    #[test]
+    fn test_condition_template_errors() {
+        use vantage_table::table::impls::condition_template::ConditionTemplate;
+
+        let mut table = json_filter_table();
+        let err = table
+            .apply_template(
+                &ConditionTemplate::new("{age} BETWEEN {} AND {}"),
+                &[AnySurrealType::new(18i64)],
+            )
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Wrong number of arguments"),
+            "{err}"
+        );
+
+        let err = table
+            .apply_template(
+                &ConditionTemplate::new("{last_seen} > {}"),
+                &[AnySurrealType::new("2024-06-01".to_string())],
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown field"), "{err}");
+        assert_eq!(table.conditions().count(), 0);
+    }
+
+    #[test]
+    fn test_toggle_condition() {
+        let db = SurrealDB::new(SurrealMockBuilder::new().build());
+        let mut table =
+            Table::<SurrealDB, EmptyEntity>::new("order", db).with_column_of::<String>("status");
+
+        assert!(table.toggle_condition("paid", crate::surreal_expr!("status = 'paid'")));
+        assert_eq!(
+            table.select().preview(),
+            "SELECT status FROM order WHERE status = 'paid'"
+        );
+
+        assert!(!table.toggle_condition("paid", crate::surreal_expr!("status = 'paid'")));
+        assert_eq!(table.select().preview(), "SELECT status FROM order");
+    }
+
+    #[tokio::test]
+    async fn test_distinct_values_returns_deduplicated_list() {
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "RETURN array::distinct(SELECT VALUE status FROM order WHERE total > $_arg1)",
+                    {"_arg1": 10}
+                ]),
+                json!([{"status": "OK", "result": ["paid", "open"]}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("order", SurrealDB::new(client))
+            .with_column_of::<String>("status")
+            .with_condition(crate::surreal_expr!("total > {}", 10));
+
+        let statuses: Vec<Option<String>> = table
+            .distinct_values("status")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|status| status.try_get::<String>())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![Some("paid".to_string()), Some("open".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_distinct_honors_conditions() {
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "RETURN array::len(array::distinct(SELECT VALUE client FROM order WHERE total > $_arg1))",
+                    {"_arg1": 10}
+                ]),
+                json!([{"status": "OK", "result": 3}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, EmptyEntity>::new("order", SurrealDB::new(client))
+            .with_column_of::<String>("client")
+            .with_condition(crate::surreal_expr!("total > {}", 10));
+
+        assert_eq!(table.count_distinct("client").await.unwrap(), 3);
+        assert!(table.count_distinct("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_id_targets_record_id() {
+        #[derive(Debug, Clone)]
+        #[entity(SurrealType)]
+        struct Client {
+            name: String,
+        }
+
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!([
+                    "SELECT name FROM type::thing($_arg1, $_arg2)",
+                    {"_arg1": "client", "_arg2": "marty"}
+                ]),
+                json!([{"status": "OK", "result": [{"id": "client:marty", "name": "Marty"}]}]),
+            )
+            .with_exact_response(
+                "query",
+                json!([
+                    "SELECT name FROM type::thing($_arg1, $_arg2)",
+                    {"_arg1": "client", "_arg2": "biff"}
+                ]),
+                json!([{"status": "OK", "result": []}]),
+            )
+            .build();
+        let table = Table::<SurrealDB, Client>::new("client", SurrealDB::new(client))
+            .with_column_of::<String>("name");
+
+        let marty = table.find_by_id("marty").await.unwrap().unwrap();
+        assert_eq!(marty.name, "Marty");
+        assert!(table.find_by_id("biff").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_columns_from_info() {
+        use vantage_table::column::flags::ColumnFlag;
+        use vantage_table::traits::column_like::ColumnLike;
+
+        let client = SurrealMockBuilder::new()
+            .with_exact_response(
+                "query",
+                json!(["INFO FOR TABLE client", {}]),
+                json!([{"status": "OK", "result": {
+                    "events": {},
+                    "fields": {
+                        "address": "DEFINE FIELD address ON client TYPE object",
+                        "address.city": "DEFINE FIELD address.city ON client TYPE string",
+                        "email": "DEFINE FIELD email ON client TYPE string",
+                        "name": "DEFINE FIELD name ON client TYPE string"
+                    },
+                    "indexes": {},
+                    "lives": {},
+                    "tables": {}
+                }}]),
+            )
+            .build();
+        let mut table = Table::<SurrealDB, EmptyEntity>::new("client", SurrealDB::new(client))
+            .with_column(
+                Column::<String>::new("name")
+                    .with_alias("client_name")
+                    .with_flag(ColumnFlag::Hidden),
+            );
+
+        table.refresh_columns().await.unwrap();
+
+        let names: Vec<&String> = table.columns().keys().collect();
+        assert_eq!(names, vec!["name", "address", "email"]);
+        let name = &table.columns()["name"];
+        assert_eq!(ColumnLike::alias(name), Some("client_name"));
+        assert!(ColumnLike::flags(name).contains(&ColumnFlag::Hidden));
+    }
+
+    #[tokio::test]
+    async fn test_page_with_count_single_round_trip() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, Clone)]
+        #[entity(SurrealType)]
+        struct Client {
+            name: String,
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let client = SurrealMockBuilder::new()
+            .on_pattern(
+                r"SELECT name FROM client LIMIT \$_arg1 START \$_arg2; RETURN count\(SELECT VALUE id FROM client\)",
+                move |m| {
+                    assert_eq!(m.variables, json!({"_arg1": 2, "_arg2": 4}));
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    json!([
+                        {"status": "OK", "result": [
+                            {"id": "client:e", "name": "Emmett"},
+                            {"id": "client:f", "name": "Fred"}
+                        ]},
+                        {"status": "OK", "result": 6}
+                    ])
+                },
+            )
+            .build();
+        let table = Table::<SurrealDB, Client>::new("client", SurrealDB::new(client))
+            .with_column_of::<String>("name");
+
+        let (page, total) = table.page_with_count(4, 2).await.unwrap();
+        let names: Vec<&str> = page.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Emmett", "Fred"]);
+        assert_eq!(total, 6);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_reports_outcome() {
+        use surreal_client::mocks::QueryMatch;
+        use vantage_types::Columns;
+
+        #[derive(Debug, Clone, Columns)]
+        #[entity(SurrealType)]
+        struct Client {
+            id: String,
+            name: String,
+        }
+
+        let client = SurrealMockBuilder::new()
+            .on_pattern(
+                r"UPSERT client:c1 CONTENT \$_arg1 RETURN BEFORE",
+                |m: &QueryMatch| {
+                    // Only Biff's upsert finds an existing row
+                    let existing = if m.variables["_arg1"] == json!({"name": "Biff"}) {
+                        json!([{"id": "client:c1", "name": "Marty"}])
+                    } else {
+                        json!([])
+                    };
+                    json!([{"status": "OK", "result": existing}])
+                },
+            )
+            .build();
+        let table = Table::<SurrealDB, Client>::new("client", SurrealDB::new(client));
+
+        let marty = Client {
+            id: "c1".to_string(),
+            name: "Marty".to_string(),
+        };
+        assert_eq!(table.upsert(marty).await.unwrap(), UpsertOutcome::Inserted);
+        let biff = Client {
+            id: "c1".to_string(),
+            name: "Biff".to_string(),
+        };
+        assert_eq!(table.upsert(biff).await.unwrap(), UpsertOutcome::Updated);
+    }
+
+    #[tokio::test]
+    async fn test_select_raw() {
+        let client = SurrealMockBuilder::new()
+            .on_pattern(
+                r"SELECT name, total \* 2 AS double FROM client WHERE vip = true",
+                |_| {
+                    json!([{"status": "OK", "result": [
+                        {"name": "Marty", "double": 20},
+                        {"name": "Doc", "double": 84}
+                    ]}])
+                },
+            )
+            .build();
+        let clients = Table::<SurrealDB, EmptyEntity>::new("client", SurrealDB::new(client))
+            .with_column_of::<String>("name")
+            .with_condition(crate::surreal_expr!("vip = true"));
+
+        let rows = clients
+            .select_raw(crate::surreal_expr!("name, total * 2 AS double"))
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1]["name"].try_get::<String>().as_deref(), Some("Doc"));
+        assert_eq!(rows[1]["double"].try_get::<i64>(), Some(84));
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_related_one_query() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, Clone)]
+        #[entity(SurrealType)]
+        struct Order {
+            total: i64,
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let client = SurrealMockBuilder::new()
+            .on_pattern(
+                r"SELECT client, total FROM order WHERE client IN \[\$_arg1, \$_arg2, \$_arg3\]",
+                move |_| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    json!([{"status": "OK", "result": [
+                        {"id": "order:o1", "client": "client:c1", "total": 10},
+                        {"id": "order:o2", "client": "client:c2", "total": 20},
+                        {"id": "order:o3", "client": "client:c1", "total": 30}
+                    ]}])
+                },
+            )
+            .build();
+        let clients = Table::<SurrealDB, EmptyEntity>::new("client", SurrealDB::new(client))
+            .with_many("orders", "client", |db| {
+                Table::<SurrealDB, Order>::new("order", db)
+                    .with_column_of::<Thing>("client")
+                    .with_column_of::<i64>("total")
+            });
+
+        let ids = ["client:c1", "client:c2", "client:c9"].map(String::from);
+        let orders = clients
+            .prefetch_related::<Order>("orders", &ids)
+            .await
+            .unwrap();
+        let totals = |id: &str| orders[id].iter().map(|o| o.total).collect::<Vec<_>>();
+        assert_eq!(totals("client:c1"), vec![10, 30]);
+        assert_eq!(totals("client:c2"), vec![20]);
+        assert!(totals("client:c9").is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -30,3 +30,41 @@ impl SurrealType for rust_decimal::Decimal {
 }
 
 // Only rust_decimal::Decimal is supported - no custom decimal types
+
+#[cfg(all(test, feature = "decimal"))]
+mod tests {
+    use ciborium::Value as CborValue;
+    use rust_decimal::Decimal;
+    use serde_json::json;
+    use surreal_client::{MockSurrealEngine, SurrealClient};
+    use vantage_expressions::{ExprDataSource, Expressive};
+
+    use crate::statements::SurrealInsert;
+    use crate::surrealdb::SurrealDB;
+
+    #[tokio::test]
+    async fn test_insert_binds_tagged_decimal() {
+        let precise = "9999999999999999.999999999999";
+        let engine = MockSurrealEngine::new()
+            .on_pattern("CREATE .*", |_| json!([{"status": "OK", "result": []}]));
+        let log = engine.cbor_log();
+        let db = SurrealDB::new(SurrealClient::new(Box::new(engine), None, None));
+
+        let amount: Decimal = precise.parse().unwrap();
+        let insert = SurrealInsert::new("price").with_field("amount", amount);
+        db.execute(&insert.expr()).await.unwrap();
+
+        let log = log.lock().unwrap();
+        let (_, CborValue::Array(params)) = &log[0] else {
+            panic!("unexpected request: {:?}", log[0]);
+        };
+        let CborValue::Map(vars) = &params[1] else {
+            panic!("query vars are not a map: {:?}", params[1]);
+        };
+        let tagged = CborValue::Tag(10, Box::new(CborValue::Text(precise.to_string())));
+        assert!(
+            vars.iter().any(|(_, value)| *value == tagged),
+            "no tagged decimal in {vars:?}"
+        );
+    }
+}
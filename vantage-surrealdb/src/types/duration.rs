@@ -0,0 +1,56 @@
+//! Duration type implementation for SurrealDB
+//!
+//! `std::time::Duration` travels as SurrealDB's compact duration,
+//! `Tag(14, [seconds, nanoseconds])`, so it arrives as a `duration` rather
+//! than a number or string.
+
+use crate::types::{SurrealType, SurrealTypeDurationMarker};
+use ciborium::Value as CborValue;
+use std::time::Duration;
+
+impl SurrealType for Duration {
+    type Target = SurrealTypeDurationMarker;
+
+    fn to_cbor(&self) -> CborValue {
+        CborValue::Tag(
+            14,
+            Box::new(CborValue::Array(vec![
+                CborValue::Integer(self.as_secs().into()),
+                CborValue::Integer(self.subsec_nanos().into()),
+            ])),
+        )
+    }
+
+    fn from_cbor(cbor: CborValue) -> Option<Self> {
+        let CborValue::Tag(14, inner) = cbor else {
+            return None;
+        };
+        let CborValue::Array(parts) = *inner else {
+            return None;
+        };
+        // Trailing zero parts may be omitted: `[]` is zero, `[secs]` whole seconds
+        let part = |i: usize| match parts.get(i) {
+            Some(CborValue::Integer(n)) => u64::try_from(*n).ok(),
+            None => Some(0),
+            _ => None,
+        };
+        Some(Duration::new(part(0)?, u32::try_from(part(1)?).ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_round_trip() {
+        let two_weeks = Duration::from_secs(14 * 24 * 3600);
+        let cbor = two_weeks.to_cbor();
+        assert!(matches!(cbor, CborValue::Tag(14, _)));
+        assert_eq!(Duration::from_cbor(cbor), Some(two_weeks));
+
+        let short = CborValue::Tag(14, Box::new(CborValue::Array(vec![5.into()])));
+        assert_eq!(Duration::from_cbor(short), Some(Duration::from_secs(5)));
+        assert_eq!(Duration::from_cbor(CborValue::Integer(5.into())), None);
+    }
+}
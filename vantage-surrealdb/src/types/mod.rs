@@ -144,6 +144,7 @@ impl SurrealTypeVariants {
 // Type implementations are organized in separate modules
 mod bool;
 mod decimal;
+mod duration;
 mod generic;
 mod numbers;
 mod string;
@@ -38,7 +38,21 @@ macro_rules! impl_from_for_any {
 }
 
 impl_from_for_any!(
-    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, bool, String
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    bool,
+    String,
+    std::time::Duration
 );
 
 impl From<&str> for AnySurrealType {
@@ -194,7 +208,21 @@ macro_rules! impl_expressive_for_scalar {
 }
 
 impl_expressive_for_scalar!(
-    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, bool, String
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    bool,
+    String,
+    std::time::Duration
 );
 
 impl Expressive<AnySurrealType> for &str {
@@ -1,6 +1,6 @@
 use ciborium::Value as CborValue;
 use serde_json::Value;
-use vantage_expressions::{Expressive, Order, Selectable};
+use vantage_expressions::{Expressive, Flatten, Order, Selectable};
 use vantage_surrealdb::operation::SurrealOperation;
 use vantage_surrealdb::{
     field::Field,
@@ -463,6 +463,43 @@ fn test_aggregation_methods() {
         count_query.preview(),
         "RETURN count(SELECT VALUE id FROM products WHERE active = true)"
     );
+
+    // Test as_max / as_min / as_avg
+    let orders = SurrealSelect::new()
+        .with_source("orders")
+        .with_condition(Field::new("status").eq("completed"));
+
+    assert_eq!(
+        orders.clone().as_max(Field::new("total")).preview(),
+        "RETURN math::max(SELECT VALUE total FROM orders WHERE status = \"completed\")"
+    );
+    assert_eq!(
+        orders.clone().as_min(Field::new("total")).preview(),
+        "RETURN math::min(SELECT VALUE total FROM orders WHERE status = \"completed\")"
+    );
+    assert_eq!(
+        orders.as_avg(Field::new("total")).preview(),
+        "RETURN math::mean(SELECT VALUE total FROM orders WHERE status = \"completed\")"
+    );
+}
+
+#[tokio::test]
+async fn test_table_get_avg() {
+    use vantage_table::table::Table;
+    use vantage_types::EmptyEntity;
+
+    let db = setup_test_db_with_data(serde_json::json!(194.6)).await;
+    let table = Table::<SurrealDB, EmptyEntity>::new("product", db)
+        .with_column_of::<i64>("price")
+        .with_condition(surreal_expr!("is_deleted = {}", false));
+
+    assert_eq!(
+        table.select().as_avg(Identifier::new("price")).preview(),
+        "RETURN math::mean(SELECT VALUE price FROM product WHERE is_deleted = false)"
+    );
+
+    let avg = table.get_avg(&table["price"]).await.unwrap();
+    assert_eq!(avg.try_get::<f64>().unwrap(), 194.6);
 }
 
 #[test]
@@ -508,3 +545,68 @@ async fn test_single_value() {
     assert_eq!(name1.try_get::<String>().unwrap(), "John Doe");
     assert_eq!(name2.try_get::<String>().unwrap(), "John Doe");
 }
+
+#[test]
+fn test_where_in_subquery() {
+    // Child: SELECT VALUE client FROM order WHERE total > 100
+    let big_spenders = SurrealSelect::new()
+        .with_source("order")
+        .with_condition(Field::new("total").gt(100))
+        .only("client");
+
+    // Parent: clients whose id appears in the child's result
+    let select = SurrealSelect::new()
+        .with_source("client")
+        .with_condition(Field::new("active").eq(true))
+        .with_where_in_subquery("id", big_spenders);
+
+    assert_eq!(
+        select.preview(),
+        "SELECT * FROM client WHERE active = true AND id IN (SELECT VALUE client FROM order WHERE total > 100)"
+    );
+
+    // Both the parent's and the child's parameters flatten into one list
+    let flat = vantage_expressions::ExpressionFlattener::new().flatten(&select.expr());
+    assert_eq!(flat.parameters.len(), 2);
+}
+
+#[tokio::test]
+async fn test_explain() {
+    use surreal_client::SurrealMockBuilder;
+
+    let plan = serde_json::json!([{"detail": {"table": "product"}, "operation": "Iterate Table"}]);
+    let client = SurrealMockBuilder::new()
+        .with_exact_response(
+            "query",
+            serde_json::json!(["SELECT * FROM product WHERE price > cost EXPLAIN", {}]),
+            serde_json::json!([{"status": "OK", "result": plan}]),
+        )
+        .with_exact_response(
+            "query",
+            serde_json::json!(["SELECT * FROM product WHERE price > cost EXPLAIN FULL", {}]),
+            serde_json::json!([{"status": "OK", "result": plan}]),
+        )
+        .build();
+    let db = SurrealDB::new(client);
+
+    let select = SurrealSelect::new()
+        .with_source("product")
+        .with_condition(Field::new("price").gt(Field::new("cost")));
+
+    // The explained form is inspectable without executing anything
+    assert_eq!(
+        select.explained(false).preview(),
+        "SELECT * FROM product WHERE price > cost EXPLAIN"
+    );
+    assert_eq!(
+        select.explained(true).preview(),
+        "SELECT * FROM product WHERE price > cost EXPLAIN FULL"
+    );
+
+    let result = select.explain(&db).await.unwrap();
+    assert_eq!(serde_json::Value::from(result), plan);
+    select.explain_full(&db).await.unwrap();
+
+    // `explain` borrows — the select is still usable afterwards
+    assert_eq!(select.preview(), "SELECT * FROM product WHERE price > cost");
+}
@@ -0,0 +1,42 @@
+//! Aggregate-related support data-types
+
+/// One aggregate of a grouped report, see [`Table::report`](crate::table::Table::report).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggExpr {
+    /// Number of records in the group
+    Count,
+    /// Sum of a column
+    Sum(String),
+    /// Largest value of a column
+    Max(String),
+    /// Smallest value of a column
+    Min(String),
+    /// Average of a column
+    Avg(String),
+}
+
+impl AggExpr {
+    pub fn sum(column: impl Into<String>) -> Self {
+        Self::Sum(column.into())
+    }
+
+    pub fn max(column: impl Into<String>) -> Self {
+        Self::Max(column.into())
+    }
+
+    pub fn min(column: impl Into<String>) -> Self {
+        Self::Min(column.into())
+    }
+
+    pub fn avg(column: impl Into<String>) -> Self {
+        Self::Avg(column.into())
+    }
+
+    /// The column this aggregate reads, `None` for [`AggExpr::Count`].
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            Self::Count => None,
+            Self::Sum(c) | Self::Max(c) | Self::Min(c) | Self::Avg(c) => Some(c),
+        }
+    }
+}
@@ -38,6 +38,7 @@ pub mod traits;
 pub mod mocks;
 
 pub mod active_entity_ext;
+pub mod aggregate;
 pub mod cbor_ext;
 pub mod conditions;
 pub mod pagination;
@@ -31,6 +31,12 @@ impl<T: ColumnType> MockColumn<T> {
         }
     }
 
+    /// Add a single flag to this column
+    pub fn with_flag(mut self, flag: ColumnFlag) -> Self {
+        self.flags.insert(flag);
+        self
+    }
+
     pub fn into_type<T2: ColumnType>(self) -> MockColumn<T2>
     where
         T: ColumnType,
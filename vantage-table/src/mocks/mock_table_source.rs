@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use rust_decimal::Decimal;
 use serde_json::Value;
 use std::sync::{Arc, Mutex};
@@ -18,12 +18,13 @@ use vantage_expressions::{
 };
 use vantage_types::{Entity, Record};
 
+use crate::aggregate::AggExpr;
 use crate::column::core::ColumnType;
 use crate::mocks::mock_column::MockColumn;
 use crate::mocks::mock_type_system::AnyMockType;
 use crate::traits::table_expr_source::TableExprSource;
 use crate::{
-    table::Table,
+    table::{Table, UpsertOutcome},
     traits::{column_like::ColumnLike, table_source::TableSource},
 };
 
@@ -164,6 +165,36 @@ impl TableSource for MockTableSource {
         Expression::new(template, parameters)
     }
 
+    fn eq_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        Ok(Expression::new(
+            format!("{} = {{}}", field),
+            vec![ExpressiveEnum::Scalar(value)],
+        ))
+    }
+
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        Ok(Expression::new(
+            format!("{} > {{}}", field),
+            vec![ExpressiveEnum::Scalar(value)],
+        ))
+    }
+
+    fn in_values_condition(
+        &self,
+        field: &str,
+        values: Vec<Self::Value>,
+    ) -> Result<Self::Condition> {
+        let placeholders = vec!["{}"; values.len()].join(", ");
+        Ok(Expression::new(
+            format!("{} IN ({})", field, placeholders),
+            values.into_iter().map(ExpressiveEnum::Scalar).collect(),
+        ))
+    }
+
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        Ok(Expression::new(field, vec![]))
+    }
+
     fn search_table_condition<E>(
         &self,
         _table: &Table<Self, E>,
@@ -185,7 +216,15 @@ impl TableSource for MockTableSource {
         Self: Sized,
     {
         let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
-        im_table.list_values().await
+        let values = im_table.list_values().await?;
+        Ok(match table.pagination() {
+            Some(page) => values
+                .into_iter()
+                .skip(page.skip() as usize)
+                .take(page.limit() as usize)
+                .collect(),
+            None => values,
+        })
     }
 
     async fn get_table_value<E>(
@@ -201,6 +240,55 @@ impl TableSource for MockTableSource {
         im_table.get_value(id).await
     }
 
+    async fn find_table_value_by_id<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+    ) -> Result<Option<Record<Self::Value>>>
+    where
+        E: Entity,
+        Self: Sized,
+    {
+        self.get_table_value(table, &id.to_string()).await
+    }
+
+    async fn upsert_table_value<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+        record: &Record<Self::Value>,
+    ) -> Result<UpsertOutcome>
+    where
+        E: Entity,
+        Self: Sized,
+    {
+        let id = id.to_string();
+        if self.get_table_value(table, &id).await?.is_some() {
+            self.replace_table_value(table, &id, record).await?;
+            Ok(UpsertOutcome::Updated)
+        } else {
+            self.insert_table_value(table, &id, record).await?;
+            Ok(UpsertOutcome::Inserted)
+        }
+    }
+
+    /// Every key seen across the stored rows, first-seen order, `id` excluded.
+    async fn describe_table_columns<E>(
+        &self,
+        table: &Table<Self, E>,
+    ) -> Result<Vec<Self::Column<Self::AnyType>>>
+    where
+        E: Entity,
+        Self: Sized,
+    {
+        let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
+        let mut names: IndexSet<String> = IndexSet::new();
+        for record in im_table.list_values().await?.values() {
+            names.extend(record.keys().filter(|k| *k != "id").cloned());
+        }
+        Ok(names.into_iter().map(MockColumn::new).collect())
+    }
+
     async fn get_table_some_value<E>(
         &self,
         table: &Table<Self, E>,
@@ -221,6 +309,94 @@ impl TableSource for MockTableSource {
         Ok(self.im_data_source.table_len(table.table_name()) as i64)
     }
 
+    async fn get_table_count_by<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<(Self::Value, i64)>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        // Groups in first-seen order; a missing field groups under null.
+        let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
+        let mut groups: IndexMap<Value, i64> = IndexMap::new();
+        for record in im_table.list_values().await?.into_values() {
+            let key = record.get(column).cloned().unwrap_or(Value::Null);
+            *groups.entry(key).or_default() += 1;
+        }
+        Ok(groups.into_iter().collect())
+    }
+
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        // Nulls and missing fields aren't counted, as in SQL's COUNT(DISTINCT)
+        let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
+        let mut values: IndexSet<Value> = IndexSet::new();
+        for record in im_table.list_values().await?.into_values() {
+            if let Some(value) = record.get(column).filter(|v| !v.is_null()) {
+                values.insert(value.clone());
+            }
+        }
+        Ok(values.len() as i64)
+    }
+
+    async fn get_table_distinct_values<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<Self::Value>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
+        let mut values: IndexSet<Value> = IndexSet::new();
+        for record in im_table.list_values().await?.into_values() {
+            values.insert(record.get(column).cloned().unwrap_or(Value::Null));
+        }
+        Ok(values.into_iter().collect())
+    }
+
+    async fn get_table_report<E>(
+        &self,
+        table: &Table<Self, E>,
+        group: &[&str],
+        aggregates: &[(&str, AggExpr)],
+    ) -> Result<Vec<Record<Self::Value>>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let im_table = ImTable::<E>::new(&self.im_data_source, table.table_name());
+        let mut groups: IndexMap<Vec<Value>, Vec<Record<Value>>> = IndexMap::new();
+        for record in im_table.list_values().await?.into_values() {
+            let key = group
+                .iter()
+                .map(|column| record.get(*column).cloned().unwrap_or(Value::Null))
+                .collect();
+            groups.entry(key).or_default().push(record);
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, records)| {
+                let mut row: Record<Value> = group
+                    .iter()
+                    .map(|column| column.to_string())
+                    .zip(key)
+                    .collect();
+                for (alias, agg) in aggregates {
+                    row.insert(alias.to_string(), mock_aggregate(agg, &records)?);
+                }
+                Ok(row)
+            })
+            .collect()
+    }
+
     async fn get_table_sum<E>(
         &self,
         _table: &Table<Self, E>,
@@ -573,6 +749,47 @@ impl TableExprSource for MockTableSource {
     }
 }
 
+/// Fold one aggregate over a group's records. Missing and null values are
+/// skipped, as in SQL; anything else that isn't a number is an error.
+fn mock_aggregate(agg: &AggExpr, records: &[Record<Value>]) -> Result<Value> {
+    let Some(column) = agg.column() else {
+        return Ok(Value::from(records.len()));
+    };
+    let mut numbers = Vec::new();
+    for value in records.iter().filter_map(|r| r.get(column)) {
+        match value {
+            Value::Null => {}
+            Value::Number(n) => numbers.push(n.clone()),
+            _ => {
+                return Err(vantage_core::error!(
+                    "MockTableSource can only aggregate numbers",
+                    column = column
+                ));
+            }
+        }
+    }
+    let as_f64 = |n: &serde_json::Number| n.as_f64().unwrap_or_default();
+    Ok(match agg {
+        AggExpr::Count => unreachable!(),
+        AggExpr::Sum(_) => match numbers.iter().map(|n| n.as_i64()).sum::<Option<i64>>() {
+            Some(total) => Value::from(total),
+            None => Value::from(numbers.iter().map(as_f64).sum::<f64>()),
+        },
+        AggExpr::Avg(_) if numbers.is_empty() => Value::Null,
+        AggExpr::Avg(_) => {
+            Value::from(numbers.iter().map(as_f64).sum::<f64>() / numbers.len() as f64)
+        }
+        AggExpr::Max(_) => numbers
+            .into_iter()
+            .max_by(|a, b| as_f64(a).total_cmp(&as_f64(b)))
+            .map_or(Value::Null, Value::Number),
+        AggExpr::Min(_) => numbers
+            .into_iter()
+            .min_by(|a, b| as_f64(a).total_cmp(&as_f64(b)))
+            .map_or(Value::Null, Value::Number),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
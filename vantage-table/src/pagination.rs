@@ -1,4 +1,6 @@
+use indexmap::IndexMap;
 use vantage_expressions::traits::selectable::Selectable;
+use vantage_types::Record;
 
 /// Pagination configuration for tables
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +91,17 @@ impl Default for Pagination {
     }
 }
 
+/// One page of a keyset (cursor) pagination, see
+/// [`Table::paginate_keyset`](crate::table::Table::paginate_keyset).
+#[derive(Debug, Clone)]
+pub struct KeysetPage<Id, V> {
+    /// Rows of this page, ordered by the key column.
+    pub records: IndexMap<Id, Record<V>>,
+    /// Key of the last row — pass it as `after` to fetch the next page.
+    /// `None` once a page comes back short, meaning there are no more rows.
+    pub next_cursor: Option<V>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,7 +4,10 @@
 
 // Core table types
 pub use crate::table::Table;
-pub use crate::table::{Hook, HookReturn, IdGenerator, Phase, Timestamps};
+pub use crate::table::{
+    Dependency, Hook, HookReturn, IdGenerator, MemoryRecordCache, Permission, Phase, RecordCache,
+    Role, Timestamps, TrackedEntity,
+};
 
 // Column functionality
 pub use crate::column::collection::ColumnCollectionExt;
@@ -20,11 +23,19 @@ pub use crate::traits::table_source::TableSource;
 pub use crate::sorting::{OrderBy, SortDirection};
 pub use crate::table::sorting::OrderByExt;
 
+// Cross-table selects
+pub use crate::table::join::TableJoin;
+pub use vantage_expressions::JoinKind;
+
+// Grouped reports
+pub use crate::aggregate::AggExpr;
+
 // Pagination functionality
-pub use crate::pagination::Pagination;
+pub use crate::pagination::{KeysetPage, Pagination};
 
 // Conditions
 pub use crate::conditions::ConditionHandle;
+pub use crate::table::impls::condition_template::ConditionTemplate;
 
 // CBOR value accessor helpers (for `Record<CborValue>` consumers crossing
 // the typed/erased boundary via `vantage_vista::Vista`).
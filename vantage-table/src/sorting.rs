@@ -111,4 +111,72 @@ mod tests {
         assert!(matches!(orders[0].1, SortDirection::Ascending));
         assert!(matches!(orders[1].1, SortDirection::Descending));
     }
+
+    #[test]
+    fn test_default_order_until_explicit_order() {
+        use vantage_expressions::Expressive;
+        use vantage_expressions::mocks::datasource::MockSelectableDataSource;
+
+        let ds = MockTableSource::new()
+            .with_select_source(MockSelectableDataSource::new(serde_json::json!([])));
+        let mut table = Table::<_, EmptyEntity>::new("users", ds)
+            .with_column_of::<String>("name")
+            .with_column_of::<i64>("age")
+            .with_default_order("name", SortDirection::Descending)
+            .unwrap();
+
+        // A bare select sorts by the default
+        assert_eq!(
+            table.select().expr().preview(),
+            "SELECT name, age FROM users ORDER BY name DESC"
+        );
+
+        // An explicit order replaces it, and removing that brings it back
+        let handle = table.temp_add_order(expr!("age").ascending());
+        assert_eq!(
+            table.select().expr().preview(),
+            "SELECT name, age FROM users ORDER BY age ASC"
+        );
+        table.temp_remove_order(handle).unwrap();
+        assert_eq!(table.effective_orders().count(), 1);
+
+        assert!(
+            Table::<_, EmptyEntity>::new("users", MockTableSource::new())
+                .with_default_order("missing", SortDirection::Ascending)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_typed_column_order() {
+        use serde::{Deserialize, Serialize};
+        use vantage_expressions::Expressive;
+        use vantage_expressions::mocks::datasource::MockSelectableDataSource;
+        use vantage_types::Columns;
+
+        #[derive(Clone, Serialize, Deserialize, Columns)]
+        struct Client {
+            name: String,
+            email: String,
+        }
+
+        let ds = MockTableSource::new()
+            .with_select_source(MockSelectableDataSource::new(serde_json::json!([])));
+        let table = Table::<_, Client>::new("client", ds)
+            .with_column_of::<String>("name")
+            .with_column_of::<String>("email")
+            .order_by(Client::email(), SortDirection::Descending)
+            .unwrap();
+        assert_eq!(
+            table.select().expr().preview(),
+            "SELECT name, email FROM client ORDER BY email DESC"
+        );
+
+        // The entity has the field but the table doesn't define the column
+        assert!(
+            Table::<_, Client>::new("client", MockTableSource::new())
+                .order_by(Client::name(), SortDirection::Ascending)
+                .is_err()
+        );
+    }
 }
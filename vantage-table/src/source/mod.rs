@@ -66,20 +66,21 @@ impl<S> From<String> for SelectSource<S> {
 /// directly there — this trait dispatches on the concrete source type, keeping
 /// the `Expressive` requirement confined to the query-capable backends.
 pub trait SelectSeed<S, V, C> {
-    /// Add this source to `select` as its FROM clause.
-    fn seed(&self, select: &mut S)
+    /// Add this source to `select` as its FROM clause, under `alias` if
+    /// given (a query source otherwise keeps its own alias).
+    fn seed(&self, select: &mut S, alias: Option<&str>)
     where
         S: Selectable<V, C>,
         V: From<String>;
 }
 
 impl<S, V, C> SelectSeed<S, V, C> for String {
-    fn seed(&self, select: &mut S)
+    fn seed(&self, select: &mut S, alias: Option<&str>)
     where
         S: Selectable<V, C>,
         V: From<String>,
     {
-        select.add_source(self.as_str(), None);
+        select.add_source(self.as_str(), alias.map(str::to_string));
     }
 }
 
@@ -88,23 +89,24 @@ where
     S: Expressive<V> + Clone,
     V: Clone,
 {
-    fn seed(&self, select: &mut S)
+    fn seed(&self, select: &mut S, alias: Option<&str>)
     where
         S: Selectable<V, C>,
         V: From<String>,
     {
         match self {
-            SelectSource::Name(name) => select.add_source(name.as_str(), None),
+            SelectSource::Name(name) => select.add_source(name.as_str(), alias.map(str::to_string)),
             SelectSource::Query {
                 select: query,
-                alias,
+                alias: query_alias,
             } => {
+                let alias = alias.unwrap_or(query_alias);
                 // Parenthesize the subquery ourselves: `expr()` renders a bare
                 // statement, and the SQL dialects' `add_source` does not wrap a
                 // nested source (only SurrealDB does). One wrap here is correct
                 // for every backend.
                 let subquery = expr_any!("({})", (query.expr()));
-                select.add_source(subquery, Some(alias.clone()));
+                select.add_source(subquery, Some(alias.to_string()));
             }
         }
     }
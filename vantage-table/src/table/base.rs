@@ -6,7 +6,8 @@ use vantage_expressions::Expression;
 use vantage_types::{EmptyEntity, Entity};
 
 use crate::{
-    pagination::Pagination, references::Reference, sorting::SortDirection, table::hooks::Hooks,
+    conditions::ConditionHandle, pagination::Pagination, references::Reference,
+    sorting::SortDirection, table::hooks::Hooks, table::soft_delete::SoftDelete,
     traits::table_source::TableSource, traits::table_source_spec::TableSourceSpec,
 };
 
@@ -21,6 +22,12 @@ use crate::{
 pub type ExpressionFn<T> =
     Arc<dyn Fn(&Table<T, EmptyEntity>) -> Expression<<T as TableSource>::Value> + Send + Sync>;
 
+/// Type alias for the checks [`Table::with_aggregate`] registers: each
+/// confirms an aggregate's relation and column exist, and is run by the read
+/// paths before the first query (see `Table::check_aggregates`).
+pub type AggregateCheckFn<T> =
+    Arc<dyn Fn(&Table<T, EmptyEntity>) -> vantage_core::Result<()> + Send + Sync>;
+
 /// Type alias for lazy-expression callbacks stored on Table.
 ///
 /// A lazy expression runs *after* the data source returns a record.
@@ -53,12 +60,23 @@ where
     pub(super) columns: IndexMap<String, T::Column<T::AnyType>>,
     pub(super) conditions: IndexMap<i64, T::Condition>,
     pub(super) next_condition_id: i64,
+    /// Temporary conditions registered under a string key; set via
+    /// [`Self::toggle_condition`].
+    pub(super) keyed_conditions: IndexMap<String, ConditionHandle>,
     pub(super) order_by: IndexMap<i64, (T::Condition, SortDirection)>,
     pub(super) next_order_id: i64,
+    /// Order applied when `order_by` is empty; set via
+    /// [`Self::with_default_order`].
+    pub(super) default_order: Option<(T::Condition, SortDirection)>,
     pub(super) refs: Option<IndexMap<String, Arc<dyn Reference>>>,
     pub(super) contained: Vec<crate::references::ContainedRelation<T>>,
     pub(super) expressions: IndexMap<String, ExpressionFn<T>>,
     pub(super) lazy_expressions: IndexMap<String, LazyExpressionFn<T>>,
+    /// Relation/column checks for aggregate columns, keyed by column name;
+    /// added via [`Self::with_aggregate`].
+    pub(super) aggregate_checks: IndexMap<String, AggregateCheckFn<T>>,
+    /// Set once `aggregate_checks` have all passed, so reads check only once.
+    pub(super) aggregates_checked: std::sync::OnceLock<()>,
     /// When `Some`, `select()` projects only these column names (plus the id
     /// column, always). `None` keeps the default "project every column"
     /// behavior. The set holds both plain column names and dotted implicit
@@ -89,6 +107,21 @@ where
     pub(super) invariants: IndexMap<String, T::Value>,
     /// Lifecycle hooks (see [`Hook`](super::Hook)). Registered via [`Self::with_hook`].
     pub(super) hooks: Hooks<T>,
+    /// Soft-delete flag column and its filter condition; set via
+    /// [`Self::with_soft_delete`].
+    pub(super) soft_delete: Option<SoftDelete<T::Condition>>,
+    /// Alias for the table in the FROM clause; set via
+    /// [`Self::with_table_alias`].
+    pub(super) table_alias: Option<String>,
+    /// Read-through cache for `find_by_id`; set via [`Self::with_cache`].
+    pub(super) cache: Option<Arc<dyn super::cache::RecordCache<T::Value>>>,
+    /// Column permissions enforced on select and write; set via
+    /// [`Self::with_role`].
+    pub(super) role: Option<Arc<super::role::Role>>,
+    /// Joined tables applied by `select()`; added via [`Self::join`].
+    pub(super) joins: Vec<super::join::TableJoin<T::Value>>,
+    /// Entity post-processing on read; added via [`Self::with_row_mapper`].
+    pub(super) row_mappers: Vec<super::row_mapper::RowMapperFn<T>>,
 }
 
 impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
@@ -101,12 +134,16 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
             columns: IndexMap::new(),
             conditions: IndexMap::new(),
             next_condition_id: 1,
+            keyed_conditions: IndexMap::new(),
             order_by: IndexMap::new(),
             next_order_id: 1,
+            default_order: None,
             refs: None,
             contained: Vec::new(),
             expressions: IndexMap::new(),
             lazy_expressions: IndexMap::new(),
+            aggregate_checks: IndexMap::new(),
+            aggregates_checked: std::sync::OnceLock::new(),
             active_columns: None,
             imported_columns: indexmap::IndexSet::new(),
             pagination: None,
@@ -116,6 +153,12 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
             id_text: false,
             invariants: IndexMap::new(),
             hooks: Hooks::default(),
+            soft_delete: None,
+            table_alias: None,
+            cache: None,
+            role: None,
+            joins: Vec::new(),
+            row_mappers: Vec::new(),
         }
     }
 
@@ -132,12 +175,16 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
             columns: self.columns,
             conditions: self.conditions,
             next_condition_id: self.next_condition_id,
+            keyed_conditions: self.keyed_conditions,
             order_by: self.order_by,
             next_order_id: self.next_order_id,
+            default_order: self.default_order,
             refs: self.refs,
             contained: self.contained,
             expressions: self.expressions,
             lazy_expressions: self.lazy_expressions,
+            aggregate_checks: self.aggregate_checks,
+            aggregates_checked: self.aggregates_checked,
             active_columns: self.active_columns,
             imported_columns: self.imported_columns,
             pagination: self.pagination,
@@ -147,9 +194,38 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
             id_text: self.id_text,
             invariants: self.invariants,
             hooks: self.hooks,
+            soft_delete: self.soft_delete,
+            table_alias: self.table_alias,
+            cache: self.cache,
+            role: self.role,
+            joins: self.joins,
+            row_mappers: self.row_mappers,
         }
     }
 
+    /// Copy of this table's schema with a fresh result set: columns,
+    /// references, expressions, hooks and source carry over; conditions,
+    /// ordering and pagination are dropped. Invariants go too — they are
+    /// registered alongside the narrowing conditions that define them — and so
+    /// does the record cache. A
+    /// soft-delete filter is schema, not a narrowing, so it is kept, and so
+    /// is the default order.
+    pub fn clone_empty(&self) -> Self {
+        let mut table = Self {
+            conditions: IndexMap::new(),
+            next_condition_id: 1,
+            keyed_conditions: IndexMap::new(),
+            order_by: IndexMap::new(),
+            next_order_id: 1,
+            pagination: None,
+            invariants: IndexMap::new(),
+            cache: None,
+            ..self.clone()
+        };
+        table.reapply_soft_delete();
+        table
+    }
+
     /// Borrow this table as its entity-erased form `Table<T, EmptyEntity>`.
     ///
     /// `E` appears in `Table` only as `PhantomData<E>` (a zero-sized field), so
@@ -162,6 +238,20 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         unsafe { &*(self as *const Table<T, E> as *const Table<T, EmptyEntity>) }
     }
 
+    /// Run the checks registered by [`Self::with_aggregate`], failing if an
+    /// aggregate names a missing relation or column. Called by the read paths
+    /// before querying; once every check has passed it is not repeated.
+    pub(crate) fn check_aggregates(&self) -> vantage_core::Result<()> {
+        if self.aggregates_checked.get().is_some() {
+            return Ok(());
+        }
+        for check in self.aggregate_checks.values() {
+            check(self.as_entity_erased())?;
+        }
+        let _ = self.aggregates_checked.set(());
+        Ok(())
+    }
+
     /// Apply lazy expressions to one returned record, in declaration order.
     /// Each callback borrows the record as built so far; the value it
     /// returns is inserted under the expression's name before the next
@@ -280,6 +370,19 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         self.source = T::Source::from_name(name.into());
     }
 
+    /// Alias the table in generated queries: `FROM user AS u`, with columns
+    /// projected as `u.name`. Needed for self-joins and qualified columns.
+    /// Backends that can't alias a table (SurrealDB) ignore it.
+    pub fn with_table_alias(mut self, alias: impl Into<String>) -> Self {
+        self.table_alias = Some(alias.into());
+        self
+    }
+
+    /// The table's FROM alias, if set (see [`Self::with_table_alias`]).
+    pub fn table_alias(&self) -> Option<&str> {
+        self.table_alias.as_deref()
+    }
+
     /// Get the underlying data source
     pub fn data_source(&self) -> &T {
         &self.data_source
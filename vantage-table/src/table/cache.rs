@@ -0,0 +1,181 @@
+//! Read-through record cache for [`Table::find_by_id`].
+//!
+//! [`Table::with_cache`] attaches a [`RecordCache`]. `find_by_id` answers from
+//! it when it can and stores what the backend returns on a miss; absent
+//! records are not cached. Any write through the table — insert, replace,
+//! patch, delete — clears the cache, since a write's id doesn't always map to
+//! the string `find_by_id` was called with (a SurrealDB record id vs. its key).
+//!
+//! The cache holds records as the source returned them; lazy expressions
+//! still run on every hit. Entries are keyed by id alone, so a table drops its
+//! cache whenever its conditions change — a narrowed copy (a soft-delete
+//! filter, a traversed reference, `with_condition`) must not answer from
+//! records its parent cached. Attach the cache once the table is narrowed.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use vantage_types::{Entity, Record};
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// Storage behind [`Table::with_cache`], keyed by the `find_by_id` string.
+pub trait RecordCache<V>: Send + Sync {
+    fn get(&self, id: &str) -> Option<Record<V>>;
+
+    fn put(&self, id: &str, record: Record<V>);
+
+    /// Drop every entry.
+    fn clear(&self);
+}
+
+/// Unbounded in-memory [`RecordCache`].
+pub struct MemoryRecordCache<V> {
+    records: Mutex<HashMap<String, Record<V>>>,
+}
+
+impl<V> MemoryRecordCache<V> {
+    pub fn new() -> Self {
+        Self {
+            records: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<V> Default for MemoryRecordCache<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone + Send> RecordCache<V> for MemoryRecordCache<V> {
+    fn get(&self, id: &str) -> Option<Record<V>> {
+        self.records.lock().unwrap().get(id).cloned()
+    }
+
+    fn put(&self, id: &str, record: Record<V>) {
+        self.records.lock().unwrap().insert(id.to_string(), record);
+    }
+
+    fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Serve [`Self::find_by_id`] through `cache`, see the [module docs](self).
+    pub fn with_cache(mut self, cache: Arc<dyn RecordCache<T::Value>>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn cache(&self) -> Option<&Arc<dyn RecordCache<T::Value>>> {
+        self.cache.as_ref()
+    }
+
+    /// Called whenever the condition set changes, see the [module docs](self).
+    pub(crate) fn drop_cache(&mut self) {
+        self.cache = None;
+    }
+
+    /// Called by every write path once the source has been written to.
+    pub(crate) fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Value, json};
+    use vantage_dataset::prelude::{ReadableValueSet, WritableValueSet};
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    struct Client {
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn test_find_by_id_reads_through_cache() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let cache = Arc::new(MemoryRecordCache::<Value>::new());
+        let table = Table::<_, Client>::new("client", source).with_cache(cache.clone());
+
+        assert_eq!(table.find_by_id("c1").await.unwrap().unwrap().name, "Marty");
+        assert_eq!(cache.len(), 1);
+        assert!(table.find_by_id("nope").await.unwrap().is_none());
+        assert_eq!(cache.len(), 1);
+
+        // Change the backend behind the table's back: the second lookup is
+        // answered from the cache
+        let raw = Table::<_, Client>::new("client", table.data_source().clone());
+        raw.patch_value("c1", &Record::from(json!({"name": "Biff"})))
+            .await
+            .unwrap();
+        assert_eq!(raw.get_value("c1").await.unwrap().unwrap()["name"], "Biff");
+        assert_eq!(table.find_by_id("c1").await.unwrap().unwrap().name, "Marty");
+    }
+
+    #[tokio::test]
+    async fn test_write_through_table_invalidates() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let cache = Arc::new(MemoryRecordCache::<Value>::new());
+        let table = Table::<_, Client>::new("client", source).with_cache(cache.clone());
+
+        table.find_by_id("c1").await.unwrap();
+        table
+            .patch_value("c1", &Record::from(json!({"name": "Doc"})))
+            .await
+            .unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(table.find_by_id("c1").await.unwrap().unwrap().name, "Doc");
+
+        table.delete("c1").await.unwrap();
+        assert!(table.find_by_id("c1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_narrowed_table_does_not_share_cache() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let cache = Arc::new(MemoryRecordCache::<Value>::new());
+        let table = Table::<_, Client>::new("client", source).with_cache(cache.clone());
+        table.find_by_id("c1").await.unwrap();
+        assert_eq!(cache.len(), 1);
+
+        let narrowed = table
+            .clone()
+            .with_condition(vantage_expressions::expr_any!("name = {}", "Biff"));
+        assert!(narrowed.cache().is_none());
+        assert!(table.cache().is_some());
+
+        // The narrowed table goes to the backend instead of the parent's entry
+        let raw = Table::<_, Client>::new("client", table.data_source().clone());
+        raw.patch_value("c1", &Record::from(json!({"name": "Biff"})))
+            .await
+            .unwrap();
+        assert_eq!(table.find_by_id("c1").await.unwrap().unwrap().name, "Marty");
+        assert_eq!(
+            narrowed.find_by_id("c1").await.unwrap().unwrap().name,
+            "Biff"
+        );
+    }
+}
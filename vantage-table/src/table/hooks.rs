@@ -10,7 +10,7 @@ use std::sync::Arc;
 
 use vantage_types::{EmptyEntity, Entity, Record};
 
-use crate::table::Table;
+use crate::table::{Table, ValidatorFn};
 use crate::traits::table_source::TableSource;
 
 /// Ordering band for before-write hooks. Hooks run in this order (and in
@@ -95,6 +95,9 @@ pub struct Hooks<T: TableSource> {
     pub(crate) after_insert: Vec<AfterFn<T>>,
     pub(crate) after_update: Vec<AfterFn<T>>,
     pub(crate) after_delete: Vec<AfterFn<T>>,
+    /// Row validators, run after the before-write hooks (see
+    /// [`Table::set_validator`]).
+    pub(crate) validators: Vec<ValidatorFn<T>>,
 }
 
 impl<T: TableSource> Default for Hooks<T> {
@@ -106,6 +109,7 @@ impl<T: TableSource> Default for Hooks<T> {
             after_insert: Vec::new(),
             after_update: Vec::new(),
             after_delete: Vec::new(),
+            validators: Vec::new(),
         }
     }
 }
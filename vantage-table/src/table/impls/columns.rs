@@ -122,6 +122,21 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         self.with_column(column)
     }
 
+    /// Pick up columns added to the backend since the table was built.
+    ///
+    /// Asks the source to describe the table and appends every column not
+    /// already defined. Existing columns are left untouched, so their flags
+    /// and aliases survive; columns dropped from the backend are kept too.
+    pub async fn refresh_columns(&mut self) -> vantage_core::Result<()> {
+        let described = self.data_source.describe_table_columns(self).await?;
+        for column in described {
+            if !self.columns.contains_key(column.name()) {
+                self.columns.insert(column.name().to_string(), column);
+            }
+        }
+        Ok(())
+    }
+
     /// Get all columns as type-erased columns (`Column<AnyType>`)
     pub fn columns(&self) -> &IndexMap<String, T::Column<T::AnyType>> {
         &self.columns
@@ -183,9 +198,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::column::flags::ColumnFlag;
     use crate::mocks::mock_column::MockColumn;
     use crate::prelude::MockTableSource;
     use serde_json::Value;
+    use serde_json::json;
     use vantage_types::EmptyEntity;
 
     #[test]
@@ -267,4 +284,34 @@ mod tests {
         let missing_column = table.columns().get("missing");
         assert!(missing_column.is_none());
     }
+
+    #[tokio::test]
+    async fn test_refresh_columns_keeps_existing_flags() {
+        let ds = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let mut table = Table::<MockTableSource, EmptyEntity>::new("client", ds)
+            .with_column(MockColumn::<String>::new("name").with_flag(ColumnFlag::Hidden));
+
+        table
+            .data_source()
+            .insert_table_value(
+                &table,
+                &"c2".to_string(),
+                &json!({"name": "Doc", "email": "doc@example.com"}).into(),
+            )
+            .await
+            .unwrap();
+        assert!(!table.columns().contains_key("email"));
+
+        table.refresh_columns().await.unwrap();
+        let names: Vec<&String> = table.columns().keys().collect();
+        assert_eq!(names, vec!["name", "email"]);
+        assert!(
+            table.columns()["name"]
+                .flags()
+                .contains(&ColumnFlag::Hidden)
+        );
+        assert!(table.columns()["email"].flags().is_empty());
+    }
 }
@@ -0,0 +1,191 @@
+//! Condition templates, for filters repeated across tables.
+//!
+//! A template is a condition with two kinds of placeholders: `{name}` is
+//! replaced by the table's column (or expression) called `name`, and `{}` by
+//! the next argument, bound as a parameter:
+//!
+//! ```rust,ignore
+//! let active_since = ConditionTemplate::new("{last_seen} > {} AND {is_blocked} = false");
+//! clients.apply_template(&active_since, &[cutoff.clone()])?;
+//! sessions.apply_template(&active_since, &[cutoff])?;
+//! ```
+//!
+//! Braces around anything that isn't a name (like an object literal) are
+//! kept as written. The instantiated condition is wrapped in parentheses, so
+//! a template using `OR` can't loosen the table's other conditions. Applying
+//! fails, and adds nothing, when the argument count doesn't match the `{}`
+//! placeholders or a named column isn't on the table.
+
+use vantage_core::{Result, error};
+use vantage_expressions::{Expression, Expressive, ExpressiveEnum};
+use vantage_types::Entity;
+
+use crate::{table::Table, traits::table_source::TableSource};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(String),
+    Arg,
+}
+
+/// A parameterized condition that isn't tied to a table, see the
+/// [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionTemplate {
+    template: String,
+    parts: Vec<Part>,
+}
+
+impl ConditionTemplate {
+    pub fn new(template: impl Into<String>) -> Self {
+        let template = template.into();
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template.as_str();
+
+        while let Some(open) = rest.find('{') {
+            text.push_str(&rest[..open]);
+            rest = &rest[open..];
+            let close = rest.find('}');
+            let name = close.map(|close| &rest[1..close]);
+            let part = match name {
+                Some("") => Part::Arg,
+                Some(name) if is_name(name) => Part::Field(name.to_string()),
+                _ => {
+                    text.push('{');
+                    rest = &rest[1..];
+                    continue;
+                }
+            };
+            if !text.is_empty() {
+                parts.push(Part::Text(std::mem::take(&mut text)));
+            }
+            parts.push(part);
+            rest = &rest[close.unwrap() + 1..];
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Self { template, parts }
+    }
+
+    /// The template as written.
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Number of `{}` arguments the template takes.
+    pub fn arity(&self) -> usize {
+        self.parts.iter().filter(|p| **p == Part::Arg).count()
+    }
+
+    /// Names of the columns the template refers to, in order.
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|p| match p {
+            Part::Field(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E>
+where
+    T::Condition: From<Expression<T::Value>>,
+    T::Column<T::AnyType>: Expressive<T::Value>,
+{
+    /// Instantiate `template` against this table's columns with `args` and
+    /// add it as a permanent condition.
+    pub fn apply_template(
+        &mut self,
+        template: &ConditionTemplate,
+        args: &[T::Value],
+    ) -> Result<()> {
+        let condition = self.template_expr(template, args)?;
+        self.add_condition(condition);
+        Ok(())
+    }
+
+    /// Apply a condition template using the builder pattern.
+    pub fn with_template(
+        mut self,
+        template: &ConditionTemplate,
+        args: &[T::Value],
+    ) -> Result<Self> {
+        self.apply_template(template, args)?;
+        Ok(self)
+    }
+
+    fn template_expr(
+        &self,
+        template: &ConditionTemplate,
+        args: &[T::Value],
+    ) -> Result<Expression<T::Value>> {
+        if args.len() != template.arity() {
+            return Err(error!(
+                "Wrong number of arguments for condition template",
+                template = template.template(),
+                expected = template.arity(),
+                got = args.len()
+            ));
+        }
+
+        let mut sql = String::from("(");
+        let mut parameters = Vec::new();
+        let mut args = args.iter();
+        for part in &template.parts {
+            match part {
+                Part::Text(text) => sql.push_str(text),
+                Part::Field(name) => {
+                    let column = self.get_column_expr(name).ok_or_else(|| {
+                        error!(
+                            "Unknown field in condition template",
+                            field = name.as_str(),
+                            table = self.table_name()
+                        )
+                    })?;
+                    sql.push_str("{}");
+                    parameters.push(ExpressiveEnum::Nested(column));
+                }
+                Part::Arg => {
+                    sql.push_str("{}");
+                    // Count checked above
+                    parameters.push(ExpressiveEnum::Scalar(args.next().unwrap().clone()));
+                }
+            }
+        }
+        sql.push(')');
+        Ok(Expression::new(sql, parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_placeholders() {
+        let template = ConditionTemplate::new("{last_seen} > {} AND {score} BETWEEN {} AND {}");
+        assert_eq!(template.arity(), 3);
+        assert_eq!(
+            template.fields().collect::<Vec<_>>(),
+            ["last_seen", "score"]
+        );
+
+        // Braces around anything else are plain text
+        let template = ConditionTemplate::new("{tags} CONTAINSANY {} OR meta = { a: 1 }");
+        assert_eq!(template.arity(), 1);
+        assert_eq!(template.fields().collect::<Vec<_>>(), ["tags"]);
+        assert_eq!(
+            template.parts.last(),
+            Some(&Part::Text(" OR meta = { a: 1 }".to_string()))
+        );
+    }
+}
@@ -9,6 +9,7 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         let id = -self.next_condition_id;
         self.next_condition_id += 1;
         self.conditions.insert(id, condition.into());
+        self.drop_cache();
     }
 
     /// Add a temporary condition that can be removed later
@@ -16,6 +17,7 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         let id = self.next_condition_id;
         self.next_condition_id += 1;
         self.conditions.insert(id, condition.into());
+        self.drop_cache();
         ConditionHandle::new(id)
     }
 
@@ -25,9 +27,25 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
             return Err(error!("Cannot remove permanent condition"));
         }
         self.conditions.shift_remove(&handle.0);
+        self.drop_cache();
         Ok(())
     }
 
+    /// Add `condition` under `key` if no condition is registered under it,
+    /// otherwise remove the one that is. Returns whether the key is now on,
+    /// so a filter chip can call this on every click.
+    pub fn toggle_condition(&mut self, key: &str, condition: impl Into<T::Condition>) -> bool {
+        if let Some(handle) = self.keyed_conditions.shift_remove(key) {
+            self.conditions.shift_remove(&handle.0);
+            self.drop_cache();
+            false
+        } else {
+            let handle = self.temp_add_condition(condition);
+            self.keyed_conditions.insert(key.to_string(), handle);
+            true
+        }
+    }
+
     /// Get all conditions
     pub fn conditions(&self) -> impl Iterator<Item = &T::Condition> {
         self.conditions.values()
@@ -109,4 +127,51 @@ mod tests {
         let result = table.temp_remove_condition(fake_handle);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_toggle_condition() {
+        let ds = MockTableSource::new();
+        let mut table = Table::<_, EmptyEntity>::new("test", ds);
+        table.add_condition(expr_any!("perm"));
+
+        assert!(table.toggle_condition("vip", expr_any!("vip")));
+        assert!(table.toggle_condition("open", expr_any!("open")));
+        assert_eq!(table.conditions().count(), 3);
+
+        // Toggling off removes exactly the keyed condition
+        assert!(!table.toggle_condition("vip", expr_any!("vip")));
+        let remaining: Vec<_> = table.conditions().map(|c| c.preview()).collect();
+        assert_eq!(remaining, vec!["perm", "open"]);
+
+        assert!(table.toggle_condition("vip", expr_any!("vip")));
+        assert_eq!(table.conditions().count(), 3);
+    }
+
+    #[test]
+    fn test_clone_empty_drops_conditions() {
+        use crate::pagination::Pagination;
+        use crate::sorting::OrderBy;
+
+        let ds = MockTableSource::new();
+        let mut table = Table::<_, EmptyEntity>::new("test", ds)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_condition(expr_any!("perm1"));
+        let _handle = table.temp_add_condition(expr_any!("temp1"));
+        table.add_order(OrderBy::ascending(expr_any!("name")));
+        table.set_pagination(Some(Pagination::new(2, 10)));
+        assert_eq!(table.conditions().count(), 2);
+
+        let empty = table.clone_empty();
+        assert_eq!(empty.conditions().count(), 0);
+        assert_eq!(empty.orders().count(), 0);
+        assert!(empty.pagination().is_none());
+        assert_eq!(
+            empty.columns().keys().collect::<Vec<_>>(),
+            table.columns().keys().collect::<Vec<_>>()
+        );
+
+        // The original is untouched
+        assert_eq!(table.conditions().count(), 2);
+    }
 }
@@ -0,0 +1,109 @@
+//! Conditions from JSON filters, as built by admin UIs.
+//!
+//! A filter is either a comparison or a combination of filters:
+//!
+//! ```json
+//! {"field": "age", "op": ">", "value": 18}
+//! {"and": [<filter>, <filter>, …]}
+//! {"or":  [<filter>, <filter>, …]}
+//! ```
+//!
+//! `op` is one of `=`, `!=`, `>`, `>=`, `<`, `<=`. `field` must be a column
+//! or expression of the table, and `value` is bound as a parameter (it can't
+//! be `null`). `and`/`or` nest freely and need at least one member. Anything
+//! else is an error, and nothing is added to the table.
+
+use serde_json::Value as JsonValue;
+use vantage_core::{Result, error};
+use vantage_expressions::{Expression, Expressive, ExpressiveEnum};
+use vantage_types::Entity;
+
+use crate::{table::Table, traits::table_source::TableSource};
+
+const OPERATORS: [&str; 6] = ["=", "!=", ">", ">=", "<", "<="];
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E>
+where
+    T::Value: From<JsonValue>,
+    T::Condition: From<Expression<T::Value>>,
+    T::Column<T::AnyType>: Expressive<T::Value>,
+{
+    /// Parse `filter` (see the [module docs](self)) and add it as a
+    /// permanent condition.
+    pub fn add_condition_from_json(&mut self, filter: JsonValue) -> Result<()> {
+        let condition = self.json_filter_expr(&filter)?;
+        self.add_condition(condition);
+        Ok(())
+    }
+
+    /// Add a JSON filter using the builder pattern.
+    pub fn with_condition_from_json(mut self, filter: JsonValue) -> Result<Self> {
+        self.add_condition_from_json(filter)?;
+        Ok(self)
+    }
+
+    fn json_filter_expr(&self, filter: &JsonValue) -> Result<Expression<T::Value>> {
+        let JsonValue::Object(map) = filter else {
+            return Err(error!("JSON filter must be an object"));
+        };
+
+        for (key, delimiter) in [("and", " AND "), ("or", " OR ")] {
+            let Some(members) = map.get(key) else {
+                continue;
+            };
+            if map.len() != 1 {
+                return Err(error!(
+                    "JSON filter combination takes no other keys",
+                    key = key
+                ));
+            }
+            let members = match members {
+                JsonValue::Array(members) if !members.is_empty() => members,
+                _ => {
+                    return Err(error!(
+                        "JSON filter combination needs a non-empty array",
+                        key = key
+                    ));
+                }
+            };
+            let parts = members
+                .iter()
+                .map(|member| self.json_filter_expr(member))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(Expression::new(
+                "({})",
+                vec![ExpressiveEnum::Nested(Expression::from_vec(
+                    parts, delimiter,
+                ))],
+            ));
+        }
+
+        let (Some(JsonValue::String(field)), Some(JsonValue::String(op)), Some(value), 3) =
+            (map.get("field"), map.get("op"), map.get("value"), map.len())
+        else {
+            return Err(error!(
+                "JSON filter must be {field, op, value} or {and|or: [...]}"
+            ));
+        };
+        if !OPERATORS.contains(&op.as_str()) {
+            return Err(error!("Unsupported JSON filter operator", op = op.as_str()));
+        }
+        if value.is_null() {
+            return Err(error!(
+                "JSON filter value cannot be null",
+                field = field.as_str()
+            ));
+        }
+        let column = self
+            .get_column_expr(field)
+            .ok_or_else(|| error!("Unknown field in JSON filter", field = field.as_str()))?;
+
+        Ok(Expression::new(
+            format!("{{}} {} {{}}", op),
+            vec![
+                ExpressiveEnum::Nested(column),
+                ExpressiveEnum::Scalar(T::Value::from(value.clone())),
+            ],
+        ))
+    }
+}
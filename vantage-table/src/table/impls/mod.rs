@@ -1,6 +1,9 @@
 pub mod columns;
+pub mod condition_template;
 pub mod conditions;
+pub mod export;
 pub mod expr;
+pub mod json_filter;
 pub mod pagination;
 pub mod refereces;
 pub mod selectable;
@@ -1,10 +1,249 @@
+use std::future::Future;
+
+use vantage_core::{Result, error};
+use vantage_dataset::prelude::ReadableDataSet;
 use vantage_types::Entity;
 
-use crate::{pagination::Pagination, table::Table, traits::table_source::TableSource};
+use crate::{
+    pagination::{KeysetPage, Pagination},
+    sorting::OrderBy,
+    table::Table,
+    traits::table_source::TableSource,
+};
 
 impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
     /// Set pagination configuration
     pub fn set_pagination(&mut self, pagination: Option<Pagination>) {
         self.pagination = pagination;
     }
+
+    /// A copy of this table narrowed to one keyset page: `key_column > after`
+    /// (omitted for the first page), ordered by `key_column` ascending in
+    /// place of any existing order, limited to `page_size` rows.
+    ///
+    /// Use [`paginate_keyset`](Self::paginate_keyset) to fetch the page.
+    pub fn with_keyset(
+        &self,
+        key_column: &str,
+        after: Option<T::Value>,
+        page_size: usize,
+    ) -> Result<Self> {
+        if !self.columns().contains_key(key_column) {
+            return Err(error!("Unknown keyset column", column = key_column));
+        }
+        let mut table = self.clone();
+        if let Some(after) = after {
+            let condition = self.data_source().gt_value_condition(key_column, after)?;
+            table.add_condition(condition);
+        }
+        table.clear_orders();
+        table.add_order(OrderBy::ascending(
+            self.data_source().sort_field_expr(key_column)?,
+        ));
+        table.set_pagination(Some(Pagination::new(1, page_size as i64)));
+        Ok(table)
+    }
+
+    /// Read the whole result set `chunk_size` entities at a time, passing
+    /// each chunk to `f` as soon as it is fetched, so the table is never held
+    /// in memory at once. Stops at the first error `f` returns.
+    ///
+    /// Chunks are OFFSET pages of the table as configured (its own
+    /// pagination is replaced), so give it an order for a stable walk, and
+    /// don't let `f` add or remove rows that earlier chunks covered.
+    pub async fn for_each_chunk<F, Fut>(&self, chunk_size: usize, mut f: F) -> Result<()>
+    where
+        F: FnMut(Vec<E>) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        if chunk_size == 0 {
+            return Err(error!("Chunk size must be greater than zero"));
+        }
+        let mut chunk = self.clone();
+        for page in 1.. {
+            chunk.set_pagination(Some(Pagination::new(page, chunk_size as i64)));
+            let entities: Vec<E> = chunk.list().await?.into_values().collect();
+            let last = entities.len() < chunk_size;
+            if !entities.is_empty() {
+                f(entities).await?;
+            }
+            if last {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the `[skip, skip + limit)` window of entities along with the
+    /// total count of rows matching the table's conditions — what a paged UI
+    /// needs. Backends that support it (SurrealDB) answer both from a single
+    /// round-trip; the table's own pagination is ignored.
+    pub async fn page_with_count(&self, skip: i64, limit: i64) -> Result<(Vec<E>, i64)> {
+        self.check_aggregates()?;
+        let mut page = self.clone();
+        page.set_pagination(Some(Pagination::window(skip, limit)));
+        let (records, count) = self
+            .data_source()
+            .list_table_values_with_count(&page)
+            .await?;
+
+        let mut entities = Vec::with_capacity(records.len());
+        for (_, mut record) in records {
+            self.apply_lazy_expressions(&mut record).await?;
+            entities.push(self.record_to_entity(record)?);
+        }
+        Ok((entities, count))
+    }
+
+    /// Fetch the page of up to `page_size` rows whose `key_column` comes
+    /// after the cursor `after` (`None` for the first page).
+    ///
+    /// Unlike OFFSET pagination the database seeks straight to the cursor, so
+    /// deep pages cost the same as the first. `key_column` should be unique
+    /// (usually the id) or rows sharing a key across a page boundary are
+    /// skipped.
+    pub async fn paginate_keyset(
+        &self,
+        key_column: &str,
+        after: Option<T::Value>,
+        page_size: usize,
+    ) -> Result<KeysetPage<T::Id, T::Value>> {
+        self.check_aggregates()?;
+        let page = self.with_keyset(key_column, after, page_size)?;
+        let records = self.data_source().list_table_values(&page).await?;
+
+        let next_cursor = match records.last() {
+            Some((_, last)) if records.len() >= page_size => Some(
+                last.get(key_column)
+                    .cloned()
+                    .ok_or_else(|| error!("Keyset column missing from row", column = key_column))?,
+            ),
+            _ => None,
+        };
+        Ok(KeysetPage {
+            records,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde_json::json;
+    use vantage_expressions::mocks::datasource::MockSelectableDataSource;
+    use vantage_expressions::{Expression, Expressive};
+    use vantage_types::EmptyEntity;
+
+    async fn users() -> Table<MockTableSource, EmptyEntity> {
+        let source = MockTableSource::new()
+            .with_data(
+                "users",
+                vec![
+                    json!({"id": "a", "name": "Alice"}),
+                    json!({"id": "b", "name": "Bob"}),
+                ],
+            )
+            .await
+            .with_select_source(MockSelectableDataSource::new(json!([])));
+        Table::new("users", source)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+    }
+
+    #[tokio::test]
+    async fn test_first_page_has_no_cursor_bound() {
+        let page = users().await.with_keyset("id", None, 2).unwrap();
+
+        assert_eq!(page.conditions().count(), 0);
+        assert_eq!(
+            page.select().expr().preview(),
+            "SELECT id, name FROM users ORDER BY id ASC LIMIT 2 OFFSET 0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_page_carries_cursor_bound() {
+        let table = users()
+            .await
+            .with_condition(Expression::new("active", vec![]));
+        let page = table.with_keyset("id", Some(json!("b")), 2).unwrap();
+
+        let conditions: Vec<String> = page.conditions().map(|c| c.preview()).collect();
+        assert_eq!(conditions, vec!["active", r#"id > "b""#]);
+        let orders: Vec<String> = page.orders().map(|(e, _)| e.preview()).collect();
+        assert_eq!(orders, vec!["id"]);
+        // The original table is left untouched
+        assert_eq!(table.conditions().count(), 1);
+
+        assert!(table.with_keyset("missing", None, 2).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_keyset_next_cursor() {
+        let table = users().await;
+
+        let full = table.paginate_keyset("id", None, 2).await.unwrap();
+        assert_eq!(full.records.len(), 2);
+        assert_eq!(full.next_cursor, Some(json!("b")));
+
+        let short = table.paginate_keyset("id", None, 5).await.unwrap();
+        assert_eq!(short.next_cursor, None);
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    struct Row {
+        n: i64,
+    }
+
+    #[tokio::test]
+    async fn test_page_with_count() {
+        let rows = (0..25)
+            .map(|n| json!({"id": format!("r{n:02}"), "n": n}))
+            .collect();
+        let source = MockTableSource::new().with_data("rows", rows).await;
+        let mut table = Table::<_, Row>::new("rows", source);
+        table.set_pagination(Some(Pagination::new(1, 5)));
+
+        let (page, total) = table.page_with_count(20, 10).await.unwrap();
+        let ns: Vec<i64> = page.iter().map(|r| r.n).collect();
+        assert_eq!(ns, vec![20, 21, 22, 23, 24]);
+        assert_eq!(total, 25);
+    }
+
+    #[tokio::test]
+    async fn test_for_each_chunk() {
+        let rows = (0..250)
+            .map(|n| json!({"id": format!("r{n}"), "n": n}))
+            .collect();
+        let source = MockTableSource::new().with_data("rows", rows).await;
+        let table = Table::<_, Row>::new("rows", source);
+
+        let mut sizes = vec![];
+        let mut last = None;
+        table
+            .for_each_chunk(100, |chunk| {
+                sizes.push(chunk.len());
+                last = chunk.last().map(|r| r.n);
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(sizes, vec![100, 100, 50]);
+        assert_eq!(last, Some(249));
+
+        // The first failing chunk ends the walk
+        let mut calls = 0;
+        let result = table
+            .for_each_chunk(100, |_| {
+                calls += 1;
+                async { Err(error!("stop")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+
+        assert!(table.for_each_chunk(0, |_| async { Ok(()) }).await.is_err());
+    }
 }
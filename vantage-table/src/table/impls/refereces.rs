@@ -2,12 +2,13 @@
 
 use ciborium::Value as CborValue;
 use indexmap::IndexMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use vantage_core::{Result, error};
-use vantage_dataset::WritableValueSet;
+use vantage_dataset::{ReadableValueSet, WritableValueSet};
 use vantage_expressions::Expression;
-use vantage_types::{EmptyEntity, Entity, Record};
+use vantage_types::{EmptyEntity, Entity, InvariantValue, Record};
 
 use crate::{
     column::flags::ColumnFlag,
@@ -533,6 +534,8 @@ impl<T: TableSource + 'static, E: Entity<T::Value> + 'static> Table<T, E> {
                 expr_fn(typed)
             });
         self.expressions.insert(name.to_string(), wrapped);
+        // A plain expression replaces any aggregate previously declared here.
+        self.aggregate_checks.shift_remove(name);
         self
     }
 
@@ -623,3 +626,77 @@ impl<T: TableSource + 'static, E: Entity<T::Value> + 'static> Table<T, E> {
             .unwrap_or_default()
     }
 }
+
+impl<T: TableSource + 'static, E: Entity<T::Value> + 'static> Table<T, E>
+where
+    T::Value: InvariantValue + From<String>,
+{
+    /// Fetch the children of a `with_many` relation for several parents in a
+    /// single query, grouped by parent id — what a master-detail view needs
+    /// instead of one [`Self::get_ref_from_row`] per row.
+    ///
+    /// Every id in `parent_ids` gets an entry, empty when it has no
+    /// children. Ids are written as the parent's id field holds them; the
+    /// backend's [`TableSource::coerce_reference_value`] turns them into join
+    /// values, so SurrealDB takes `"client:c1"`. The filter comes from
+    /// [`TableSource::in_values_condition`].
+    pub async fn prefetch_related<E2: Entity<T::Value> + 'static>(
+        &self,
+        relation: &str,
+        parent_ids: &[String],
+    ) -> Result<HashMap<String, Vec<E2>>> {
+        let (reference, relation_str) = self.lookup_ref(relation)?;
+        if reference.cardinality() != vantage_vista::ReferenceKind::HasMany {
+            return Err(error!(
+                "prefetch_related needs a has-many relation",
+                relation = relation_str.as_str()
+            ));
+        }
+
+        let mut target = self.get_ref_target::<E2>(relation)?;
+        let source_id = self
+            .id_field()
+            .map(|c| c.name().to_string())
+            .unwrap_or_else(|| "id".to_string());
+        let target_id = target
+            .id_field()
+            .map(|c| c.name().to_string())
+            .unwrap_or_else(|| "id".to_string());
+        let (_, foreign_key) = reference.columns(&source_id, &target_id);
+
+        let parents: Vec<(String, T::Value)> = parent_ids
+            .iter()
+            .map(|id| {
+                let value = self.data_source().coerce_reference_value(id.clone().into());
+                (id.clone(), value)
+            })
+            .collect();
+        let mut grouped: HashMap<String, Vec<E2>> = parent_ids
+            .iter()
+            .map(|id| (id.clone(), Vec::new()))
+            .collect();
+        if parents.is_empty() {
+            return Ok(grouped);
+        }
+
+        let condition = self.data_source().in_values_condition(
+            &foreign_key,
+            parents.iter().map(|(_, value)| value.clone()).collect(),
+        )?;
+        target.add_condition(condition);
+
+        for (_, record) in target.list_values().await? {
+            let Some(link) = record.get(foreign_key.as_str()) else {
+                continue;
+            };
+            let link = self.data_source().coerce_reference_value(link.clone());
+            // Rows outside the requested parents are skipped
+            let Some((parent, _)) = parents.iter().find(|(_, value)| value.value_eq(&link)) else {
+                continue;
+            };
+            let child = target.record_to_entity(record)?;
+            grouped.get_mut(parent).unwrap().push(child);
+        }
+        Ok(grouped)
+    }
+}
@@ -1,9 +1,13 @@
+use std::fmt::Debug;
+
+use indexmap::IndexMap;
 use vantage_core::{Result, error};
 use vantage_expressions::traits::selectable::Selectable;
-use vantage_expressions::{Expression, Expressive, SelectableDataSource, expr_any};
-use vantage_types::{EmptyEntity, Entity};
+use vantage_expressions::{ExprDataSource, Expression, Expressive, SelectableDataSource, expr_any};
+use vantage_types::{EmptyEntity, Entity, TryFromRecord};
 
 use crate::{
+    aggregate::AggExpr,
     column::core::ColumnType,
     source::{SelectSeed, SelectSource},
     table::Table,
@@ -18,18 +22,18 @@ where
     T::Value: From<String>, // that's because table is specified as a string
     E: Entity<T::Value>,
 {
-    /// Create a bare select with source, conditions, ordering, and pagination —
-    /// but no fields. Used by `select_column` and aggregates to avoid evaluating
+    /// Create a bare select with source, conditions, ordering (or the default
+    /// order), and pagination — but no fields. Used by `select_column` and aggregates to avoid evaluating
     /// all expressions.
     pub fn select_empty(&self) -> T::Select {
         let mut select = self.data_source.select();
-        self.source.seed(&mut select);
+        self.source.seed(&mut select, self.table_alias.as_deref());
 
         for condition in self.conditions.values() {
             select.add_where_condition(condition.clone());
         }
 
-        for (expr, direction) in self.order_by.values() {
+        for (expr, direction) in self.effective_orders() {
             let order = match direction {
                 crate::sorting::SortDirection::Ascending => vantage_expressions::Order::Asc,
                 crate::sorting::SortDirection::Descending => vantage_expressions::Order::Desc,
@@ -62,6 +66,13 @@ where
             if !self.is_active(column.name()) {
                 continue;
             }
+            // Columns the table's role can't read are left out; the id
+            // column stays for the same reason as above.
+            if !self.can_read_column(column.name())
+                && self.id_field.as_deref() != Some(column.name())
+            {
+                continue;
+            }
             if let Some(expr_fn) = self.expressions.get(column.name()) {
                 let expr = expr_fn(self.as_entity_erased());
                 self.data_source.add_select_column(
@@ -69,6 +80,9 @@ where
                     expr_any!("({})", (expr)),
                     Some(column.name()),
                 );
+            } else if let Some(expr) = self.qualified_column(column.name()) {
+                self.data_source
+                    .add_select_column(&mut select, expr, column.alias());
             } else if let Some(alias) = column.alias() {
                 let expr = self.data_source.expr(column.name(), vec![]);
                 self.data_source
@@ -80,7 +94,10 @@ where
 
         // Add expressions that don't correspond to any column
         for (name, expr_fn) in &self.expressions {
-            if !self.columns.contains_key(name) && self.is_active(name) {
+            if !self.columns.contains_key(name)
+                && self.is_active(name)
+                && self.can_read_column(name)
+            {
                 let expr = expr_fn(self.as_entity_erased());
                 self.data_source.add_select_column(
                     &mut select,
@@ -90,8 +107,29 @@ where
             }
         }
 
+        for join in &self.joins {
+            self.data_source
+                .add_select_join(&mut select, join.kind, &join.table, join.on.clone());
+        }
+
         select
     }
+    /// Like [`select`](Self::select), but projecting only `columns` plus the
+    /// id column, so a view showing a few fields doesn't fetch the rest.
+    /// Replaces any active-column set; errors on a name that is neither a
+    /// column nor an expression of this table.
+    pub fn select_only(&self, columns: &[&str]) -> Result<T::Select> {
+        for &column in columns {
+            if !self.columns.contains_key(column) && !self.expressions.contains_key(column) {
+                return Err(error!("Unknown column for select_only", column = column));
+            }
+            self.check_read_permission(column)?;
+        }
+        let mut table = self.clone();
+        table.active_columns = Some(columns.iter().map(|c| c.to_string()).collect());
+        Ok(table.select())
+    }
+
     /// Get count of records in the table
     pub async fn get_count(&self) -> Result<i64> {
         self.data_source.get_table_count(self).await
@@ -99,19 +137,193 @@ where
 
     /// Get sum of a column in the table
     pub async fn get_sum(&self, column: &T::Column<T::AnyType>) -> Result<T::Value> {
+        self.check_read_permission(column.name())?;
         self.data_source.get_table_sum(self, column).await
     }
 
     /// Get max of a column in the table
     pub async fn get_max(&self, column: &T::Column<T::AnyType>) -> Result<T::Value> {
+        self.check_read_permission(column.name())?;
         self.data_source.get_table_max(self, column).await
     }
 
     /// Get min of a column in the table
     pub async fn get_min(&self, column: &T::Column<T::AnyType>) -> Result<T::Value> {
+        self.check_read_permission(column.name())?;
         self.data_source.get_table_min(self, column).await
     }
 
+    /// Get average of a column in the table
+    pub async fn get_avg(&self, column: &T::Column<T::AnyType>) -> Result<T::Value> {
+        self.check_read_permission(column.name())?;
+        self.data_source.get_table_avg(self, column).await
+    }
+
+    /// Count records per distinct value of `column` — e.g. orders per
+    /// status. Pairs keep the order the backend grouped them in. Errors if
+    /// `column` is not a column of this table.
+    pub async fn count_by(&self, column: &str) -> Result<Vec<(T::Value, i64)>> {
+        if !self.columns.contains_key(column) {
+            return Err(error!("Unknown column for count_by", column = column));
+        }
+        self.check_read_permission(column)?;
+        self.data_source.get_table_count_by(self, column).await
+    }
+
+    /// Number of distinct values of `column` under the table's conditions —
+    /// e.g. unique customers across orders. Errors if `column` is not a
+    /// column of this table.
+    pub async fn count_distinct(&self, column: &str) -> Result<i64> {
+        if !self.columns.contains_key(column) {
+            return Err(error!("Unknown column for count_distinct", column = column));
+        }
+        self.check_read_permission(column)?;
+        self.data_source
+            .get_table_count_distinct(self, column)
+            .await
+    }
+
+    /// Distinct values of `column` under the table's conditions, e.g. to
+    /// fill a filter dropdown. Errors if `column` is not a column of this
+    /// table.
+    pub async fn distinct_values(&self, column: &str) -> Result<Vec<T::Value>> {
+        if !self.columns.contains_key(column) {
+            return Err(error!(
+                "Unknown column for distinct_values",
+                column = column
+            ));
+        }
+        self.check_read_permission(column)?;
+        self.data_source
+            .get_table_distinct_values(self, column)
+            .await
+    }
+
+    /// Group by the `group` columns and deserialize each group, with the
+    /// aggregates stored under their aliases, into a report struct `R`:
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Revenue { region: String, status: String, total: f64 }
+    ///
+    /// let rows: Vec<Revenue> = orders
+    ///     .report(vec!["region", "status"], vec![("total", AggExpr::sum("amount"))])
+    ///     .await?;
+    /// ```
+    ///
+    /// Errors on an unknown column, or with the row index when a row
+    /// doesn't fit `R`.
+    pub async fn report<R>(
+        &self,
+        group: Vec<&str>,
+        aggregates: Vec<(&str, AggExpr)>,
+    ) -> Result<Vec<R>>
+    where
+        R: Entity<T::Value>,
+        <R as TryFromRecord<T::Value>>::Error: Debug,
+    {
+        let columns = group
+            .iter()
+            .copied()
+            .chain(aggregates.iter().filter_map(|(_, agg)| agg.column()));
+        for column in columns {
+            if !self.columns.contains_key(column) {
+                return Err(error!("Unknown column for report", column = column));
+            }
+            self.check_read_permission(column)?;
+        }
+
+        let rows = self
+            .data_source
+            .get_table_report(self, &group, &aggregates)
+            .await?;
+        rows.into_iter()
+            .enumerate()
+            .map(|(row, record)| {
+                R::from_record(record).map_err(|e| {
+                    error!(
+                        "Report row does not match the report struct",
+                        row = row,
+                        error = format!("{:?}", e)
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Declare a read-only column `name` holding `aggregate` over the rows
+    /// `relation` points at, e.g. a client's order count:
+    ///
+    /// ```rust,ignore
+    /// let clients = Table::new("client", db)
+    ///     .with_many("orders", "client_id", Order::table)
+    ///     .with_aggregate("total_orders", "orders", AggExpr::Count)
+    ///     .with_aggregate("spent", "orders", AggExpr::sum("total"));
+    /// ```
+    ///
+    /// The column is selected as a correlated subquery (see
+    /// [`get_subquery_erased`](Self::get_subquery_erased)), so it comes back
+    /// with every row and is flagged calculated like any
+    /// [expression](Self::with_expression) column.
+    ///
+    /// `relation` and the aggregated column are resolved when the table is
+    /// first read: a name that doesn't exist makes that read (`list`, `get`,
+    /// `stream`, …) return an error. A select built directly with
+    /// [`select`](Self::select) renders such a column as `NULL`.
+    pub fn with_aggregate(mut self, name: &str, relation: &str, aggregate: AggExpr) -> Self
+    where
+        T: 'static,
+        E: 'static,
+        T::Column<T::AnyType>: Expressive<T::Value>,
+    {
+        if !self.columns.contains_key(name) {
+            let column = self.data_source.create_column::<T::AnyType>(name);
+            self.add_column(column);
+        }
+        let check: crate::table::base::AggregateCheckFn<T> = {
+            let relation = relation.to_string();
+            let aggregate = aggregate.clone();
+            std::sync::Arc::new(move |t| {
+                let related = t.get_subquery_erased(&relation)?;
+                match aggregate.column() {
+                    Some(column)
+                        if !related.columns.contains_key(column)
+                            && !related.expressions.contains_key(column) =>
+                    {
+                        Err(error!(
+                            "Aggregated column is not a column of the related table",
+                            column = column,
+                            relation = relation.as_str(),
+                            table = related.table_name()
+                        ))
+                    }
+                    _ => Ok(()),
+                }
+            })
+        };
+        let relation = relation.to_string();
+        self = self.with_expression(name, move |t| {
+            let Ok(related) = t.get_subquery_erased(&relation) else {
+                return t.data_source().expr("NULL", vec![]);
+            };
+            // Unresolvable names are reported by the read path's
+            // `check_aggregates`; the select itself just gets a NULL column.
+            let column = |name: &str| related.get_column_expr(name);
+            let select = related.select_empty();
+            match &aggregate {
+                AggExpr::Count => Some(select.as_count()),
+                AggExpr::Sum(c) => column(c).map(|c| select.as_sum(c)),
+                AggExpr::Max(c) => column(c).map(|c| select.as_max(c)),
+                AggExpr::Min(c) => column(c).map(|c| select.as_min(c)),
+                AggExpr::Avg(c) => column(c).map(|c| select.as_avg(c)),
+            }
+            .unwrap_or_else(|| t.data_source().expr("NULL", vec![]))
+        });
+        self.aggregate_checks.insert(name.to_string(), check);
+        self.aggregates_checked = std::sync::OnceLock::new();
+        self
+    }
+
     /// Create a count query expression (does not execute).
     /// The result is wrapped in parentheses so it's safe to nest as a subquery.
     pub fn get_count_query(&self) -> Expression<T::Value> {
@@ -167,6 +379,56 @@ where
         select.expr()
     }
 
+    /// Run `projection` against this table's source and conditions and
+    /// return the rows as untyped maps — an escape hatch for ad-hoc queries
+    /// that have no entity. The query is built like
+    /// [`select_expression`](Self::select_expression), so the table's
+    /// ordering is dropped; pagination still applies.
+    ///
+    /// ```rust,ignore
+    /// let rows = clients.select_raw(sqlite_expr!("name, length(name) AS len"))?;
+    /// ```
+    pub async fn select_raw(
+        &self,
+        projection: Expression<T::Value>,
+    ) -> Result<Vec<IndexMap<String, T::Value>>>
+    where
+        T: ExprDataSource<T::Value>,
+        T::Select: Expressive<T::Value>,
+        T::Value: Into<ciborium::Value> + From<ciborium::Value>,
+    {
+        self.check_read_all_permission()?;
+        let result: ciborium::Value = self
+            .data_source
+            .execute(&self.select_expression(projection))
+            .await?
+            .into();
+        let ciborium::Value::Array(rows) = result else {
+            return Err(error!("select_raw expected rows from the data source"));
+        };
+        rows.into_iter()
+            .map(|row| {
+                let ciborium::Value::Map(fields) = row else {
+                    return Err(error!("select_raw expected each row to be a map"));
+                };
+                fields
+                    .into_iter()
+                    .map(|(key, value)| match key {
+                        ciborium::Value::Text(key) => Ok((key, T::Value::from(value))),
+                        _ => Err(error!("select_raw expected text column names")),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// `alias.column` when the table has an alias and the backend can
+    /// qualify columns with it.
+    fn qualified_column(&self, name: &str) -> Option<Expression<T::Value>> {
+        let alias = self.table_alias.as_deref()?;
+        self.data_source.qualified_column_expr(alias, name)
+    }
+
     /// Whether `name` is projected by [`select`](Self::select). With no active
     /// set every column is active; otherwise only the set's members are, plus
     /// the id column (always projected — consumers key rows by it).
@@ -499,6 +761,27 @@ mod tests {
         Table::<_, vantage_types::EmptyEntity>::new("users", source)
     }
 
+    #[tokio::test]
+    async fn test_select_only_projects_requested_columns_and_id() {
+        let source =
+            MockTableSource::new().with_select_source(MockSelectableDataSource::new(json!([])));
+        let table = Table::<_, vantage_types::EmptyEntity>::new("users", source)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_column_of::<String>("email")
+            .with_column_of::<i64>("age");
+
+        let select = table.select_only(&["age", "name"]).unwrap();
+        assert_eq!(select.expr().preview(), "SELECT id, name, age FROM users");
+        // The table itself still selects everything
+        assert_eq!(
+            table.select().expr().preview(),
+            "SELECT id, name, email, age FROM users"
+        );
+
+        assert!(table.select_only(&["name", "nickname"]).is_err());
+    }
+
     #[tokio::test]
     async fn test_count_unwraps_single_element_array() {
         // SQL/Surreal count queries commonly return `[{"count": N}]`.
@@ -527,4 +810,112 @@ mod tests {
         let query = table.data_source().expr("SELECT COUNT(*)", vec![]);
         let _result = table.data_source().execute(&query).await;
     }
+
+    #[tokio::test]
+    async fn test_count_by_keeps_first_seen_order() {
+        let source = MockTableSource::new()
+            .with_data(
+                "orders",
+                vec![
+                    json!({"id": "1", "status": "open"}),
+                    json!({"id": "2", "status": "paid"}),
+                    json!({"id": "3", "status": "open"}),
+                ],
+            )
+            .await;
+        let table = Table::<_, vantage_types::EmptyEntity>::new("orders", source)
+            .with_id_column("id")
+            .with_column_of::<String>("status");
+
+        assert_eq!(
+            table.count_by("status").await.unwrap(),
+            vec![(json!("open"), 2), (json!("paid"), 1)]
+        );
+        assert!(table.count_by("total").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_distinct_values_deduplicates_in_order() {
+        let source = MockTableSource::new()
+            .with_data(
+                "orders",
+                vec![
+                    json!({"id": "1", "status": "paid"}),
+                    json!({"id": "2", "status": "open"}),
+                    json!({"id": "3", "status": "paid"}),
+                ],
+            )
+            .await;
+        let table = Table::<_, vantage_types::EmptyEntity>::new("orders", source)
+            .with_id_column("id")
+            .with_column_of::<String>("status");
+
+        assert_eq!(
+            table.distinct_values("status").await.unwrap(),
+            vec![json!("paid"), json!("open")]
+        );
+        assert!(table.distinct_values("total").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_report_groups_into_struct() {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Revenue {
+            region: String,
+            status: String,
+            total: i64,
+        }
+
+        let source = MockTableSource::new()
+            .with_data(
+                "orders",
+                vec![
+                    json!({"id": "1", "region": "eu", "status": "paid", "amount": 10}),
+                    json!({"id": "2", "region": "us", "status": "paid", "amount": 5}),
+                    json!({"id": "3", "region": "eu", "status": "paid", "amount": 7}),
+                    json!({"id": "4", "region": "eu", "status": "open", "amount": 3}),
+                ],
+            )
+            .await;
+        let table = Table::<_, vantage_types::EmptyEntity>::new("orders", source)
+            .with_id_column("id")
+            .with_column_of::<String>("region")
+            .with_column_of::<String>("status")
+            .with_column_of::<i64>("amount");
+
+        let report: Vec<Revenue> = table
+            .report(
+                vec!["region", "status"],
+                vec![("total", AggExpr::sum("amount"))],
+            )
+            .await
+            .unwrap();
+        let row = |region: &str, status: &str, total| Revenue {
+            region: region.into(),
+            status: status.into(),
+            total,
+        };
+        assert_eq!(
+            report,
+            vec![
+                row("eu", "paid", 17),
+                row("us", "paid", 5),
+                row("eu", "open", 3)
+            ]
+        );
+
+        // `total` is stored under another alias, so no row fits
+        let err = table
+            .report::<Revenue>(vec!["region", "status"], vec![("n", AggExpr::Count)])
+            .await
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("row"));
+
+        assert!(
+            table
+                .report::<Revenue>(vec!["region"], vec![("total", AggExpr::sum("price"))])
+                .await
+                .is_err()
+        );
+    }
 }
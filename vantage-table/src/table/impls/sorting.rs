@@ -1,6 +1,6 @@
 use vantage_core::{Result, error};
 use vantage_expressions::Expression;
-use vantage_types::Entity;
+use vantage_types::{ColumnRef, Entity};
 
 use crate::{sorting::*, table::Table, traits::table_source::TableSource};
 
@@ -46,11 +46,52 @@ impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
         self.order_by.clear();
     }
 
+    /// Order by `column` whenever no explicit order is set. Any order added
+    /// with [`Self::add_order`] or [`Self::temp_add_order`] replaces it, and
+    /// it comes back once those are removed.
+    ///
+    /// The sort expression comes from `TableSource::sort_field_expr`, so
+    /// backends that don't implement it return an error here.
+    pub fn with_default_order(mut self, column: &str, dir: SortDirection) -> Result<Self> {
+        if !self.columns.contains_key(column) {
+            return Err(error!("Unknown column for default order", column = column));
+        }
+        let expression = self.data_source.sort_field_expr(column)?;
+        self.default_order = Some((expression, dir));
+        Ok(self)
+    }
+
+    /// Order clauses a select will apply: the explicit ones, or the default
+    /// order when there are none.
+    pub fn effective_orders(&self) -> impl Iterator<Item = &(T::Condition, SortDirection)> {
+        let default = self
+            .default_order
+            .as_ref()
+            .filter(|_| self.order_by.is_empty());
+        self.order_by.values().chain(default)
+    }
+
     /// Add an order clause using the builder pattern
     pub fn with_order(mut self, order: OrderBy<T::Condition>) -> Self {
         self.add_order(order);
         self
     }
+
+    /// Order by a typed column of `E`, e.g. `table.order_by(Client::email(),
+    /// SortDirection::Ascending)` with `Client` deriving
+    /// [`Columns`](vantage_types::Columns). Use [`Self::with_order`] for
+    /// dynamic tables.
+    pub fn order_by(mut self, column: ColumnRef<E>, dir: SortDirection) -> Result<Self> {
+        if !self.columns.contains_key(column.name()) {
+            return Err(error!("Unknown column for order", column = column.name()));
+        }
+        let expression = self.data_source.sort_field_expr(column.name())?;
+        self.add_order(OrderBy {
+            expression,
+            direction: dir,
+        });
+        Ok(self)
+    }
 }
 
 /// Extension trait for creating OrderBy from expressions.
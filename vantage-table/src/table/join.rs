@@ -0,0 +1,86 @@
+//! Cross-table selects for [`Table::join`].
+//!
+//! A join is recorded on the table and applied by [`select`](Table::select)
+//! after the table's own columns, through
+//! [`SelectableDataSource::add_select_join`]. The joined table is aliased as
+//! its own name, so its columns are reachable as `other.column` in
+//! conditions and expressions. How the join renders is up to the backend:
+//! SQL emits `INNER JOIN` / `LEFT JOIN ... ON`, SurrealDB projects the
+//! matching record through a subquery (see its `add_select_join`).
+//!
+//! Joins shape the select only. Counts and aggregates run on the base table
+//! (they start from [`select_empty`](Table::select_empty)), and writes never
+//! touch the joined table.
+
+use vantage_core::{Result, error};
+use vantage_expressions::{Expression, JoinKind};
+use vantage_types::Entity;
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// One join recorded by [`Table::join`].
+#[derive(Clone)]
+pub struct TableJoin<V> {
+    pub kind: JoinKind,
+    pub table: String,
+    pub on: Expression<V>,
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Join `other` into this table's select, matching rows on `on`. Fails,
+    /// and records nothing, on a backend without joins (see
+    /// [`TableSource::supports_joins`]).
+    pub fn join(&mut self, other: &str, kind: JoinKind, on: Expression<T::Value>) -> Result<()> {
+        if !self.data_source().supports_joins() {
+            return Err(error!(
+                "Joins are not supported by this backend",
+                table = self.table_name(),
+                join = other
+            ));
+        }
+        self.joins.push(TableJoin {
+            kind,
+            table: other.to_string(),
+            on,
+        });
+        Ok(())
+    }
+
+    /// Builder form of [`Self::join`].
+    pub fn with_join(
+        mut self,
+        other: &str,
+        kind: JoinKind,
+        on: Expression<T::Value>,
+    ) -> Result<Self> {
+        self.join(other, kind, on)?;
+        Ok(self)
+    }
+
+    pub fn joins(&self) -> &[TableJoin<T::Value>] {
+        &self.joins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use vantage_expressions::expr_any;
+    use vantage_types::EmptyEntity;
+
+    #[test]
+    fn test_join_rejected_without_backend_support() {
+        let mut table = Table::<_, EmptyEntity>::new("product", MockTableSource::new());
+        let err = table
+            .join(
+                "bakery",
+                JoinKind::Inner,
+                expr_any!("bakery.id = bakery_id"),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("not supported"), "{err}");
+        assert!(table.joins().is_empty());
+    }
+}
@@ -24,9 +24,36 @@ pub use hooks::*;
 pub mod audit;
 pub use audit::*;
 
+pub mod cache;
+pub use cache::*;
+
 pub mod id_generator;
 pub use id_generator::*;
 
+pub mod join;
+pub use join::*;
+
+pub mod observe;
+pub use observe::*;
+
+pub mod role;
+pub use role::*;
+
+pub mod row_mapper;
+pub use row_mapper::*;
+
+pub mod soft_delete;
+pub use soft_delete::*;
+
+pub mod tracked;
+pub use tracked::*;
+
+pub mod upsert;
+pub use upsert::*;
+
+pub mod validation;
+pub use validation::*;
+
 pub mod impls;
 pub use impls::*;
 
@@ -0,0 +1,236 @@
+//! Re-run a query whenever something it depends on changes.
+//!
+//! [`Table::observe`] yields the table's entities once, then again every
+//! time one of its [`Dependency`] handles is notified. A dependency is a
+//! plain signal: whoever changes a filter value (or writes the data) calls
+//! [`Dependency::notify`], or [`Dependency::track`] forwards a broadcast
+//! channel such as `ImTable::subscribe` into one. Notifications that arrive
+//! while a query is running are coalesced into a single re-run.
+
+use std::future::{Future, poll_fn};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Poll;
+
+use futures_core::Stream;
+use tokio::sync::{broadcast, watch};
+
+use vantage_core::Result;
+use vantage_dataset::prelude::ReadableDataSet;
+use vantage_types::Entity;
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// Change signal for [`Table::observe`]. Clones share the same signal.
+#[derive(Clone, Debug)]
+pub struct Dependency {
+    version: Arc<watch::Sender<u64>>,
+    /// Forwarding task of [`Self::track`], stopped with the last clone.
+    _forwarder: Option<Arc<AbortOnDrop>>,
+}
+
+/// Aborts a spawned task when dropped.
+#[derive(Debug)]
+struct AbortOnDrop(tokio::task::AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl Dependency {
+    pub fn new() -> Self {
+        Self {
+            version: Arc::new(watch::channel(0).0),
+            _forwarder: None,
+        }
+    }
+
+    /// Re-run every query observing this dependency.
+    pub fn notify(&self) {
+        bump(&self.version);
+    }
+
+    /// Dependency notified on every message of `changes`, e.g.
+    /// `ImTable::subscribe()`. Spawns a forwarding task on the current tokio
+    /// runtime, which ends when the channel closes or the last clone of the
+    /// dependency (including those held by `observe` streams) is dropped.
+    pub fn track<C: Clone + Send + 'static>(mut changes: broadcast::Receiver<C>) -> Self {
+        let version = Arc::new(watch::channel(0).0);
+        let forward = version.clone();
+        let task = tokio::spawn(async move {
+            // A lagged receiver still means something changed.
+            while let Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) = changes.recv().await {
+                bump(&forward);
+            }
+        });
+        Self {
+            version,
+            _forwarder: Some(Arc::new(AbortOnDrop(task.abort_handle()))),
+        }
+    }
+
+    fn subscribe(&self) -> watch::Receiver<u64> {
+        self.version.subscribe()
+    }
+}
+
+fn bump(version: &watch::Sender<u64>) {
+    version.send_modify(|v| *v = v.wrapping_add(1));
+}
+
+impl Default for Dependency {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Table<T, E>
+where
+    T: TableSource,
+    E: Entity<T::Value>,
+{
+    /// Stream the table's entities now and again after each change to any of
+    /// `deps`. With no dependencies the stream ends after the first result.
+    ///
+    /// Conditions are re-evaluated on every run, so a condition whose value
+    /// is read through a deferred parameter picks up the new filter value.
+    #[allow(clippy::type_complexity)]
+    pub fn observe(
+        &self,
+        deps: &[Dependency],
+    ) -> Pin<Box<dyn Stream<Item = Result<Vec<E>>> + Send + '_>> {
+        // Keep the dependencies alive with the stream, so `changed()` only
+        // fails if the stream itself is gone.
+        let deps = deps.to_vec();
+        let mut receivers: Vec<_> = deps.iter().map(Dependency::subscribe).collect();
+
+        Box::pin(async_stream::stream! {
+            let _deps = deps;
+            loop {
+                // Anything notified before this run is covered by it.
+                for rx in receivers.iter_mut() {
+                    rx.borrow_and_update();
+                }
+
+                yield self.list().await.map(|entities| entities.into_values().collect());
+
+                if receivers.is_empty() {
+                    break;
+                }
+                any_changed(&mut receivers).await;
+            }
+        })
+    }
+}
+
+/// Wait until at least one of `receivers` sees a new version.
+async fn any_changed(receivers: &mut [watch::Receiver<u64>]) {
+    let mut changes: Vec<Pin<Box<dyn Future<Output = _> + Send + '_>>> = receivers
+        .iter_mut()
+        .map(|rx| Box::pin(rx.changed()) as Pin<Box<dyn Future<Output = _> + Send>>)
+        .collect();
+    poll_fn(|cx| {
+        for change in changes.iter_mut() {
+            if change.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use vantage_dataset::prelude::WritableValueSet;
+    use vantage_types::Record;
+
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+    struct Client {
+        name: String,
+    }
+
+    async fn next<S: Stream + Unpin>(stream: &mut S) -> Option<S::Item> {
+        let item = poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx));
+        tokio::time::timeout(Duration::from_secs(1), item)
+            .await
+            .expect("observe stream did not yield")
+    }
+
+    #[tokio::test]
+    async fn test_observe_reruns_on_dependency_change() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let table = Table::<_, Client>::new("client", source);
+        let dependency = Dependency::new();
+
+        let mut results = table.observe(std::slice::from_ref(&dependency));
+        let first = next(&mut results).await.unwrap().unwrap();
+        assert_eq!(first.len(), 1);
+
+        let mut doc = Record::new();
+        doc.insert("name".to_string(), json!("Doc"));
+        table.insert_value("c2".to_string(), &doc).await.unwrap();
+        dependency.notify();
+
+        let second = next(&mut results).await.unwrap().unwrap();
+        assert_eq!(second.len(), 2);
+        assert_eq!(second[1].name, "Doc");
+    }
+
+    #[tokio::test]
+    async fn test_tracked_broadcast_triggers_rerun() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let table = Table::<_, Client>::new("client", source);
+        let (changes, rx) = broadcast::channel::<()>(4);
+
+        let mut results = table.observe(&[Dependency::track(rx)]);
+        assert_eq!(next(&mut results).await.unwrap().unwrap().len(), 1);
+
+        changes.send(()).unwrap();
+        assert_eq!(next(&mut results).await.unwrap().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tracking_stops_with_last_observer() {
+        let table = Table::<_, Client>::new("client", MockTableSource::new());
+        let (changes, rx) = broadcast::channel::<()>(4);
+        let dependency = Dependency::track(rx);
+        let mut results = table.observe(std::slice::from_ref(&dependency));
+        next(&mut results).await.unwrap().unwrap();
+
+        // The stream still holds a clone, so forwarding goes on
+        drop(dependency);
+        tokio::task::yield_now().await;
+        assert_eq!(changes.receiver_count(), 1);
+
+        drop(results);
+        for _ in 0..100 {
+            if changes.receiver_count() == 0 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(changes.receiver_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_observe_without_dependencies_ends() {
+        let table = Table::<_, Client>::new("client", MockTableSource::new());
+        let mut results = table.observe(&[]);
+        assert!(next(&mut results).await.unwrap().unwrap().is_empty());
+        assert!(next(&mut results).await.is_none());
+    }
+}
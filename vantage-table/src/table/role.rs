@@ -0,0 +1,225 @@
+//! Column-level permissions for [`Table::with_role`].
+//!
+//! A [`Role`] is a list of rules in the shape of the `roles` section of the
+//! vantage config: each rule allows a [`Permission`] on a target, which is
+//! `"*"`, a table (`"client"` or `"client.*"`) or a single column
+//! (`"client.email"`). Anything not allowed is denied.
+//!
+//! Once a table has a role, [`select`](Table::select) leaves out columns the
+//! role can't read (the id column is always projected). Queries that name a
+//! column — `select_only`, `count_by`, `count_distinct`, `distinct_values`,
+//! `report` and the aggregates — fail on one the role can't read, and
+//! `select_raw`, whose projection could name any column, needs read access to
+//! all of them. Writes that carry
+//! a column the role can't edit fail with a permission error. Writes check
+//! the caller's record as given, so a full-entity replace is denied as soon
+//! as the entity has a protected field; use a patch to change the rest.
+
+use std::sync::Arc;
+
+use vantage_core::{Result, error};
+use vantage_types::{Entity, Record};
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Read,
+    Write,
+}
+
+/// Named set of column permissions, see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Role {
+    name: String,
+    rules: Vec<(Permission, String)>,
+}
+
+impl Role {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Allow `permission` on `on`: `"*"`, `"table"`, `"table.*"` or
+    /// `"table.column"`.
+    pub fn allow(mut self, permission: Permission, on: impl Into<String>) -> Self {
+        self.rules.push((permission, on.into()));
+        self
+    }
+
+    /// Whether the role holds `permission` on `column` of `table`.
+    pub fn can(&self, permission: Permission, table: &str, column: &str) -> bool {
+        self.rules.iter().any(|(allowed, on)| {
+            *allowed == permission
+                && match on.split_once('.') {
+                    None => on == "*" || on == table,
+                    Some((t, c)) => t == table && (c == "*" || c == column),
+                }
+        })
+    }
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Enforce `role`'s column permissions on this table's selects and
+    /// writes, see [`Role`].
+    pub fn with_role(mut self, role: &Role) -> Self {
+        self.role = Some(Arc::new(role.clone()));
+        self
+    }
+
+    pub fn role(&self) -> Option<&Role> {
+        self.role.as_deref()
+    }
+
+    /// Whether the table's role (if any) may read `column`.
+    pub(crate) fn can_read_column(&self, column: &str) -> bool {
+        match &self.role {
+            None => true,
+            Some(role) => role.can(Permission::Read, self.table_name(), column),
+        }
+    }
+
+    /// Fail if the table's role may not read `column`. The id column is
+    /// always readable, as in [`Self::select`].
+    pub(crate) fn check_read_permission(&self, column: &str) -> Result<()> {
+        let Some(role) = &self.role else {
+            return Ok(());
+        };
+        if self.id_field.as_deref() == Some(column)
+            || role.can(Permission::Read, self.table_name(), column)
+        {
+            return Ok(());
+        }
+        Err(error!(
+            "Permission denied: role cannot read column",
+            role = role.name(),
+            table = self.table_name(),
+            column = column
+        ))
+    }
+
+    /// Fail unless the table's role may read every column, for queries whose
+    /// projection isn't known column by column.
+    pub(crate) fn check_read_all_permission(&self) -> Result<()> {
+        for column in self.columns.keys().chain(self.expressions.keys()) {
+            self.check_read_permission(column)?;
+        }
+        Ok(())
+    }
+
+    /// Fail if `record` sets a column the table's role may not edit. The id
+    /// column keys the row rather than being edited, so it is exempt.
+    pub(crate) fn check_write_permission(&self, record: &Record<T::Value>) -> Result<()> {
+        let Some(role) = &self.role else {
+            return Ok(());
+        };
+        for column in record.keys() {
+            if self.id_field.as_deref() == Some(column.as_str()) {
+                continue;
+            }
+            if !role.can(Permission::Write, self.table_name(), column) {
+                return Err(error!(
+                    "Permission denied: role cannot edit column",
+                    role = role.name(),
+                    table = self.table_name(),
+                    column = column.as_str()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde_json::json;
+    use vantage_dataset::prelude::{ReadableValueSet, WritableValueSet};
+    use vantage_types::EmptyEntity;
+
+    fn clerk() -> Role {
+        Role::new("clerk")
+            .allow(Permission::Read, "client.name")
+            .allow(Permission::Write, "client.name")
+            .allow(Permission::Read, "order")
+    }
+
+    #[test]
+    fn test_rule_targets() {
+        let role = clerk();
+        assert!(role.can(Permission::Read, "client", "name"));
+        assert!(!role.can(Permission::Read, "client", "salary"));
+        assert!(role.can(Permission::Read, "order", "total"));
+        assert!(!role.can(Permission::Write, "order", "total"));
+        assert!(Role::new("admin").allow(Permission::Write, "*").can(
+            Permission::Write,
+            "client",
+            "salary"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_edit_of_protected_column_is_denied() {
+        let source = MockTableSource::new()
+            .with_data(
+                "client",
+                vec![json!({"id": "c1", "name": "Marty", "salary": 100})],
+            )
+            .await;
+        let table = Table::<_, EmptyEntity>::new("client", source)
+            .with_id_column("id")
+            .with_role(&clerk());
+
+        let err = table
+            .patch_value("c1", &Record::from(json!({"salary": 1000})))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Permission denied"), "{err}");
+
+        table
+            .patch_value("c1", &Record::from(json!({"name": "Doc"})))
+            .await
+            .unwrap();
+        let c1 = table.get_value("c1").await.unwrap().unwrap();
+        assert_eq!(c1["name"], json!("Doc"));
+        assert_eq!(c1["salary"], json!(100));
+    }
+
+    #[tokio::test]
+    async fn test_queries_naming_unreadable_column_are_denied() {
+        use vantage_expressions::mocks::datasource::MockSelectableDataSource;
+
+        let source =
+            MockTableSource::new().with_select_source(MockSelectableDataSource::new(json!([])));
+        let table = Table::<_, EmptyEntity>::new("client", source)
+            .with_id_column("id")
+            .with_column_of::<String>("name")
+            .with_column_of::<i64>("salary")
+            .with_role(&clerk());
+
+        let denied = |err: vantage_core::VantageError| {
+            assert!(err.to_string().contains("Permission denied"), "{err}");
+        };
+        denied(table.select_only(&["salary"]).unwrap_err());
+        denied(table.count_by("salary").await.unwrap_err());
+        denied(table.count_distinct("salary").await.unwrap_err());
+        denied(table.distinct_values("salary").await.unwrap_err());
+        denied(
+            table
+                .get_sum(&table.data_source().create_column("salary"))
+                .await
+                .unwrap_err(),
+        );
+
+        assert!(table.select_only(&["id", "name"]).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+//! Post-processing of fetched entities — derived fields the backend can't
+//! compute. Register with [`Table::with_row_mapper`].
+
+use std::sync::Arc;
+
+use vantage_core::{Result, error};
+use vantage_types::{Entity, Record};
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// A row mapper, stored record-level so it survives [`Table::into_entity`]:
+/// it converts the record to the entity it was registered for, runs the
+/// caller's closure and writes the fields back.
+pub type RowMapperFn<T> =
+    Arc<dyn Fn(&mut Record<<T as TableSource>::Value>) -> Result<()> + Send + Sync>;
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Run `mapper` on every entity read from this table — `list`, `get`,
+    /// `get_some`, `find_by_id`, `stream`, `page_with_count` and related
+    /// rows — before it is returned. Mappers run in registration order,
+    /// after lazy expressions. Raw value reads (`list_values` and friends)
+    /// are left untouched.
+    ///
+    /// ```rust,ignore
+    /// let clients = Client::table(db).with_row_mapper(|c: &mut Client| {
+    ///     c.name = c.name.to_uppercase();
+    /// });
+    /// ```
+    pub fn with_row_mapper(mut self, mapper: impl Fn(&mut E) + Send + Sync + 'static) -> Self
+    where
+        E: 'static,
+    {
+        self.add_row_mapper(mapper);
+        self
+    }
+
+    /// Mutable form of [`Self::with_row_mapper`].
+    pub fn add_row_mapper(&mut self, mapper: impl Fn(&mut E) + Send + Sync + 'static)
+    where
+        E: 'static,
+    {
+        self.row_mappers.push(Arc::new(move |record| {
+            let mut entity = E::try_from_record(record)
+                .map_err(|_| error!("Failed to convert record to entity"))?;
+            mapper(&mut entity);
+            let mapped = entity
+                .try_into_record()
+                .map_err(|_| error!("Failed to convert mapped entity to record"))?;
+            // Fields the entity doesn't carry (the id, extra columns) stay
+            for (field, value) in mapped {
+                record.insert(field, value);
+            }
+            Ok(())
+        }));
+    }
+
+    /// Convert a fetched record into an entity, running the row mappers.
+    pub(crate) fn record_to_entity(&self, mut record: Record<T::Value>) -> Result<E> {
+        for mapper in &self.row_mappers {
+            mapper(&mut record)?;
+        }
+        E::try_from_record(&record).map_err(|_| error!("Failed to convert record to entity"))
+    }
+}
@@ -27,6 +27,30 @@ where
     }
 }
 
+impl<T, E> Table<T, E>
+where
+    T: TableSource,
+    T::Value: InvariantValue,
+    E: Entity<T::Value>,
+    <E as TryIntoRecord<T::Value>>::Error: std::fmt::Debug,
+{
+    /// Insert several entities, batched into one backend call where the
+    /// source supports it. Returns the created IDs in input order; see
+    /// [`insert_many_values`](Table::insert_many_values).
+    pub async fn insert_many(&self, entities: impl IntoIterator<Item = E>) -> Result<Vec<T::Id>> {
+        let records = entities
+            .into_iter()
+            .map(|entity| {
+                entity.try_into_record().map_err(|e| {
+                    vantage_core::error!("Failed to serialize entity to record", error = e)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.insert_many_values(records).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,4 +145,29 @@ mod tests {
         let id = result.unwrap();
         assert!(!id.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_insert_many() {
+        let mock_source = MockTableSource::new().with_data("events", vec![]).await;
+        let table = Table::<MockTableSource, TestEvent>::new("events", mock_source);
+
+        let event = |id: Option<&str>, event_type: &str| TestEvent {
+            id: id.map(str::to_string),
+            event_type: event_type.to_string(),
+            message: "seeded".to_string(),
+            timestamp: None,
+        };
+        let ids = table
+            .insert_many(vec![
+                event(Some("e2"), "click"),
+                event(Some("e1"), "view"),
+                event(None, "login"),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(&ids[..2], ["e2", "e1"]);
+        assert_eq!(table.get_count().await.unwrap(), 3);
+
+        assert!(table.insert_many(Vec::new()).await.unwrap().is_empty());
+    }
 }
@@ -22,24 +22,75 @@ where
 {
     async fn insert_return_id_value(&self, record: &Record<Self::Value>) -> Result<Self::Id> {
         let erased = self.as_entity_erased();
+        self.check_write_permission(record)?;
         let mut record = record.clone();
         run_before(self.before_insert_hooks(), &mut record, erased).await?;
+        self.validate(&record)?;
         self.strip_imported_columns(&mut record);
         enforce_invariants(&mut record, self.invariants())?;
         let id = self
             .data_source()
             .insert_table_return_id_value(self, &record)
-            .await?;
+            .await;
+        self.invalidate_cache();
+        let id = id?;
         run_after(self.after_insert_hooks(), &id, &record, erased).await?;
         Ok(id)
     }
 }
 
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E>
+where
+    T::Value: InvariantValue,
+{
+    /// Insert several records in one backend call where the source supports
+    /// it, returning their IDs in input order. Hooks and invariants run per
+    /// record, as with [`insert_return_id_value`](InsertableValueSet::insert_return_id_value).
+    /// An empty input returns an empty vec without touching the source.
+    pub async fn insert_many_values(
+        &self,
+        records: impl IntoIterator<Item = Record<T::Value>>,
+    ) -> Result<Vec<T::Id>> {
+        let erased = self.as_entity_erased();
+        let mut prepared = Vec::new();
+        for mut record in records {
+            self.check_write_permission(&record)?;
+            run_before(self.before_insert_hooks(), &mut record, erased).await?;
+            self.validate(&record)?;
+            self.strip_imported_columns(&mut record);
+            enforce_invariants(&mut record, self.invariants())?;
+            prepared.push(record);
+        }
+        if prepared.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids = self
+            .data_source()
+            .insert_table_return_id_values(self, &prepared)
+            .await;
+        self.invalidate_cache();
+        let ids = ids?;
+        if ids.len() != prepared.len() {
+            return Err(vantage_core::error!(
+                "Bulk insert returned a different number of ids",
+                records = prepared.len(),
+                ids = ids.len()
+            ));
+        }
+        for (id, record) in ids.iter().zip(&prepared) {
+            run_after(self.after_insert_hooks(), id, record, erased).await?;
+        }
+        Ok(ids)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::mocks::mock_table_source::MockTableSource;
     use serde_json::json;
+    use vantage_dataset::prelude::ReadableValueSet;
     use vantage_types::EmptyEntity;
 
     #[tokio::test]
@@ -83,4 +134,26 @@ mod tests {
             .unwrap();
         assert_eq!(id5, "42");
     }
+
+    #[tokio::test]
+    async fn test_insert_many_values() {
+        let mock_source = MockTableSource::new().with_data("test_table", vec![]).await;
+        let table = Table::<MockTableSource, EmptyEntity>::new("test_table", mock_source);
+
+        assert!(table.insert_many_values(vec![]).await.unwrap().is_empty());
+
+        let ids = table
+            .insert_many_values(vec![
+                Record::from(json!({"id": "c", "name": "Carol"})),
+                Record::from(json!({"id": "a", "name": "Alice"})),
+                Record::from(json!({"name": "Bob"})),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(&ids[..2], ["c", "a"]);
+
+        let bob = table.get_value(ids[2].clone()).await.unwrap().unwrap();
+        assert_eq!(bob.get("name"), Some(&json!("Bob")));
+    }
 }
@@ -18,14 +18,13 @@ where
     E: Entity<T::Value>,
 {
     async fn list(&self) -> Result<IndexMap<Self::Id, E>> {
+        self.check_aggregates()?;
         let records = self.data_source().list_table_values(self).await?;
         let mut entities = IndexMap::new();
 
         for (id, mut record) in records {
             self.apply_lazy_expressions(&mut record).await?;
-            let entity: E = E::try_from_record(&record)
-                .map_err(|_| vantage_core::error!("Failed to convert record to entity"))?;
-            entities.insert(id, entity);
+            entities.insert(id, self.record_to_entity(record)?);
         }
 
         Ok(entities)
@@ -33,21 +32,19 @@ where
 
     async fn get(&self, id: impl Into<Self::Id> + Send) -> Result<Option<E>> {
         let id = id.into();
+        self.check_aggregates()?;
         let Some(mut record) = self.data_source().get_table_value(self, &id).await? else {
             return Ok(None);
         };
         self.apply_lazy_expressions(&mut record).await?;
-        let entity = E::try_from_record(&record)
-            .map_err(|_| vantage_core::error!("Failed to convert record to entity"))?;
-        Ok(Some(entity))
+        Ok(Some(self.record_to_entity(record)?))
     }
 
     async fn get_some(&self) -> Result<Option<(Self::Id, E)>> {
+        self.check_aggregates()?;
         if let Some((id, mut record)) = self.data_source().get_table_some_value(self).await? {
             self.apply_lazy_expressions(&mut record).await?;
-            let entity: E = E::try_from_record(&record)
-                .map_err(|_| vantage_core::error!("Failed to convert record to entity"))?;
-            Ok(Some((id, entity)))
+            Ok(Some((id, self.record_to_entity(record)?)))
         } else {
             Ok(None)
         }
@@ -59,6 +56,20 @@ where
     T: TableSource,
     E: Entity<T::Value>,
 {
+    /// Re-read a single record by id and return it as an entity — lets a UI
+    /// refresh one row after a mutation without reloading the whole table.
+    ///
+    /// Unlike [`ReadableDataSet::get`], a missing record is an error: the
+    /// caller is refreshing something it already holds.
+    pub async fn reload_record(&self, id: impl Into<T::Id> + Send) -> Result<E> {
+        self.get(id).await?.ok_or_else(|| {
+            vantage_core::error!(
+                "Record to reload no longer exists",
+                table = self.table_name()
+            )
+        })
+    }
+
     /// Stream all entities as (Id, Entity) pairs.
     ///
     /// Delegates to `stream_table_values` on the data source, converting
@@ -66,6 +77,9 @@ where
     /// (e.g. paginated REST APIs) yield records incrementally.
     #[allow(clippy::type_complexity)]
     pub fn stream(&self) -> Pin<Box<dyn Stream<Item = Result<(T::Id, E)>> + Send + '_>> {
+        if let Err(e) = self.check_aggregates() {
+            return Box::pin(async_stream::stream! { yield Err(e) });
+        }
         let value_stream = self.data_source().stream_table_values(self);
         Box::pin(async_stream::stream! {
             tokio::pin!(value_stream);
@@ -81,9 +95,7 @@ where
                 let mapped: Option<Result<(T::Id, E)>> = match item {
                     Some(Ok((id, record))) => {
                         // Convert record to entity — handle error before yield to keep Send
-                        let result = E::try_from_record(&record)
-                            .map(|entity| (id, entity))
-                            .map_err(|_| vantage_core::error!("Failed to convert record to entity"));
+                        let result = self.record_to_entity(record).map(|entity| (id, entity));
                         Some(result)
                     }
                     Some(Err(e)) => Some(Err(e)),
@@ -98,12 +110,42 @@ where
     }
 }
 
+impl<T, E> Table<T, E>
+where
+    T: TableSource,
+    E: Entity<T::Value>,
+{
+    /// Load the entity whose id is `id`, given as a plain string, without
+    /// building the backend's id type first. `Ok(None)` if it doesn't exist.
+    /// Goes through the table's cache, if it has one (see [`Self::with_cache`]).
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<E>> {
+        self.check_aggregates()?;
+        let cached = self.cache().and_then(|cache| cache.get(id));
+        let mut record = match cached {
+            Some(record) => record,
+            None => {
+                let Some(record) = self.data_source().find_table_value_by_id(self, id).await?
+                else {
+                    return Ok(None);
+                };
+                if let Some(cache) = self.cache() {
+                    cache.put(id, record.clone());
+                }
+                record
+            }
+        };
+        self.apply_lazy_expressions(&mut record).await?;
+        Ok(Some(self.record_to_entity(record)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::mocks::mock_table_source::MockTableSource;
     use serde::{Deserialize, Serialize};
     use serde_json::json;
+    use vantage_dataset::prelude::{ReadableValueSet, WritableValueSet};
 
     #[derive(Clone, Debug, Serialize, Deserialize)]
     struct TestUser {
@@ -151,6 +193,11 @@ mod tests {
         let result = table.get("999".to_string()).await.unwrap();
         assert!(result.is_none());
 
+        // Test find_by_id() with a plain string id
+        let entity_3 = table.find_by_id("3").await.unwrap().expect("row 3");
+        assert_eq!(entity_3.name, "Charlie");
+        assert!(table.find_by_id("999").await.unwrap().is_none());
+
         // Test get_some()
         let some_entity = table.get_some().await.unwrap();
         assert!(some_entity.is_some());
@@ -192,4 +239,63 @@ mod tests {
         let result = table.get_some().await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_reload_record() {
+        let mock_source = MockTableSource::new()
+            .with_data(
+                "users",
+                vec![
+                    json!({"id": "1", "name": "Alice", "age": 30}),
+                    json!({"id": "2", "name": "Bob", "age": 25}),
+                ],
+            )
+            .await;
+        let table = Table::<MockTableSource, TestUser>::new("users", mock_source);
+
+        // Mutate through a clone sharing the same storage
+        let mut patch = vantage_types::Record::new();
+        patch.insert("age".to_string(), json!(26));
+        table.clone().patch_value("2", &patch).await.unwrap();
+
+        let bob = table.reload_record("2").await.unwrap();
+        assert_eq!(bob.id, Some("2".to_string()));
+        assert_eq!(bob.age, 26);
+
+        let err = table.reload_record("999").await.unwrap_err();
+        assert!(err.to_string().contains("no longer exists"));
+    }
+
+    #[tokio::test]
+    async fn test_row_mapper_runs_on_every_read() {
+        let mock_source = MockTableSource::new()
+            .with_data(
+                "test_table",
+                vec![
+                    json!({"id": "1", "name": "Alice", "age": 30}),
+                    json!({"id": "2", "name": "Bob", "age": 25}),
+                ],
+            )
+            .await;
+        let table = Table::<MockTableSource, TestUser>::new("test_table", mock_source)
+            .with_row_mapper(|user: &mut TestUser| user.name = user.name.to_uppercase());
+
+        let names: Vec<String> = table
+            .list()
+            .await
+            .unwrap()
+            .into_values()
+            .map(|user| user.name)
+            .collect();
+        assert_eq!(names, vec!["ALICE", "BOB"]);
+
+        let (_, first) = table.get_some().await.unwrap().expect("a row");
+        assert_eq!(first.name, "ALICE");
+        assert_eq!(table.get("2").await.unwrap().unwrap().name, "BOB");
+        assert_eq!(table.find_by_id("2").await.unwrap().unwrap().name, "BOB");
+
+        // Raw values are not mapped
+        let raw = table.get_value("1").await.unwrap().unwrap();
+        assert_eq!(raw["name"], json!("Alice"));
+    }
 }
@@ -15,6 +15,7 @@ use crate::{table::Table, traits::table_source::TableSource};
 #[async_trait]
 impl<T: TableSource, E: Entity<T::Value>> ReadableValueSet for Table<T, E> {
     async fn list_values(&self) -> Result<IndexMap<Self::Id, Record<Self::Value>>> {
+        self.check_aggregates()?;
         let mut rows = self.data_source().list_table_values(self).await?;
         for (_, record) in rows.iter_mut() {
             self.apply_lazy_expressions(record).await?;
@@ -27,6 +28,7 @@ impl<T: TableSource, E: Entity<T::Value>> ReadableValueSet for Table<T, E> {
         id: impl Into<Self::Id> + Send,
     ) -> Result<Option<Record<Self::Value>>> {
         let id = id.into();
+        self.check_aggregates()?;
         let Some(mut record) = self.data_source().get_table_value(self, &id).await? else {
             return Ok(None);
         };
@@ -35,6 +37,7 @@ impl<T: TableSource, E: Entity<T::Value>> ReadableValueSet for Table<T, E> {
     }
 
     async fn get_some_value(&self) -> Result<Option<(Self::Id, Record<Self::Value>)>> {
+        self.check_aggregates()?;
         let Some((id, mut record)) = self.data_source().get_table_some_value(self).await? else {
             return Ok(None);
         };
@@ -45,6 +48,9 @@ impl<T: TableSource, E: Entity<T::Value>> ReadableValueSet for Table<T, E> {
     fn stream_values(
         &self,
     ) -> Pin<Box<dyn Stream<Item = Result<(Self::Id, Record<Self::Value>)>> + Send + '_>> {
+        if let Err(e) = self.check_aggregates() {
+            return Box::pin(async_stream::stream! { yield Err(e) });
+        }
         let mut source = self.data_source().stream_table_values(self);
         if self.lazy_expressions.is_empty() {
             return source;
@@ -29,14 +29,18 @@ where
     ) -> Result<Record<Self::Value>> {
         let id = id.into();
         let erased = self.as_entity_erased();
+        self.check_write_permission(record)?;
         let mut record = record.clone();
         run_before(self.before_insert_hooks(), &mut record, erased).await?;
+        self.validate(&record)?;
         self.strip_imported_columns(&mut record);
         enforce_invariants(&mut record, self.invariants())?;
         let result = self
             .data_source()
             .insert_table_value(self, &id, &record)
-            .await?;
+            .await;
+        self.invalidate_cache();
+        let result = result?;
         run_after(self.after_insert_hooks(), &id, &result, erased).await?;
         Ok(result)
     }
@@ -48,14 +52,18 @@ where
     ) -> Result<Record<Self::Value>> {
         let id = id.into();
         let erased = self.as_entity_erased();
+        self.check_write_permission(record)?;
         let mut record = record.clone();
         run_before(self.before_update_hooks(), &mut record, erased).await?;
+        self.validate(&record)?;
         self.strip_imported_columns(&mut record);
         enforce_invariants(&mut record, self.invariants())?;
         let result = self
             .data_source()
             .replace_table_value(self, &id, &record)
-            .await?;
+            .await;
+        self.invalidate_cache();
+        let result = result?;
         run_after(self.after_update_hooks(), &id, &result, erased).await?;
         Ok(result)
     }
@@ -76,15 +84,24 @@ where
                 column = name.as_str()
             ));
         }
+        self.check_write_permission(partial)?;
         let erased = self.as_entity_erased();
         let mut partial = partial.clone();
         run_before(self.before_update_hooks(), &mut partial, erased).await?;
+        if self.has_validators() {
+            // Validate the row the patch produces, not the patch alone
+            let mut merged = self.get_value(id.clone()).await?.unwrap_or_default();
+            merged.extend(partial.clone());
+            self.validate(&merged)?;
+        }
         self.strip_imported_columns(&mut partial);
         enforce_invariants(&mut partial, self.invariants())?;
         let result = self
             .data_source()
             .patch_table_value(self, &id, &partial)
-            .await?;
+            .await;
+        self.invalidate_cache();
+        let result = result?;
         run_after(self.after_update_hooks(), &id, &result, erased).await?;
         Ok(result)
     }
@@ -100,21 +117,77 @@ where
         } else {
             None
         };
+        // Under soft delete a row the table can't see is already deleted;
+        // falling through to the real delete would purge it.
+        if former.is_none() && self.soft_delete().is_some() {
+            return Ok(());
+        }
         if let Some(former) = former {
             let erased = self.as_entity_erased();
             let outcome =
                 run_before_delete(self.before_delete_hooks(), &id, &former, erased).await?;
             if let HookReturn::Proceed = outcome {
-                self.data_source().delete_table_value(self, &id).await?;
+                let deleted = self.data_source().delete_table_value(self, &id).await;
+                self.invalidate_cache();
+                deleted?;
             }
             run_after(self.after_delete_hooks(), &id, &former, erased).await?;
             return Ok(());
         }
-        self.data_source().delete_table_value(self, &id).await
+        let deleted = self.data_source().delete_table_value(self, &id).await;
+        self.invalidate_cache();
+        deleted
     }
 
     async fn delete_all(&self) -> Result<()> {
-        self.data_source().delete_table_all_values(self).await
+        // Soft delete flags rows one by one through `delete`, so its hooks run.
+        if self.soft_delete().is_some() {
+            for id in self.list_values().await?.into_keys() {
+                self.delete(id).await?;
+            }
+            return Ok(());
+        }
+        let deleted = self.data_source().delete_table_all_values(self).await;
+        self.invalidate_cache();
+        deleted
+    }
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E>
+where
+    T::Value: InvariantValue,
+{
+    /// Delete the records with these IDs, in one backend call where the
+    /// source supports it, and return how many were removed. IDs with no
+    /// record are skipped. An empty input returns 0 without touching the
+    /// source.
+    ///
+    /// Delete hooks and soft delete work per record, so with either in
+    /// place each ID goes through [`delete`](WritableValueSet::delete).
+    pub async fn delete_by_ids(&self, ids: &[T::Id]) -> Result<u64> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let per_row = self.soft_delete().is_some()
+            || !self.before_delete_hooks().is_empty()
+            || !self.after_delete_hooks().is_empty();
+        if per_row {
+            let mut deleted = 0;
+            for id in ids {
+                if self.get_value(id.clone()).await?.is_some() {
+                    self.delete(id.clone()).await?;
+                    deleted += 1;
+                }
+            }
+            return Ok(deleted);
+        }
+
+        let deleted = self
+            .data_source()
+            .delete_table_values_by_ids(self, ids)
+            .await;
+        self.invalidate_cache();
+        deleted
     }
 }
 
@@ -189,4 +262,26 @@ mod tests {
         let all_values = table.list_values().await.unwrap();
         assert_eq!(all_values.len(), 0); // All records should be deleted
     }
+
+    #[tokio::test]
+    async fn test_delete_by_ids_counts_removed_rows() {
+        let source = MockTableSource::new()
+            .with_data(
+                "client",
+                vec![
+                    json!({"id": "1", "name": "Marty"}),
+                    json!({"id": "2", "name": "Doc"}),
+                    json!({"id": "3", "name": "Biff"}),
+                ],
+            )
+            .await;
+        let table = Table::<MockTableSource, EmptyEntity>::new("client", source);
+
+        let ids = ["1".to_string(), "3".to_string(), "999".to_string()];
+        assert_eq!(table.delete_by_ids(&ids).await.unwrap(), 2);
+        let left = table.list_values().await.unwrap();
+        assert_eq!(left.keys().collect::<Vec<_>>(), vec!["2"]);
+
+        assert_eq!(table.delete_by_ids(&[]).await.unwrap(), 0);
+    }
 }
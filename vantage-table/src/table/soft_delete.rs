@@ -0,0 +1,239 @@
+//! Soft delete: flag rows as deleted instead of removing them.
+//!
+//! [`Table::with_soft_delete`] names a boolean column (typically
+//! `is_deleted`). From then on the table:
+//!
+//! - narrows every read and generated select with `field = false`;
+//! - fills the flag with `false` on insert when the caller leaves it empty,
+//!   so new rows are visible under that condition;
+//! - turns `delete` / `delete_all` into a patch setting the flag to `true`,
+//!   via a before-delete hook returning [`HookReturn::Handled`].
+//!
+//! [`Table::include_deleted`] drops the narrowing condition for a query that
+//! needs to see flagged rows; deletes stay soft.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use vantage_core::{Result, error};
+use vantage_dataset::prelude::WritableValueSet;
+use vantage_types::{EmptyEntity, Entity, InvariantValue, Record};
+
+use crate::conditions::ConditionHandle;
+use crate::table::{Hook, HookReturn, Phase, Table};
+use crate::traits::table_source::TableSource;
+
+/// Soft-delete configuration held by a table: the flag column, the
+/// `field = false` condition, and its handle while it is applied.
+#[derive(Clone)]
+pub struct SoftDelete<C> {
+    field: String,
+    condition: C,
+    handle: Option<ConditionHandle>,
+}
+
+impl<C> SoftDelete<C> {
+    /// The flag column.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Whether flagged rows are currently filtered out.
+    pub fn is_filtering(&self) -> bool {
+        self.handle.is_some()
+    }
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E>
+where
+    T::Value: InvariantValue + From<bool>,
+{
+    /// Treat `field` as a soft-delete flag: hide rows where it is `true`, and
+    /// set it instead of removing rows on delete.
+    ///
+    /// The condition is built through `TableSource::eq_value_condition`, so
+    /// backends that don't implement it return an error here.
+    pub fn with_soft_delete(mut self, field: &str) -> Result<Self> {
+        if self.soft_delete.is_some() {
+            return Err(error!("soft delete is already configured", field = field));
+        }
+        let condition = self
+            .data_source()
+            .eq_value_condition(field, T::Value::from(false))?;
+        let handle = self.temp_add_condition(condition.clone());
+        self.soft_delete = Some(SoftDelete {
+            field: field.to_string(),
+            condition,
+            handle: Some(handle),
+        });
+
+        Ok(self
+            .with_hook(Hook::BeforeInsert(
+                Phase::Populate,
+                default_flag_hook::<T>(field.to_string()),
+            ))
+            .with_hook(Hook::BeforeDelete(flag_deleted_hook::<T>(
+                field.to_string(),
+            ))))
+    }
+}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Soft-delete configuration, if [`Self::with_soft_delete`] was used.
+    pub fn soft_delete(&self) -> Option<&SoftDelete<T::Condition>> {
+        self.soft_delete.as_ref()
+    }
+
+    /// Stop hiding soft-deleted rows on this table. Deletes remain soft.
+    /// A no-op on tables without soft delete.
+    pub fn include_deleted(mut self) -> Self {
+        if let Some(handle) = self.soft_delete.as_mut().and_then(|sd| sd.handle.take()) {
+            self.conditions.shift_remove(&handle.0);
+            self.drop_cache();
+        }
+        self
+    }
+
+    /// Put the soft-delete condition back after the condition set was
+    /// cleared (see [`Self::clone_empty`]).
+    pub(super) fn reapply_soft_delete(&mut self) {
+        if let Some(condition) = self
+            .soft_delete
+            .as_ref()
+            .filter(|sd| sd.handle.is_some())
+            .map(|sd| sd.condition.clone())
+        {
+            let handle = self.temp_add_condition(condition);
+            if let Some(sd) = self.soft_delete.as_mut() {
+                sd.handle = Some(handle);
+            }
+        }
+    }
+}
+
+/// Before-insert hook: default the flag to `false` when absent or null, so a
+/// fresh row passes the `field = false` filter.
+fn default_flag_hook<T: TableSource>(field: String) -> crate::table::BeforeFn<T>
+where
+    T::Value: InvariantValue + From<bool>,
+{
+    Arc::new(
+        move |rec: &mut Record<T::Value>,
+              _table: &Table<T, EmptyEntity>|
+              -> Pin<Box<dyn Future<Output = Result<()>> + Send + '_>> {
+            let field = field.clone();
+            Box::pin(async move {
+                if rec.get(field.as_str()).is_none_or(|v| v.is_null()) {
+                    rec.insert(field, T::Value::from(false));
+                }
+                Ok(())
+            })
+        },
+    )
+}
+
+/// Before-delete hook: patch the flag to `true` and skip the real delete.
+fn flag_deleted_hook<T: TableSource>(field: String) -> crate::table::BeforeDeleteFn<T>
+where
+    T::Value: InvariantValue + From<bool>,
+{
+    Arc::new(
+        move |id: &T::Id,
+              _former: &Record<T::Value>,
+              table: &Table<T, EmptyEntity>|
+              -> Pin<Box<dyn Future<Output = Result<HookReturn>> + Send + '_>> {
+            let mut patch = Record::new();
+            patch.insert(field.clone(), T::Value::from(true));
+            Box::pin(async move {
+                table.patch_value(id.clone(), &patch).await?;
+                Ok(HookReturn::Handled)
+            })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde_json::json;
+    use vantage_dataset::prelude::ReadableValueSet;
+
+    type MockTable = Table<MockTableSource, EmptyEntity>;
+
+    async fn users() -> MockTable {
+        let src = MockTableSource::new()
+            .with_data(
+                "users",
+                vec![
+                    json!({"id": "1", "name": "Alice", "is_deleted": false}),
+                    json!({"id": "2", "name": "Bob", "is_deleted": false}),
+                ],
+            )
+            .await;
+        MockTable::new("users", src)
+            .with_soft_delete("is_deleted")
+            .unwrap()
+    }
+
+    fn previews(table: &MockTable) -> Vec<String> {
+        table.conditions().map(|c| c.preview()).collect()
+    }
+
+    #[tokio::test]
+    async fn injects_flag_condition() {
+        let table = users()
+            .await
+            .with_condition(vantage_expressions::expr_any!("age > 18"));
+        assert_eq!(previews(&table), vec!["is_deleted = false", "age > 18"]);
+
+        // Survives clone_empty, which otherwise drops every condition
+        assert_eq!(previews(&table.clone_empty()), vec!["is_deleted = false"]);
+    }
+
+    #[tokio::test]
+    async fn include_deleted_bypasses_filter() {
+        let table = users().await.include_deleted();
+        assert!(previews(&table).is_empty());
+        assert!(!table.soft_delete().unwrap().is_filtering());
+        assert!(table.clone_empty().conditions().next().is_none());
+    }
+
+    #[tokio::test]
+    async fn delete_sets_flag() {
+        let table = users().await;
+        table.delete("1").await.unwrap();
+
+        // The row is still stored, flagged
+        let row = table
+            .clone()
+            .include_deleted()
+            .get_value("1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(row["is_deleted"], json!(true));
+
+        // ...and the filtered table still renders the flag filter, which the
+        // flagged row no longer passes (MockTableSource doesn't apply
+        // conditions itself, so assert on the WHERE rather than a read).
+        assert_eq!(previews(&table), vec!["is_deleted = false"]);
+
+        table.delete_all().await.unwrap();
+        let rows = table.include_deleted().list_values().await.unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.values().all(|r| r["is_deleted"] == json!(true)));
+    }
+
+    #[tokio::test]
+    async fn insert_defaults_flag() {
+        let table = users().await;
+        table
+            .insert_value("3", &Record::from(json!({"name": "Carol"})))
+            .await
+            .unwrap();
+        let row = table.get_value("3").await.unwrap().unwrap();
+        assert_eq!(row["is_deleted"], json!(false));
+    }
+}
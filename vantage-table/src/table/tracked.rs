@@ -0,0 +1,214 @@
+//! Field-level change tracking for a loaded entity.
+//!
+//! [`Table::get_tracked`] loads an entity together with a snapshot of its
+//! fields. The [`TrackedEntity`] derefs to the entity for editing;
+//! [`TrackedEntity::dirty_fields`] lists what changed since the load, and
+//! [`TrackedEntity::save`] writes only those fields with a patch. SQL
+//! backends turn that into an `UPDATE ... SET` naming just the dirty
+//! columns, so a save neither ships the whole row nor overwrites fields
+//! someone else changed in the meantime.
+//!
+//! Unlike [`ActiveEntity::save`](vantage_dataset::prelude::ActiveEntity::save),
+//! which replaces the whole row, a clean `TrackedEntity` saves nothing.
+
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+use vantage_core::{Result, error};
+use vantage_dataset::prelude::{ReadableDataSet, WritableValueSet};
+use vantage_types::{Entity, InvariantValue, Record, TryIntoRecord};
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// An entity loaded from a [`Table`] that remembers its fields as loaded.
+pub struct TrackedEntity<'a, T: TableSource, E: Entity<T::Value>> {
+    id: T::Id,
+    data: E,
+    original: Record<T::Value>,
+    table: &'a Table<T, E>,
+}
+
+impl<T, E> Table<T, E>
+where
+    T: TableSource,
+    E: Entity<T::Value>,
+    <E as TryIntoRecord<T::Value>>::Error: Debug,
+{
+    /// Load the entity with `id` for change tracking. `Ok(None)` if it
+    /// doesn't exist.
+    pub async fn get_tracked(
+        &self,
+        id: impl Into<T::Id> + Send,
+    ) -> Result<Option<TrackedEntity<'_, T, E>>> {
+        let id = id.into();
+        let Some(data) = self.get(id.clone()).await? else {
+            return Ok(None);
+        };
+        let original = to_record(&data)?;
+        Ok(Some(TrackedEntity {
+            id,
+            data,
+            original,
+            table: self,
+        }))
+    }
+}
+
+impl<'a, T, E> TrackedEntity<'a, T, E>
+where
+    T: TableSource,
+    T::Value: InvariantValue,
+    E: Entity<T::Value>,
+    <E as TryIntoRecord<T::Value>>::Error: Debug,
+{
+    pub fn id(&self) -> &T::Id {
+        &self.id
+    }
+
+    /// Fields whose value differs from the loaded snapshot, in the order
+    /// the entity serializes them.
+    pub fn dirty_fields(&self) -> Result<Vec<String>> {
+        Ok(self.changes()?.keys().cloned().collect())
+    }
+
+    pub fn is_dirty(&self) -> Result<bool> {
+        Ok(!self.dirty_fields()?.is_empty())
+    }
+
+    /// The patch [`Self::save`] would send: dirty fields with their current
+    /// values. Fields the entity doesn't serialize are never part of it.
+    pub fn changes(&self) -> Result<Record<T::Value>> {
+        Ok(to_record(&self.data)?
+            .into_iter()
+            .filter(|(key, value)| {
+                !self
+                    .original
+                    .get(key)
+                    .is_some_and(|original| original.value_eq(value))
+            })
+            .collect())
+    }
+
+    /// Patch the dirty fields into the table, then take the current state
+    /// as the new snapshot. Does nothing when no field changed.
+    pub async fn save(&mut self) -> Result<()> {
+        let changes = self.changes()?;
+        if changes.is_empty() {
+            return Ok(());
+        }
+        self.table.patch_value(self.id.clone(), &changes).await?;
+        self.original = to_record(&self.data)?;
+        Ok(())
+    }
+
+    /// Drop the edits and restore the entity as it was loaded.
+    pub fn revert(&mut self) -> Result<()> {
+        self.data = E::from_record(self.original.clone())
+            .map_err(|_| error!("Failed to restore entity from snapshot"))?;
+        Ok(())
+    }
+}
+
+fn to_record<V, E>(data: &E) -> Result<Record<V>>
+where
+    V: Clone,
+    E: Entity<V>,
+    <E as TryIntoRecord<V>>::Error: Debug,
+{
+    data.clone().try_into_record().map_err(|e| {
+        error!(
+            "Failed to serialize entity to record",
+            error = format!("{:?}", e)
+        )
+    })
+}
+
+impl<'a, T: TableSource, E: Entity<T::Value>> Deref for TrackedEntity<'a, T, E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<'a, T: TableSource, E: Entity<T::Value>> DerefMut for TrackedEntity<'a, T, E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use vantage_dataset::prelude::ReadableValueSet;
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    struct Contact {
+        name: String,
+        email: String,
+        phone: String,
+        city: String,
+        age: i32,
+    }
+
+    async fn contacts() -> Table<MockTableSource, Contact> {
+        let source = MockTableSource::new()
+            .with_data(
+                "contact",
+                vec![json!({
+                    "id": "c1", "name": "Ada", "email": "ada@example.com",
+                    "phone": "555-0100", "city": "London", "age": 36
+                })],
+            )
+            .await;
+        Table::new("contact", source)
+    }
+
+    #[tokio::test]
+    async fn test_save_patches_only_dirty_fields() {
+        let table = contacts().await;
+        let mut contact = table.get_tracked("c1").await.unwrap().unwrap();
+        assert!(!contact.is_dirty().unwrap());
+
+        contact.email = "ada@lovelace.dev".to_string();
+        contact.age = 37;
+        let mut dirty = contact.dirty_fields().unwrap();
+        dirty.sort();
+        assert_eq!(dirty, vec!["age", "email"]);
+        let changes = contact.changes().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes["email"], json!("ada@lovelace.dev"));
+        assert_eq!(changes["age"], json!(37));
+
+        // Someone else moves the contact before we save
+        table
+            .patch_value("c1", &Record::from(json!({"city": "Paris"})))
+            .await
+            .unwrap();
+
+        contact.save().await.unwrap();
+        assert!(!contact.is_dirty().unwrap());
+
+        let row = table.get_value("c1").await.unwrap().unwrap();
+        assert_eq!(row["email"], json!("ada@lovelace.dev"));
+        assert_eq!(row["age"], json!(37));
+        assert_eq!(row["city"], json!("Paris"));
+        assert_eq!(row["name"], json!("Ada"));
+    }
+
+    #[tokio::test]
+    async fn test_revert_restores_snapshot() {
+        let table = contacts().await;
+        let mut contact = table.get_tracked("c1").await.unwrap().unwrap();
+        contact.name = "Augusta".to_string();
+        contact.revert().unwrap();
+        assert_eq!(contact.name, "Ada");
+        assert!(!contact.is_dirty().unwrap());
+
+        assert!(table.get_tracked("nope").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,102 @@
+//! Insert-or-update of an entity that carries its own id.
+//!
+//! [`Table::upsert`] takes the id from the entity itself — the field
+//! `#[derive(Columns)]` marks as its id, see
+//! [`IdentifiedEntity`] — and hands the rest of the record to
+//! [`TableSource::upsert_table_value`]. SurrealDB does this in a single
+//! `UPSERT` statement; other backends may check for the row first.
+
+use vantage_core::{Result, error};
+use vantage_types::{Entity, IdentifiedEntity, InvariantValue};
+
+use crate::table::Table;
+use crate::table::sets::{hooks::run_before, invariants::enforce_invariants};
+use crate::traits::table_source::TableSource;
+
+/// What [`Table::upsert`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    /// No row had the entity's id; one was created.
+    Inserted,
+    /// The row with the entity's id was replaced.
+    Updated,
+}
+
+impl<T: TableSource, E: Entity<T::Value> + IdentifiedEntity> Table<T, E>
+where
+    T::Value: InvariantValue,
+{
+    /// Write `entity` under its own id, creating the row or replacing it.
+    ///
+    /// Before-update hooks and set invariants apply as for `replace`. The
+    /// after-hooks don't fire: the backend reports only the outcome, not the
+    /// written row.
+    pub async fn upsert(&self, entity: E) -> Result<UpsertOutcome> {
+        let id = entity
+            .entity_id()
+            .ok_or_else(|| error!("Cannot upsert an entity without an id"))?;
+        let mut record = entity
+            .try_into_record()
+            .map_err(|_| error!("Failed to convert entity to record"))?;
+        record.shift_remove(E::ID_FIELD);
+        self.check_write_permission(&record)?;
+
+        run_before(
+            self.before_update_hooks(),
+            &mut record,
+            self.as_entity_erased(),
+        )
+        .await?;
+        self.validate(&record)?;
+        self.strip_imported_columns(&mut record);
+        enforce_invariants(&mut record, self.invariants())?;
+        let result = self
+            .data_source()
+            .upsert_table_value(self, &id, &record)
+            .await;
+        self.invalidate_cache();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use vantage_dataset::prelude::ReadableValueSet;
+    use vantage_types::Columns;
+
+    #[derive(Clone, Debug, Serialize, Deserialize, Columns)]
+    struct Client {
+        #[id]
+        code: String,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn test_upsert_inserts_then_updates() {
+        let source = MockTableSource::new()
+            .with_data("client", vec![json!({"id": "c1", "name": "Marty"})])
+            .await;
+        let table = Table::<_, Client>::new("client", source);
+
+        let doc = Client {
+            code: "c2".to_string(),
+            name: "Doc".to_string(),
+        };
+        assert_eq!(table.upsert(doc).await.unwrap(), UpsertOutcome::Inserted);
+
+        let biff = Client {
+            code: "c1".to_string(),
+            name: "Biff".to_string(),
+        };
+        assert_eq!(table.upsert(biff).await.unwrap(), UpsertOutcome::Updated);
+
+        let c1 = table.get_value("c1").await.unwrap().unwrap();
+        assert_eq!(c1["name"], "Biff");
+        assert!(c1.get("code").is_none());
+        assert_eq!(table.get_value("c2").await.unwrap().unwrap()["name"], "Doc");
+    }
+}
@@ -0,0 +1,237 @@
+//! Row-level validation before writes.
+//!
+//! [`Table::set_validator`] registers a synchronous check that runs on every
+//! insert, replace and patch, after the before-write hooks (see
+//! [`crate::table::hooks`]) — so it sees the record after normalizing and
+//! populating hooks, and a rejection aborts the write before it reaches the
+//! backend. A patch is checked as the row it will produce: the stored record
+//! with the patched fields laid over it.
+//!
+//! The rejection surfaces as a `VantageError` whose context lists the failing
+//! fields; the original [`ValidationError`] is kept as its source so UI
+//! adapters can recover the per-field messages with
+//! [`ValidationError::from_error`].
+
+use std::fmt;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use vantage_core::{Context, Result, VantageError, error};
+use vantage_types::{Entity, Record};
+
+use crate::table::Table;
+use crate::traits::table_source::TableSource;
+
+/// A row validator registered with [`Table::set_validator`].
+pub type ValidatorFn<T> = Arc<
+    dyn Fn(&Record<<T as TableSource>::Value>) -> std::result::Result<(), ValidationError>
+        + Send
+        + Sync,
+>;
+
+/// Per-field validation messages returned by a validator.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationError {
+    fields: IndexMap<String, String>,
+}
+
+impl ValidationError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shorthand for a single failing field.
+    pub fn field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new().with_field(field, message)
+    }
+
+    /// Record a message for `field` (replacing an earlier one).
+    pub fn add_field(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.fields.insert(field.into(), message.into());
+    }
+
+    /// Builder form of [`Self::add_field`].
+    pub fn with_field(mut self, field: impl Into<String>, message: impl Into<String>) -> Self {
+        self.add_field(field, message);
+        self
+    }
+
+    /// Failing fields and their messages, in the order they were added.
+    pub fn fields(&self) -> &IndexMap<String, String> {
+        &self.fields
+    }
+
+    /// No field failed — handy for validators that collect messages and
+    /// only reject when something was added.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Find the `ValidationError` behind a write error, if validation is
+    /// what rejected it.
+    pub fn from_error(err: &VantageError) -> Option<&ValidationError> {
+        std::error::Error::source(err)?.downcast_ref::<ValidationError>()
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (field, message)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", field, message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl<T: TableSource, E: Entity<T::Value>> Table<T, E> {
+    /// Validate records before they are written. Runs on insert, replace and
+    /// patch; a patch is validated merged into the stored record, so a check
+    /// on one field doesn't reject a patch that leaves it alone. Returning
+    /// `Err` aborts the write and propagates as an error.
+    ///
+    /// Can be called more than once — every registered validator runs.
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&Record<T::Value>) -> std::result::Result<(), ValidationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.hooks.validators.push(Arc::new(validator));
+    }
+
+    /// Builder form of [`Self::set_validator`].
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&Record<T::Value>) -> std::result::Result<(), ValidationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_validator(validator);
+        self
+    }
+
+    pub(crate) fn has_validators(&self) -> bool {
+        !self.hooks.validators.is_empty()
+    }
+
+    /// Run every registered validator on `record`; the first rejection
+    /// becomes the write error.
+    pub(crate) fn validate(&self, record: &Record<T::Value>) -> Result<()> {
+        for validator in &self.hooks.validators {
+            validator(record).map_err(into_write_error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wrap a validation failure as the write error: field messages go into the
+/// context map, the `ValidationError` itself becomes the source.
+fn into_write_error(validation: ValidationError) -> VantageError {
+    let mut err = error!("Record failed validation");
+    for (field, message) in validation.fields() {
+        err.context.insert(field.clone(), message.clone());
+    }
+    Err::<(), _>(validation).context(err).unwrap_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::mock_table_source::MockTableSource;
+    use serde_json::{Value, json};
+    use vantage_dataset::prelude::{ReadableValueSet, WritableValueSet};
+    use vantage_types::EmptyEntity;
+
+    type MockTable = Table<MockTableSource, EmptyEntity>;
+
+    fn require_name(rec: &Record<Value>) -> std::result::Result<(), ValidationError> {
+        match rec.get("name").and_then(Value::as_str) {
+            Some(name) if !name.trim().is_empty() => Ok(()),
+            _ => Err(ValidationError::field("name", "must not be empty")),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_name_on_insert() {
+        let src = MockTableSource::new().with_data("t", vec![]).await;
+        let table = MockTable::new("t", src).with_validator(require_name);
+
+        let err = table
+            .insert_value("1", &Record::from(json!({"name": "  "})))
+            .await
+            .unwrap_err();
+
+        let validation = ValidationError::from_error(&err).expect("validation source");
+        assert_eq!(
+            validation.fields().get("name").map(String::as_str),
+            Some("must not be empty")
+        );
+        assert_eq!(
+            err.context.get("name").map(String::as_str),
+            Some("must not be empty")
+        );
+        // Nothing reached the backend
+        assert!(table.get_value("1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn valid_record_reaches_backend() {
+        let src = MockTableSource::new().with_data("t", vec![]).await;
+        let table = MockTable::new("t", src).with_validator(require_name);
+
+        table
+            .insert_value("1", &Record::from(json!({"name": "Alice"})))
+            .await
+            .unwrap();
+        let row = table.get_value("1").await.unwrap().unwrap();
+        assert_eq!(row["name"], json!("Alice"));
+    }
+
+    #[tokio::test]
+    async fn runs_on_replace() {
+        let src = MockTableSource::new()
+            .with_data("t", vec![json!({"id": "1", "name": "Alice"})])
+            .await;
+        let table = MockTable::new("t", src).with_validator(require_name);
+
+        let err = table
+            .replace_value("1", &Record::from(json!({"name": ""})))
+            .await
+            .unwrap_err();
+        assert!(ValidationError::from_error(&err).is_some());
+        let row = table.get_value("1").await.unwrap().unwrap();
+        assert_eq!(row["name"], json!("Alice"));
+    }
+
+    #[tokio::test]
+    async fn patch_is_validated_against_stored_record() {
+        let src = MockTableSource::new()
+            .with_data("t", vec![json!({"id": "1", "name": "Alice", "age": 30})])
+            .await;
+        let table = MockTable::new("t", src).with_validator(require_name);
+
+        // "name" isn't in the patch, but the stored row has one
+        table
+            .patch_value("1", &Record::from(json!({"age": 31})))
+            .await
+            .unwrap();
+        let row = table.get_value("1").await.unwrap().unwrap();
+        assert_eq!(row["age"], json!(31));
+
+        // Clearing it is still rejected
+        let err = table
+            .patch_value("1", &Record::from(json!({"name": ""})))
+            .await
+            .unwrap_err();
+        assert!(ValidationError::from_error(&err).is_some());
+        let row = table.get_value("1").await.unwrap().unwrap();
+        assert_eq!(row["name"], json!("Alice"));
+    }
+}
@@ -13,7 +13,9 @@ use vantage_expressions::{
 };
 use vantage_types::{Entity, Record};
 
-use crate::{column::core::ColumnType, table::Table, traits::column_like::ColumnLike};
+use crate::{
+    aggregate::AggExpr, column::core::ColumnType, table::Table, traits::column_like::ColumnLike,
+};
 
 /// Trait for table data sources that defines column type separate from execution
 /// TableSource represents a data source that can create and manage tables
@@ -67,6 +69,44 @@ pub trait TableSource: DataSource + Clone + 'static {
         ))
     }
 
+    /// Build a `field IN (values…)` condition with typed `Self::Value`s.
+    ///
+    /// Used by [`Table::prefetch_related`] to fetch the children of many
+    /// parents in one query. The default returns an error so existing impls
+    /// compile.
+    fn in_values_condition(
+        &self,
+        field: &str,
+        values: Vec<Self::Value>,
+    ) -> Result<Self::Condition> {
+        let _ = (field, values);
+        Err(vantage_core::error!(
+            "in_values_condition not implemented for this TableSource"
+        ))
+    }
+
+    /// Build a `field > value` condition with a typed `Self::Value`.
+    ///
+    /// Used for keyset pagination ([`Table::paginate_keyset`]). Backends that
+    /// support it override; the default returns an error so existing impls
+    /// compile.
+    fn gt_value_condition(&self, field: &str, value: Self::Value) -> Result<Self::Condition> {
+        let _ = (field, value);
+        Err(vantage_core::error!(
+            "gt_value_condition not implemented for this TableSource"
+        ))
+    }
+
+    /// Build the expression that sorts by a plain `field`, for use in an
+    /// [`OrderBy`](crate::sorting::OrderBy). Same default as
+    /// [`TableSource::gt_value_condition`].
+    fn sort_field_expr(&self, field: &str) -> Result<Self::Condition> {
+        let _ = field;
+        Err(vantage_core::error!(
+            "sort_field_expr not implemented for this TableSource"
+        ))
+    }
+
     /// Coerce a reference join value into the backend's native id form.
     ///
     /// Reference traversal (`resolve_from_row`) reads the join value raw out
@@ -162,6 +202,25 @@ pub trait TableSource: DataSource + Clone + 'static {
         E: Entity<Self::Value>,
         Self: Sized;
 
+    /// The table's current page of rows together with the count of all
+    /// rows its conditions match, pagination ignored. Backends that can
+    /// answer both in one round-trip override this; the default makes the
+    /// two calls.
+    async fn list_table_values_with_count<E>(
+        &self,
+        table: &Table<Self, E>,
+    ) -> Result<(IndexMap<Self::Id, Record<Self::Value>>, i64)>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let rows = self.list_table_values(table).await?;
+        let mut unpaged = table.clone();
+        unpaged.set_pagination(None);
+        let count = self.get_table_count(&unpaged).await?;
+        Ok((rows, count))
+    }
+
     /// Get sum of a column in the table (returns native value type)
     async fn get_table_sum<E>(
         &self,
@@ -192,6 +251,152 @@ pub trait TableSource: DataSource + Clone + 'static {
         E: Entity<Self::Value>,
         Self: Sized;
 
+    /// Get average value of a column in the table (returns native value type).
+    ///
+    /// Backends with a native average (`AVG` in SQL, `math::mean` in
+    /// SurrealDB) override; the default returns an error so existing impls
+    /// compile.
+    async fn get_table_avg<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &Self::Column<Self::AnyType>,
+    ) -> Result<Self::Value>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, column);
+        Err(vantage_core::error!(
+            "get_table_avg not implemented for this TableSource"
+        ))
+    }
+
+    /// Count records per distinct value of `column`, in the order the backend
+    /// returns the groups. The default returns an error so existing impls
+    /// compile.
+    async fn get_table_count_by<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<(Self::Value, i64)>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, column);
+        Err(vantage_core::error!(
+            "get_table_count_by not implemented for this TableSource"
+        ))
+    }
+
+    /// Number of distinct values of `column` across the table's rows. The
+    /// default returns an error so existing impls compile.
+    async fn get_table_count_distinct<E>(&self, table: &Table<Self, E>, column: &str) -> Result<i64>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, column);
+        Err(vantage_core::error!(
+            "get_table_count_distinct not implemented for this TableSource"
+        ))
+    }
+
+    /// Fetch one record by its id written as a string — a record key for
+    /// SurrealDB rather than a full record id. `Ok(None)` when absent. The
+    /// default returns an error so existing impls compile.
+    async fn find_table_value_by_id<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+    ) -> Result<Option<Record<Self::Value>>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, id);
+        Err(vantage_core::error!(
+            "find_table_value_by_id not implemented for this TableSource"
+        ))
+    }
+
+    /// Write `record` under the id written as a string (as in
+    /// [`TableSource::find_table_value_by_id`]), creating the row if it is
+    /// missing and replacing it otherwise, and report which happened. Used by
+    /// [`Table::upsert`]; the default returns an error so existing impls
+    /// compile.
+    async fn upsert_table_value<E>(
+        &self,
+        table: &Table<Self, E>,
+        id: &str,
+        record: &Record<Self::Value>,
+    ) -> Result<crate::table::UpsertOutcome>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, id, record);
+        Err(vantage_core::error!(
+            "upsert_table_value not implemented for this TableSource"
+        ))
+    }
+
+    /// Columns the backend currently knows for this table, as type-erased
+    /// columns without flags. Used by
+    /// [`Table::refresh_columns`](crate::table::Table::refresh_columns); the
+    /// default returns an error so existing impls compile.
+    async fn describe_table_columns<E>(
+        &self,
+        table: &Table<Self, E>,
+    ) -> Result<Vec<Self::Column<Self::AnyType>>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = table;
+        Err(vantage_core::error!(
+            "describe_table_columns not implemented for this TableSource"
+        ))
+    }
+
+    /// Distinct values of `column` across the table's rows, duplicates
+    /// removed and first-seen order kept. The default returns an error so
+    /// existing impls compile.
+    async fn get_table_distinct_values<E>(
+        &self,
+        table: &Table<Self, E>,
+        column: &str,
+    ) -> Result<Vec<Self::Value>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, column);
+        Err(vantage_core::error!(
+            "get_table_distinct_values not implemented for this TableSource"
+        ))
+    }
+
+    /// One row per distinct combination of the `group` columns, holding
+    /// those columns plus each aggregate under its alias. Groups keep the
+    /// order the backend returns them in. The default returns an error so
+    /// existing impls compile.
+    async fn get_table_report<E>(
+        &self,
+        table: &Table<Self, E>,
+        group: &[&str],
+        aggregates: &[(&str, AggExpr)],
+    ) -> Result<Vec<Record<Self::Value>>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let _ = (table, group, aggregates);
+        Err(vantage_core::error!(
+            "get_table_report not implemented for this TableSource"
+        ))
+    }
+
     /// Insert a record as Record value (for WritableValueSet implementation)
     async fn insert_table_value<E>(
         &self,
@@ -237,6 +442,31 @@ pub trait TableSource: DataSource + Clone + 'static {
         E: Entity<Self::Value>,
         Self: Sized;
 
+    /// Delete the records with these IDs and return how many existed (for
+    /// `Table::delete_by_ids`).
+    ///
+    /// Default implementation looks each record up and deletes it on its own.
+    /// Backends that can match a list of IDs (SurrealDB
+    /// `DELETE t WHERE id IN [...]`) override this to send a single statement.
+    async fn delete_table_values_by_ids<E>(
+        &self,
+        table: &Table<Self, E>,
+        ids: &[Self::Id],
+    ) -> Result<u64>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let mut deleted = 0;
+        for id in ids {
+            if self.get_table_value(table, id).await?.is_some() {
+                self.delete_table_value(table, id).await?;
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
     /// Insert a record and return generated ID (for InsertableValueSet implementation)
     async fn insert_table_return_id_value<E>(
         &self,
@@ -247,6 +477,28 @@ pub trait TableSource: DataSource + Clone + 'static {
         E: Entity<Self::Value>,
         Self: Sized;
 
+    /// Insert several records and return their IDs in input order (for
+    /// `Table::insert_many`).
+    ///
+    /// Default implementation inserts one record at a time. Backends with a
+    /// multi-row insert (SurrealDB `INSERT INTO t [...]`) override this to
+    /// send a single statement.
+    async fn insert_table_return_id_values<E>(
+        &self,
+        table: &Table<Self, E>,
+        records: &[Record<Self::Value>],
+    ) -> Result<Vec<Self::Id>>
+    where
+        E: Entity<Self::Value>,
+        Self: Sized,
+    {
+        let mut ids = Vec::with_capacity(records.len());
+        for record in records {
+            ids.push(self.insert_table_return_id_value(table, record).await?);
+        }
+        Ok(ids)
+    }
+
     /// Stream all records from a table as (Id, Record) pairs.
     ///
     /// Default implementation wraps `list_table_values` into a stream.
@@ -323,6 +575,15 @@ pub trait TableSource: DataSource + Clone + 'static {
         false
     }
 
+    /// Whether this backend implements
+    /// [`SelectableDataSource::add_select_join`](vantage_expressions::SelectableDataSource::add_select_join).
+    /// Backends without joins (e.g. MongoDB, GraphQL) leave the default
+    /// `false`, and [`Table::join`](crate::table::Table::join) rejects the
+    /// join when it is recorded instead of panicking on every read.
+    fn supports_joins(&self) -> bool {
+        false
+    }
+
     /// Build a backend-native path expression for a dotted active column,
     /// tried before the generic correlated-subquery chain.
     ///
@@ -339,6 +600,19 @@ pub trait TableSource: DataSource + Clone + 'static {
         None
     }
 
+    /// `qualifier.column` for projecting a column of an aliased table (see
+    /// [`Table::with_table_alias`](crate::table::Table::with_table_alias)).
+    /// The default returns `None` and columns stay unqualified; SQL backends
+    /// override it with a quoted, dotted identifier.
+    fn qualified_column_expr(
+        &self,
+        qualifier: &str,
+        column: &str,
+    ) -> Option<Expression<Self::Value>> {
+        let _ = (qualifier, column);
+        None
+    }
+
     /// Return an associated expression that, when resolved, yields all values
     /// of the given typed column from this table (respecting current conditions).
     ///
@@ -152,3 +152,65 @@ pub fn entity(args: TokenStream, input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// One associated function per named field, each returning a
+/// `vantage_types::ColumnRef<Self>` with the field's name. A field marked
+/// `#[id]`, or else one named `id`, also gets `vantage_types::IdentifiedEntity`.
+#[proc_macro_derive(Columns, attributes(id))]
+pub fn columns(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("Columns only supports structs with named fields"),
+        },
+        _ => panic!("Columns only supports structs"),
+    };
+
+    let accessors = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_name_str = field_name.to_string();
+        quote! {
+            pub const fn #field_name() -> vantage_types::ColumnRef<Self> {
+                vantage_types::ColumnRef::new(#field_name_str)
+            }
+        }
+    });
+
+    let id_field = fields
+        .iter()
+        .find(|f| f.attrs.iter().any(|a| a.path().is_ident("id")))
+        .or_else(|| fields.iter().find(|f| f.ident.as_ref().unwrap() == "id"));
+
+    let id_impl = id_field.map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_name_str = field_name.to_string();
+        let value = if is_option_type(&field.ty) {
+            quote! { self.#field_name.as_ref().map(|v| v.to_string()) }
+        } else {
+            quote! { Some(self.#field_name.to_string()) }
+        };
+        quote! {
+            impl #impl_generics vantage_types::IdentifiedEntity for #name #ty_generics #where_clause {
+                const ID_FIELD: &'static str = #field_name_str;
+
+                fn entity_id(&self) -> Option<String> {
+                    #value
+                }
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#accessors)*
+        }
+
+        #id_impl
+    };
+
+    TokenStream::from(expanded)
+}
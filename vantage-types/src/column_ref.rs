@@ -0,0 +1,104 @@
+//! Typed column references
+//!
+//! `#[derive(Columns)]` gives an entity one associated function per field,
+//! each returning a [`ColumnRef`] tied to that entity. Table methods that take
+//! a `ColumnRef<E>` then only accept columns of their own entity, and a typo
+//! in a field name is a compile error rather than a runtime one:
+//!
+//! ```rust
+//! use vantage_types::{ColumnRef, Columns};
+//!
+//! #[derive(Columns)]
+//! struct Client {
+//!     name: String,
+//!     email: String,
+//! }
+//!
+//! let column: ColumnRef<Client> = Client::email();
+//! assert_eq!(column.name(), "email");
+//! ```
+//!
+//! Referencing a field the entity doesn't have does not compile:
+//!
+//! ```rust,compile_fail
+//! use vantage_types::Columns;
+//!
+//! #[derive(Columns)]
+//! struct Client {
+//!     name: String,
+//! }
+//!
+//! let column = Client::emial();
+//! ```
+//!
+//! Dynamic tables keep using the string-based APIs.
+//!
+//! When the entity has a field named `id`, or one marked `#[id]`, the derive
+//! also implements [`IdentifiedEntity`] for it.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Name of a column of entity `E`, see the [module docs](self).
+pub struct ColumnRef<E> {
+    name: &'static str,
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> ColumnRef<E> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// Manual impls: deriving would require `E: Clone` etc.
+impl<E> Clone for ColumnRef<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for ColumnRef<E> {}
+
+impl<E> PartialEq for ColumnRef<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<E> Eq for ColumnRef<E> {}
+
+impl<E> fmt::Debug for ColumnRef<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ColumnRef").field(&self.name).finish()
+    }
+}
+
+impl<E> fmt::Display for ColumnRef<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<E> AsRef<str> for ColumnRef<E> {
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+/// An entity that carries its own id, see the [module docs](self).
+pub trait IdentifiedEntity {
+    /// Name of the id field.
+    const ID_FIELD: &'static str;
+
+    /// The id in its string form, as taken by `Table::find_by_id`. `None`
+    /// when an optional id is unset.
+    fn entity_id(&self) -> Option<String>;
+}
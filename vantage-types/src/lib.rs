@@ -1,9 +1,10 @@
 // Re-export proc-macros from entity subcrate
-pub use vantage_types_entity::entity;
+pub use vantage_types_entity::{Columns, entity};
 
 // Include type_system module with regular macros
 #[cfg(feature = "serde")]
 pub mod cbor_json;
+pub mod column_ref;
 pub mod null;
 pub mod prelude;
 pub mod record;
@@ -15,6 +16,7 @@ pub use cbor_json::{
     CborDialect, PlainDialect, PresentationDialect, cbor_to_json, cbor_to_string, json_to_cbor,
     json_to_cbor_with_hint, rfc3339_to_tag12, tag12_to_rfc3339,
 };
+pub use column_ref::{ColumnRef, IdentifiedEntity};
 pub use null::InvariantValue;
 pub use record::{IntoRecord, Record, TryFromRecord, TryIntoRecord};
 pub use terminal_render::{RichText, Span, Style, TerminalRender};
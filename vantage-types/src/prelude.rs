@@ -8,6 +8,7 @@
 //! ```
 
 // Re-export core types
+pub use crate::column_ref::{ColumnRef, IdentifiedEntity};
 pub use crate::null::InvariantValue;
 pub use crate::record::{IntoRecord, Record, TryFromRecord, TryIntoRecord};
 pub use crate::terminal_render::{RichText, Span, Style, TerminalRender};
@@ -16,7 +17,7 @@ pub use crate::terminal_render::{RichText, Span, Style, TerminalRender};
 pub use crate::vantage_type_system;
 
 // Re-export proc-macros
-pub use vantage_types_entity::entity;
+pub use vantage_types_entity::{Columns, entity};
 
 #[cfg(feature = "serde")]
 pub use crate::Entity;
@@ -1,183 +1,152 @@
-use crate::{CellValue, DataSet, TableStore};
-use egui_data_table::{DataTable, RowViewer};
-use std::cell::RefCell;
-use std::sync::Arc;
-
-/// A row reference for egui-data-table - just contains the row index
-#[derive(Debug, Clone)]
-pub struct EguiTableRow {
-    pub index: usize,
-    pub data: Vec<CellValue>,
+use crate::header_state::{ColumnWidths, SortState};
+use crate::{DataSet, TableRow, TableStore};
+use egui_extras::{Column, TableBuilder};
+use std::sync::{mpsc, Arc};
+
+/// Load every row from the store, in the dataset's current order
+async fn load_rows<D: DataSet>(store: &TableStore<D>) -> Vec<TableRow> {
+    let mut rows = Vec::new();
+    if let Ok(row_count) = store.row_count().await {
+        let _ = store.prefetch_range(0, row_count).await;
+        for i in 0..row_count {
+            if let Ok(row) = store.get_row(i).await {
+                rows.push(row);
+            }
+        }
+    }
+    rows
 }
 
-/// egui adapter that implements RowViewer trait
-pub struct EguiTableViewer<D: DataSet> {
+/// egui table with clickable, sortable headers and resizable columns.
+///
+/// Clicking a header calls [`DataSet::sort_by`] (via the store) on the tokio
+/// runtime `new` was called from and swaps in the re-ordered rows once they
+/// arrive. Column widths start content-fit and are remembered in
+/// [`ColumnWidths`] across frames.
+pub struct EguiTable<D: DataSet> {
     store: Arc<TableStore<D>>,
-    cached_data: RefCell<Option<Vec<Vec<CellValue>>>>,
-    cached_columns: RefCell<Option<Vec<String>>>,
+    runtime: tokio::runtime::Handle,
+    columns: Vec<String>,
+    rows: Vec<TableRow>,
+    sort: SortState,
+    widths: ColumnWidths,
+    pending: Option<mpsc::Receiver<Vec<TableRow>>>,
 }
 
-impl<D: DataSet + 'static> EguiTableViewer<D> {
+impl<D: DataSet + 'static> EguiTable<D> {
     pub async fn new(store: TableStore<D>) -> Self {
-        let viewer = Self {
+        let columns = store
+            .column_info()
+            .await
+            .map(|info| info.into_iter().map(|col| col.name).collect())
+            .unwrap_or_default();
+        let rows = load_rows(&store).await;
+
+        Self {
             store: Arc::new(store),
-            cached_data: RefCell::new(None),
-            cached_columns: RefCell::new(None),
-        };
-
-        viewer.load_data().await;
-        viewer
-    }
-
-    async fn load_data(&self) {
-        // Load column names
-        if let Ok(column_info) = self.store.column_info().await {
-            if let Ok(mut columns) = self.cached_columns.try_borrow_mut() {
-                *columns = Some(column_info.into_iter().map(|col| col.name).collect());
-            }
-        }
-
-        // Load row data
-        if let Ok(row_count) = self.store.row_count().await {
-            let _ = self.store.prefetch_range(0, row_count).await;
-
-            let mut rows = Vec::new();
-            for i in 0..row_count {
-                if let Ok(table_row) = self.store.get_row(i).await {
-                    rows.push(table_row);
-                }
-            }
-
-            if let Ok(mut data) = self.cached_data.try_borrow_mut() {
-                *data = Some(rows);
-            }
-        }
-    }
-
-    fn ensure_data_loaded(&self) {
-        // Initialize empty data structures if not loaded
-        if let Ok(mut columns) = self.cached_columns.try_borrow_mut() {
-            if columns.is_none() {
-                *columns = Some(vec![]);
-            }
-        }
-
-        if let Ok(mut data) = self.cached_data.try_borrow_mut() {
-            if data.is_none() {
-                *data = Some(vec![]);
-            }
+            runtime: tokio::runtime::Handle::current(),
+            columns,
+            rows,
+            sort: SortState::default(),
+            widths: ColumnWidths::default(),
+            pending: None,
         }
     }
-}
 
-impl<D: DataSet + 'static> RowViewer<EguiTableRow> for EguiTableViewer<D> {
-    fn num_columns(&mut self) -> usize {
-        self.ensure_data_loaded();
-        if let Ok(columns) = self.cached_columns.try_borrow() {
-            if let Some(ref cols) = *columns {
-                return cols.len();
-            }
-        }
-        0
+    /// Current header sort state
+    pub fn sort_state(&self) -> &SortState {
+        &self.sort
     }
 
-    fn show_cell_view(&mut self, ui: &mut egui::Ui, row: &EguiTableRow, column: usize) {
-        if let Some(cell_value) = row.data.get(column) {
-            ui.label(cell_value.as_string());
-        } else {
-            ui.label("N/A");
-        }
+    /// Widths columns were last rendered at
+    pub fn column_widths(&self) -> &ColumnWidths {
+        &self.widths
     }
 
-    fn show_cell_editor(
-        &mut self,
-        ui: &mut egui::Ui,
-        row: &mut EguiTableRow,
-        column: usize,
-    ) -> Option<egui::Response> {
-        if let Some(cell_value) = row.data.get_mut(column) {
-            let mut text = cell_value.as_string();
-            let response = ui.text_edit_singleline(&mut text);
-
-            if response.lost_focus() {
-                *cell_value = CellValue::String(text);
-            }
-
-            Some(response)
-        } else {
-            None
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        self.poll_pending(ui.ctx());
+
+        let mut table = TableBuilder::new(ui).striped(true).resizable(true);
+        for col in 0..self.columns.len() {
+            let column = match self.widths.get(col) {
+                Some(width) => Column::initial(width),
+                None => Column::auto(),
+            };
+            table = table.column(column.at_least(30.0).resizable(true));
         }
-    }
 
-    fn set_cell_value(&mut self, src: &EguiTableRow, dst: &mut EguiTableRow, column: usize) {
-        if let (Some(src_cell), Some(dst_cell)) = (src.data.get(column), dst.data.get_mut(column)) {
-            *dst_cell = src_cell.clone();
+        let columns = &self.columns;
+        let rows = &self.rows;
+        let sort = &self.sort;
+        let widths = &mut self.widths;
+        let mut clicked = None;
+
+        table
+            .header(20.0, |mut header| {
+                for (i, name) in columns.iter().enumerate() {
+                    let (rect, _) = header.col(|ui| {
+                        let label = format!("{}{}", name, sort.indicator(i));
+                        if ui.button(label).clicked() {
+                            clicked = Some(i);
+                        }
+                    });
+                    widths.record(i, rect.width());
+                }
+            })
+            .body(|body| {
+                body.rows(18.0, rows.len(), |mut row| {
+                    let data = &rows[row.index()];
+                    for i in 0..columns.len() {
+                        row.col(|ui| {
+                            match data.get(i) {
+                                Some(cell) => ui.label(cell.as_string()),
+                                None => ui.label("N/A"),
+                            };
+                        });
+                    }
+                });
+            });
+
+        if let Some(column) = clicked {
+            let ascending = self.sort.toggle(column);
+            self.spawn_reload(Some((column, ascending)));
         }
-    }
 
-    fn new_empty_row(&mut self) -> EguiTableRow {
-        // Get column count from cached columns or use empty row
-        let column_count = if let Ok(columns) = self.cached_columns.try_borrow() {
-            if let Some(ref cols) = *columns {
-                cols.len()
-            } else {
-                0
-            }
-        } else {
-            0
-        };
-
-        EguiTableRow {
-            index: 0,
-            data: vec![CellValue::String(String::new()); column_count],
-        }
-    }
+        ui.add_space(10.0);
 
-    fn clone_row(&mut self, src: &EguiTableRow) -> EguiTableRow {
-        EguiTableRow {
-            index: src.index,
-            data: src.data.clone(),
+        if ui.button("Refresh Data").clicked() {
+            self.store.clear_cache();
+            self.spawn_reload(None);
         }
     }
-}
 
-/// Wrapper that creates the egui DataTable with our adapter
-pub struct EguiTable<D: DataSet> {
-    data_table: DataTable<EguiTableRow>,
-    viewer: EguiTableViewer<D>,
-}
-
-impl<D: DataSet + 'static> EguiTable<D> {
-    pub async fn new(store: TableStore<D>) -> Self {
-        let viewer = EguiTableViewer::new(store).await;
-        let mut data_table = DataTable::new();
-
-        // Load actual data from the viewer
-        if let Ok(data) = viewer.cached_data.try_borrow() {
-            if let Some(ref data) = *data {
-                for (index, row_data) in data.iter().enumerate() {
-                    data_table.push(EguiTableRow {
-                        index,
-                        data: row_data.clone(),
-                    });
+    /// Re-read rows in the background, optionally sorting first. The result
+    /// is picked up by `show` on a later frame.
+    fn spawn_reload(&mut self, sort: Option<(usize, bool)>) {
+        let store = self.store.clone();
+        let (tx, rx) = mpsc::channel();
+        self.runtime.spawn(async move {
+            if let Some((column, ascending)) = sort {
+                if store.sort_by(column, ascending).await.is_err() {
+                    return;
                 }
             }
-        }
-
-        Self { data_table, viewer }
+            let _ = tx.send(load_rows(&store).await);
+        });
+        self.pending = Some(rx);
     }
 
-    pub fn show(&mut self, ui: &mut egui::Ui) {
-        ui.add(egui_data_table::Renderer::new(
-            &mut self.data_table,
-            &mut self.viewer,
-        ));
-
-        ui.add_space(10.0);
-
-        if ui.button("Refresh Data").clicked() {
-            // Note: In a real app, you'd want to trigger async reload here
-            // For now, just show current data
+    fn poll_pending(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.pending else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(rows) => {
+                self.rows = rows;
+                self.pending = None;
+            }
+            Err(mpsc::TryRecvError::Empty) => ctx.request_repaint(),
+            Err(mpsc::TryRecvError::Disconnected) => self.pending = None,
         }
     }
 }
@@ -185,8 +154,10 @@ impl<D: DataSet + 'static> EguiTable<D> {
 impl<D: DataSet> std::fmt::Debug for EguiTable<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EguiTable")
-            .field("data_table", &"<DataTable>")
-            .field("viewer", &"<EguiTableViewer>")
+            .field("columns", &self.columns)
+            .field("rows", &self.rows.len())
+            .field("sort", &self.sort)
+            .field("widths", &self.widths)
             .finish()
     }
 }
@@ -0,0 +1,112 @@
+//! Column header state shared across frames: which column is sorted (and
+//! which way), and the width each column was last rendered at.
+//!
+//! Kept free of any UI framework so immediate-mode adapters (egui) can hold
+//! it between frames and the toggle logic can be tested on its own.
+
+/// Current sort column and direction. Clicking a new column sorts it
+/// ascending; clicking the sorted column again flips the direction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SortState {
+    column: Option<usize>,
+    ascending: bool,
+}
+
+impl SortState {
+    /// Register a header click. Returns the direction to sort in
+    /// (`true` = ascending).
+    pub fn toggle(&mut self, column: usize) -> bool {
+        if self.column == Some(column) {
+            self.ascending = !self.ascending;
+        } else {
+            self.column = Some(column);
+            self.ascending = true;
+        }
+        self.ascending
+    }
+
+    /// Sorted column and direction, if any.
+    pub fn current(&self) -> Option<(usize, bool)> {
+        self.column.map(|column| (column, self.ascending))
+    }
+
+    /// Suffix for a header label: ` ▲` / ` ▼` on the sorted column, empty
+    /// elsewhere.
+    pub fn indicator(&self, column: usize) -> &'static str {
+        match self.current() {
+            Some((c, true)) if c == column => " ▲",
+            Some((c, false)) if c == column => " ▼",
+            _ => "",
+        }
+    }
+}
+
+/// Per-column widths remembered across frames. A column without a recorded
+/// width should be rendered content-fit; once rendered (or dragged), its
+/// width is recorded and reused.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnWidths {
+    widths: Vec<Option<f32>>,
+}
+
+impl ColumnWidths {
+    /// Last recorded width, or `None` if the column hasn't been rendered yet.
+    pub fn get(&self, column: usize) -> Option<f32> {
+        self.widths.get(column).copied().flatten()
+    }
+
+    /// Record the width a column was rendered at.
+    pub fn record(&mut self, column: usize, width: f32) {
+        if self.widths.len() <= column {
+            self.widths.resize(column + 1, None);
+        }
+        self.widths[column] = Some(width);
+    }
+
+    /// Forget all widths, returning every column to content-fit.
+    pub fn reset(&mut self) {
+        self.widths.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_toggle() {
+        let mut sort = SortState::default();
+        assert_eq!(sort.current(), None);
+        assert_eq!(sort.indicator(0), "");
+
+        assert!(sort.toggle(1));
+        assert_eq!(sort.current(), Some((1, true)));
+        assert_eq!(sort.indicator(1), " ▲");
+        assert_eq!(sort.indicator(0), "");
+
+        assert!(!sort.toggle(1));
+        assert_eq!(sort.indicator(1), " ▼");
+
+        // Switching columns starts ascending again
+        assert!(sort.toggle(0));
+        assert_eq!(sort.current(), Some((0, true)));
+        assert_eq!(sort.indicator(1), "");
+    }
+
+    #[test]
+    fn test_widths_persist() {
+        let mut widths = ColumnWidths::default();
+        assert_eq!(widths.get(2), None);
+
+        widths.record(2, 120.0);
+        assert_eq!(widths.get(0), None);
+        assert_eq!(widths.get(2), Some(120.0));
+
+        // A later frame (e.g. after a drag) overwrites the stored width
+        widths.record(2, 80.5);
+        assert_eq!(widths.get(2), Some(80.5));
+
+        widths.reset();
+        assert_eq!(widths.get(2), None);
+    }
+}
@@ -3,11 +3,14 @@ use ciborium::Value as CborValue;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use thiserror::Error;
+use vantage_dataset::prelude::{ReadableValueSet, WritableValueSet};
 use vantage_types::Record;
 use vantage_vista::Vista;
 
+use value_set_adapter::record_to_row;
+
 #[derive(Error, Debug)]
 pub enum TableStoreError {
     #[error("Data fetch failed: {0}")]
@@ -21,7 +24,7 @@ pub enum TableStoreError {
 pub type Result<T> = std::result::Result<T, TableStoreError>;
 
 /// Represents a cell value in the table
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CellValue {
     String(String),
     Integer(i64),
@@ -40,15 +43,68 @@ impl CellValue {
             CellValue::Null => "".to_string(),
         }
     }
+
+    /// Ordering used for client-side sorting: nulls first, numbers
+    /// numerically, booleans false-first, everything else by display text.
+    pub fn sort_cmp(&self, other: &CellValue) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        match (self, other) {
+            (CellValue::Null, CellValue::Null) => Ordering::Equal,
+            (CellValue::Null, _) => Ordering::Less,
+            (_, CellValue::Null) => Ordering::Greater,
+            (CellValue::Integer(a), CellValue::Integer(b)) => a.cmp(b),
+            (CellValue::Integer(a), CellValue::Float(b)) => (*a as f64).total_cmp(b),
+            (CellValue::Float(a), CellValue::Integer(b)) => a.total_cmp(&(*b as f64)),
+            (CellValue::Float(a), CellValue::Float(b)) => a.total_cmp(b),
+            (CellValue::Boolean(a), CellValue::Boolean(b)) => a.cmp(b),
+            (a, b) => a.as_string().cmp(&b.as_string()),
+        }
+    }
 }
 
+/// Turns a cell into its display text (e.g. a currency or date column).
+pub type CellFormatter = Arc<dyn Fn(&CellValue) -> String + Send + Sync>;
+
 /// Column metadata
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ColumnInfo {
     pub name: String,
     pub data_type: String,
     pub sortable: bool,
     pub editable: bool,
+    pub formatter: Option<CellFormatter>,
+}
+
+impl ColumnInfo {
+    /// Display cells of this column through `formatter` instead of
+    /// [`CellValue::as_string`].
+    pub fn with_formatter(
+        mut self,
+        formatter: impl Fn(&CellValue) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Display text for a cell of this column.
+    pub fn format(&self, value: &CellValue) -> String {
+        match &self.formatter {
+            Some(formatter) => formatter(value),
+            None => value.as_string(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ColumnInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnInfo")
+            .field("name", &self.name)
+            .field("data_type", &self.data_type)
+            .field("sortable", &self.sortable)
+            .field("editable", &self.editable)
+            .field("formatter", &self.formatter.as_ref().map(|_| "<fn>"))
+            .finish()
+    }
 }
 
 /// A row of data - just a vector of cell values
@@ -80,6 +136,81 @@ pub trait DataSet: Send + Sync {
             "Deletes not supported".to_string(),
         ))
     }
+
+    /// Reorder rows by the column at `column`. Subsequent fetches return
+    /// rows in the new order.
+    async fn sort_by(&self, _column: usize, _ascending: bool) -> Result<()> {
+        Err(TableStoreError::FetchError(
+            "Sorting not supported".to_string(),
+        ))
+    }
+
+    /// Re-read the record with primary key `id` from the backend. Returns
+    /// the row's index and its fresh contents.
+    async fn reload_row(&self, _id: &str) -> Result<(usize, TableRow)> {
+        Err(TableStoreError::FetchError(
+            "Reloading not supported".to_string(),
+        ))
+    }
+}
+
+type RefreshCallback = Box<dyn FnOnce() + Send>;
+
+/// Callbacks of the in-flight background refresh, and of the one queued to
+/// run after it.
+struct RefreshState {
+    waiters: Vec<RefreshCallback>,
+    queued: Option<Vec<RefreshCallback>>,
+}
+
+/// The background refresh state; `None` when no refresh is running.
+#[derive(Default)]
+struct PendingRefresh(Mutex<Option<RefreshState>>);
+
+impl std::fmt::Debug for PendingRefresh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pending = self.0.lock().unwrap();
+        let waiting = pending.as_ref().map(|state| state.waiters.len());
+        let queued = pending
+            .as_ref()
+            .and_then(|state| state.queued.as_ref().map(Vec::len));
+        f.debug_struct("PendingRefresh")
+            .field("waiting", &waiting)
+            .field("queued", &queued)
+            .finish()
+    }
+}
+
+/// Marks the background refresh as finished if its task ends early — a
+/// panic in the fetch or a callback, or the runtime shutting down — so
+/// later refreshes don't wait on it forever.
+struct RefreshGuard(Option<Arc<PendingRefresh>>);
+
+impl RefreshGuard {
+    fn disarm(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        if let Some(pending) = self.0.take() {
+            *pending.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+    }
+}
+
+/// Group row indexes into `(start, end)` runs of consecutive rows.
+fn contiguous_ranges(mut rows: Vec<usize>) -> Vec<(usize, usize)> {
+    rows.sort_unstable();
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for row in rows {
+        match ranges.last_mut() {
+            Some((_, end)) if *end == row => *end += 1,
+            _ => ranges.push((row, row + 1)),
+        }
+    }
+    ranges
 }
 
 /// The intermediate caching layer - "TableStore" instead of "Hydrator"
@@ -89,6 +220,7 @@ pub struct TableStore<D: DataSet> {
     cached_rows: Arc<RwLock<HashMap<usize, TableRow>>>,
     cached_columns: Arc<RwLock<Option<Vec<ColumnInfo>>>>,
     cached_row_count: Arc<RwLock<Option<usize>>>,
+    pending_refresh: Arc<PendingRefresh>,
     page_size: usize,
 }
 
@@ -99,6 +231,7 @@ impl<D: DataSet> TableStore<D> {
             cached_rows: Arc::new(RwLock::new(HashMap::new())),
             cached_columns: Arc::new(RwLock::new(None)),
             cached_row_count: Arc::new(RwLock::new(None)),
+            pending_refresh: Arc::new(PendingRefresh::default()),
             page_size: 100, // Default page size for efficient loading
         }
     }
@@ -191,6 +324,102 @@ impl<D: DataSet> TableStore<D> {
         *count = None;
     }
 
+    /// Re-fetch the row count, columns and the cached (visible) rows on a
+    /// spawned task, then call `on_complete` so the UI can repaint. With
+    /// nothing cached yet, the first page is fetched. A sparse cache is
+    /// re-read range by range, not across the gaps between them.
+    ///
+    /// A call made while a refresh is already running queues one follow-up
+    /// refresh, started when the running one finishes, so `on_complete` sees
+    /// data read after the call; further calls join that follow-up. If the
+    /// fetch fails the previous cache is kept and callbacks still run.
+    pub fn refresh_in_background(&self, on_complete: impl FnOnce() + Send + 'static)
+    where
+        D: 'static,
+    {
+        {
+            let mut pending = self.pending_refresh.0.lock().unwrap();
+            if let Some(state) = pending.as_mut() {
+                state
+                    .queued
+                    .get_or_insert_with(Vec::new)
+                    .push(Box::new(on_complete));
+                return;
+            }
+            *pending = Some(RefreshState {
+                waiters: vec![Box::new(on_complete)],
+                queued: None,
+            });
+        }
+
+        let dataset = self.dataset.clone();
+        let cached_rows = self.cached_rows.clone();
+        let cached_columns = self.cached_columns.clone();
+        let cached_row_count = self.cached_row_count.clone();
+        let pending_refresh = self.pending_refresh.clone();
+        let page_size = self.page_size;
+        tokio::spawn(async move {
+            let mut guard = RefreshGuard(Some(pending_refresh.clone()));
+            loop {
+                let visible: Vec<usize> = cached_rows.read().unwrap().keys().copied().collect();
+                let fetched = async {
+                    let count = dataset.row_count().await?;
+                    let columns = dataset.column_info().await?;
+                    let ranges = if visible.is_empty() {
+                        vec![(0, page_size)]
+                    } else {
+                        contiguous_ranges(visible)
+                    };
+                    let mut rows = Vec::new();
+                    for (start, end) in ranges {
+                        let end = end.min(count);
+                        if start < end {
+                            rows.push((start, dataset.fetch_rows(start, end - start).await?));
+                        }
+                    }
+                    Ok::<_, TableStoreError>((count, columns, rows))
+                }
+                .await;
+
+                if let Ok((count, columns, rows)) = fetched {
+                    *cached_row_count.write().unwrap() = Some(count);
+                    *cached_columns.write().unwrap() = Some(columns);
+                    let mut cached = cached_rows.write().unwrap();
+                    cached.clear();
+                    for (start, rows) in rows {
+                        for (i, row) in rows.into_iter().enumerate() {
+                            cached.insert(start + i, row);
+                        }
+                    }
+                }
+
+                // Hand the queued callbacks to the next round, if any
+                let (waiters, again) = {
+                    let mut pending = pending_refresh.0.lock().unwrap();
+                    let state = pending.take();
+                    let (waiters, queued) = state
+                        .map(|state| (state.waiters, state.queued))
+                        .unwrap_or_default();
+                    let again = queued.is_some();
+                    *pending = queued.map(|waiters| RefreshState {
+                        waiters,
+                        queued: None,
+                    });
+                    (waiters, again)
+                };
+                if !again {
+                    guard.disarm();
+                }
+                for on_complete in waiters {
+                    on_complete();
+                }
+                if !again {
+                    break;
+                }
+            }
+        });
+    }
+
     // Mutation methods that invalidate cache as needed
     pub async fn update_cell(&self, row: usize, col: usize, value: CellValue) -> Result<()> {
         self.dataset.update_cell(row, col, value.clone()).await?;
@@ -207,6 +436,49 @@ impl<D: DataSet> TableStore<D> {
         Ok(())
     }
 
+    /// Like [`Self::update_cell`], but the cached cell changes right away and
+    /// the backend write runs on a spawned task, so the UI need not wait for
+    /// it. If the write fails `on_error` is called, and the cell is put back
+    /// to its previous value unless something (a later edit, a refresh) has
+    /// changed it since. Await the returned handle to know the write has
+    /// settled.
+    pub fn update_cell_optimistic(
+        &self,
+        row: usize,
+        col: usize,
+        value: CellValue,
+        on_error: impl FnOnce(TableStoreError) + Send + 'static,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        D: 'static,
+    {
+        let previous = {
+            let mut cached = self.cached_rows.write().unwrap();
+            cached
+                .get_mut(&row)
+                .and_then(|cached_row| cached_row.get_mut(col))
+                .map(|cell| std::mem::replace(cell, value.clone()))
+        };
+
+        let dataset = self.dataset.clone();
+        let cached_rows = self.cached_rows.clone();
+        tokio::spawn(async move {
+            if let Err(err) = dataset.update_cell(row, col, value.clone()).await {
+                if let Some(previous) = previous {
+                    let mut cached = cached_rows.write().unwrap();
+                    if let Some(cell) = cached
+                        .get_mut(&row)
+                        .and_then(|cached_row| cached_row.get_mut(col))
+                        .filter(|cell| **cell == value)
+                    {
+                        *cell = previous;
+                    }
+                }
+                on_error(err);
+            }
+        })
+    }
+
     pub async fn insert_row(&self, row: TableRow) -> Result<usize> {
         let new_index = self.dataset.insert_row(row).await?;
 
@@ -226,6 +498,29 @@ impl<D: DataSet> TableStore<D> {
         Ok(new_index)
     }
 
+    /// Refresh a single record after a mutation, replacing its cached row in
+    /// place; other cached rows are kept. Returns the row's index.
+    pub async fn reload_row(&self, id: &str) -> Result<usize> {
+        let (index, row) = self.dataset.reload_row(id).await?;
+        {
+            let mut cached = self.cached_rows.write().unwrap();
+            cached.insert(index, row);
+        }
+        Ok(index)
+    }
+
+    pub async fn sort_by(&self, column: usize, ascending: bool) -> Result<()> {
+        self.dataset.sort_by(column, ascending).await?;
+
+        // Row indexes now point at different records
+        {
+            let mut cached = self.cached_rows.write().unwrap();
+            cached.clear();
+        }
+
+        Ok(())
+    }
+
     pub async fn delete_row(&self, index: usize) -> Result<()> {
         self.dataset.delete_row(index).await?;
 
@@ -250,9 +545,17 @@ impl<D: DataSet> TableStore<D> {
 /// the cache. Works with any persistence: build a Vista via the driver's
 /// `vista_factory().from_table(...)` (or `from_yaml(...)`) before passing
 /// it in.
+///
+/// The Vista's id column is fetched and cached, so edits and deletes find
+/// their record, but it is left out of `column_info` and the rows handed to
+/// the UI. Call [`show_id`](Self::show_id) to display it.
 pub struct VantageTableAdapter {
-    cached_data: Vec<TableRow>,
+    vista: Vista,
+    /// Rows paired with their record id, so single rows can be reloaded.
+    cached_data: RwLock<Vec<(String, TableRow)>>,
+    /// Every fetched column, the id column included.
     cached_columns: Vec<ColumnInfo>,
+    hide_id: bool,
 }
 
 impl VantageTableAdapter {
@@ -269,6 +572,7 @@ impl VantageTableAdapter {
                 data_type: "String".to_string(),
                 sortable: true,
                 editable: false,
+                formatter: None,
             })
             .collect();
 
@@ -277,38 +581,82 @@ impl VantageTableAdapter {
             .list_vista_values(&vista)
             .await
             .unwrap_or_default();
-        let cached_data: Vec<TableRow> = records
-            .into_values()
-            .map(|record| {
-                column_names
-                    .iter()
-                    .map(|name| match record.get(name) {
-                        // Vista carries Record<ciborium::Value>; convert to
-                        // serde_json::Value once for the existing cell
-                        // mapper. The presentation dialect renders tagged
-                        // values (record ids, datetimes) as their display
-                        // text instead of nulling them.
-                        Some(value) => {
-                            let json = vantage_types::cbor_to_json(
-                                &vantage_types::PresentationDialect,
-                                value.clone(),
-                            );
-                            json_to_cell(&json)
-                        }
-                        None => CellValue::Null,
-                    })
-                    .collect()
-            })
+        let cached_data: Vec<(String, TableRow)> = records
+            .into_iter()
+            .map(|(id, record)| (id, record_to_row(&column_names, &record)))
             .collect();
 
         Self {
-            cached_data,
+            vista,
+            cached_data: RwLock::new(cached_data),
             cached_columns,
+            hide_id: true,
         }
     }
+
+    /// Display the id column, which is hidden by default.
+    pub fn show_id(mut self) -> Self {
+        self.hide_id = false;
+        self
+    }
+
+    /// Positions in the cached rows of the columns the UI sees.
+    fn visible_columns(&self) -> Vec<usize> {
+        let id_column = self.vista.get_id_column().filter(|_| self.hide_id);
+        self.cached_columns
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| Some(info.name.as_str()) != id_column)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Cached-row position of the UI's column `column`.
+    fn cached_column(&self, column: usize) -> Result<usize> {
+        self.visible_columns()
+            .get(column)
+            .copied()
+            .ok_or(TableStoreError::IndexError)
+    }
+
+    /// Format cells of `column` with `formatter` in the rows handed to the
+    /// UI. Cached rows keep the raw values, so sorting stays typed (a
+    /// currency column still sorts numerically). Unknown columns are ignored.
+    pub fn with_formatter(
+        mut self,
+        column: &str,
+        formatter: impl Fn(&CellValue) -> String + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(info) = self.cached_columns.iter_mut().find(|c| c.name == column) {
+            info.formatter = Some(Arc::new(formatter));
+        }
+        self
+    }
+
+    /// Apply column formatters to a cached row and drop hidden columns;
+    /// unformatted columns pass through unchanged.
+    fn display_row(&self, row: &TableRow) -> TableRow {
+        self.visible_columns()
+            .into_iter()
+            .map(|i| match &self.cached_columns[i].formatter {
+                Some(formatter) => CellValue::String(formatter(&row[i])),
+                None => row[i].clone(),
+            })
+            .collect()
+    }
+}
+
+fn cell_to_cbor(value: CellValue) -> CborValue {
+    match value {
+        CellValue::String(s) => CborValue::Text(s),
+        CellValue::Integer(i) => CborValue::Integer(i.into()),
+        CellValue::Float(f) => CborValue::Float(f),
+        CellValue::Boolean(b) => CborValue::Bool(b),
+        CellValue::Null => CborValue::Null,
+    }
 }
 
-fn json_to_cell(value: &serde_json::Value) -> CellValue {
+pub(crate) fn json_to_cell(value: &serde_json::Value) -> CellValue {
     use serde_json::Value;
     match value {
         Value::Null => CellValue::Null,
@@ -330,29 +678,132 @@ fn json_to_cell(value: &serde_json::Value) -> CellValue {
 #[async_trait]
 impl DataSet for VantageTableAdapter {
     async fn row_count(&self) -> Result<usize> {
-        Ok(self.cached_data.len())
+        Ok(self.cached_data.read().unwrap().len())
     }
 
     async fn column_info(&self) -> Result<Vec<ColumnInfo>> {
-        Ok(self.cached_columns.clone())
+        Ok(self
+            .visible_columns()
+            .into_iter()
+            .map(|i| self.cached_columns[i].clone())
+            .collect())
     }
 
     async fn fetch_rows(&self, start: usize, count: usize) -> Result<Vec<TableRow>> {
-        let end = (start + count).min(self.cached_data.len());
-        if start >= self.cached_data.len() {
+        let data = self.cached_data.read().unwrap();
+        let end = (start + count).min(data.len());
+        if start >= data.len() {
             return Ok(vec![]);
         }
-        Ok(self.cached_data[start..end].to_vec())
+        Ok(data[start..end]
+            .iter()
+            .map(|(_, row)| self.display_row(row))
+            .collect())
     }
 
     async fn fetch_row(&self, index: usize) -> Result<TableRow> {
         self.cached_data
+            .read()
+            .unwrap()
             .get(index)
-            .cloned()
+            .map(|(_, row)| self.display_row(row))
             .ok_or(TableStoreError::IndexError)
     }
+
+    // Edits and deletes address the record by its cached id, whether or not
+    // the id column is displayed
+    async fn update_cell(&self, row: usize, col: usize, value: CellValue) -> Result<()> {
+        let column = self.cached_column(col)?;
+        let id = self
+            .cached_data
+            .read()
+            .unwrap()
+            .get(row)
+            .map(|(id, _)| id.clone())
+            .ok_or(TableStoreError::IndexError)?;
+
+        let mut patch = Record::new();
+        patch.insert(
+            self.cached_columns[column].name.clone(),
+            cell_to_cbor(value.clone()),
+        );
+        self.vista
+            .patch_value(id.clone(), &patch)
+            .await
+            .map_err(|e| TableStoreError::FetchError(e.to_string()))?;
+
+        let mut data = self.cached_data.write().unwrap();
+        if let Some((_, cached)) = data.iter_mut().find(|(row_id, _)| *row_id == id) {
+            cached[column] = value;
+        }
+        Ok(())
+    }
+
+    async fn delete_row(&self, index: usize) -> Result<()> {
+        let id = self
+            .cached_data
+            .read()
+            .unwrap()
+            .get(index)
+            .map(|(id, _)| id.clone())
+            .ok_or(TableStoreError::IndexError)?;
+        self.vista
+            .delete(id.clone())
+            .await
+            .map_err(|e| TableStoreError::FetchError(e.to_string()))?;
+        self.cached_data
+            .write()
+            .unwrap()
+            .retain(|(row_id, _)| *row_id != id);
+        Ok(())
+    }
+
+    // Rows are already cached in full, so sort them in place. A row too
+    // short to have the column sorts last in either direction.
+    async fn sort_by(&self, column: usize, ascending: bool) -> Result<()> {
+        use std::cmp::Ordering;
+
+        let column = self.cached_column(column)?;
+        let mut data = self.cached_data.write().unwrap();
+        data.sort_by(|(_, a), (_, b)| match (a.get(column), b.get(column)) {
+            (Some(a), Some(b)) if ascending => a.sort_cmp(b),
+            (Some(a), Some(b)) => b.sort_cmp(a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        Ok(())
+    }
+
+    async fn reload_row(&self, id: &str) -> Result<(usize, TableRow)> {
+        let record = self
+            .vista
+            .get_value(id)
+            .await
+            .map_err(|e| TableStoreError::FetchError(e.to_string()))?
+            .ok_or_else(|| {
+                TableStoreError::FetchError(format!("Record {} no longer exists", id))
+            })?;
+        let column_names: Vec<String> =
+            self.cached_columns.iter().map(|c| c.name.clone()).collect();
+
+        let mut data = self.cached_data.write().unwrap();
+        let index = data
+            .iter()
+            .position(|(row_id, _)| row_id == id)
+            .ok_or(TableStoreError::IndexError)?;
+        data[index].1 = record_to_row(&column_names, &record);
+        Ok((index, self.display_row(&data[index].1)))
+    }
 }
 
+// Framework-agnostic header state (sort indicator, column widths)
+pub mod header_state;
+
+// Any ReadableValueSet as a DataSet
+pub mod value_set_adapter;
+pub use value_set_adapter::{DataSetValueAdapter, ToCellValue};
+
 // Framework-specific modules (behind feature flags)
 #[cfg(feature = "egui")]
 pub mod egui_adapter;
@@ -374,3 +825,366 @@ pub mod ratatui_dio;
 
 #[cfg(feature = "cursive")]
 pub mod cursive_adapter;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vantage_vista::mocks::MockShell;
+    use vantage_vista::{Column, VistaMetadata};
+
+    fn currency(value: &CellValue) -> String {
+        match value {
+            CellValue::Integer(cents) => format!("${}.{:02}", cents / 100, cents % 100),
+            other => other.as_string(),
+        }
+    }
+
+    async fn products() -> VantageTableAdapter {
+        let metadata = VistaMetadata::new()
+            .with_column(Column::new("name", "String"))
+            .with_column(Column::new("price", "i64"));
+        let mut shell = MockShell::new().with_metadata(metadata);
+        for (id, name, price) in [("1", "Tart", 1999), ("2", "Bun", 250)] {
+            let mut record = Record::new();
+            record.insert("name".to_string(), CborValue::Text(name.into()));
+            record.insert("price".to_string(), CborValue::Integer(price.into()));
+            shell = shell.with_record(id, record);
+        }
+        VantageTableAdapter::new(Vista::new("product", Box::new(shell))).await
+    }
+
+    #[test]
+    fn column_format_falls_back_to_as_string() {
+        let info = ColumnInfo {
+            name: "price".into(),
+            data_type: "i64".into(),
+            sortable: true,
+            editable: false,
+            formatter: None,
+        };
+        assert_eq!(info.format(&CellValue::Integer(1999)), "1999");
+        let info = info.with_formatter(currency);
+        assert_eq!(info.format(&CellValue::Integer(1999)), "$19.99");
+    }
+
+    #[tokio::test]
+    async fn adapter_applies_currency_formatter() {
+        let adapter = products().await.with_formatter("price", currency);
+
+        let row = adapter.fetch_row(0).await.unwrap();
+        assert_eq!(row[0].as_string(), "Tart");
+        assert_eq!(row[1].as_string(), "$19.99");
+
+        // Sorting still sees the raw integers
+        adapter.sort_by(1, true).await.unwrap();
+        let rows = adapter.fetch_rows(0, 2).await.unwrap();
+        assert_eq!(rows[0][1].as_string(), "$2.50");
+        assert_eq!(rows[1][1].as_string(), "$19.99");
+    }
+
+    #[tokio::test]
+    async fn adapter_sorts_short_rows_last() {
+        let adapter = products().await;
+        adapter
+            .cached_data
+            .write()
+            .unwrap()
+            .insert(0, ("3".into(), vec![CellValue::String("Scone".into())]));
+
+        let ids = |adapter: &VantageTableAdapter| -> Vec<String> {
+            let data = adapter.cached_data.read().unwrap();
+            data.iter().map(|(id, _)| id.clone()).collect()
+        };
+        adapter.sort_by(1, true).await.unwrap();
+        assert_eq!(ids(&adapter), ["2", "1", "3"]);
+        adapter.sort_by(1, false).await.unwrap();
+        assert_eq!(ids(&adapter), ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn store_reload_row_replaces_cached_row() {
+        let metadata = VistaMetadata::new()
+            .with_column(Column::new("name", "String"))
+            .with_column(Column::new("price", "i64"));
+        let mut shell = MockShell::new().with_metadata(metadata);
+        for (id, name, price) in [("1", "Tart", 1999), ("2", "Bun", 250)] {
+            let mut record = Record::new();
+            record.insert("name".to_string(), CborValue::Text(name.into()));
+            record.insert("price".to_string(), CborValue::Integer(price.into()));
+            shell = shell.with_record(id, record);
+        }
+        // The shell's storage is shared, so this handle can mutate behind the adapter
+        let backend = shell.clone();
+        let adapter = VantageTableAdapter::new(Vista::new("product", Box::new(shell))).await;
+        let store = TableStore::new(adapter);
+        store.prefetch_range(0, 2).await.unwrap();
+
+        backend.set_field("2", "price", CborValue::Integer(300.into()));
+        backend.set_field("1", "price", CborValue::Integer(1.into()));
+        assert_eq!(store.cell_value(1, 1).await.unwrap().as_string(), "250");
+
+        // Only record "2" is re-read; row 0 keeps its cached value
+        assert_eq!(store.reload_row("2").await.unwrap(), 1);
+        assert_eq!(store.cell_value(1, 1).await.unwrap().as_string(), "300");
+        assert_eq!(store.cell_value(0, 1).await.unwrap().as_string(), "1999");
+
+        assert!(store.reload_row("999").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn adapter_hides_id_but_mutates_by_it() {
+        let metadata = VistaMetadata::new()
+            .with_column(Column::new("id", "String"))
+            .with_column(Column::new("name", "String"))
+            .with_column(Column::new("price", "i64"))
+            .with_id_column("id");
+        let mut shell = MockShell::new().with_metadata(metadata);
+        for (id, name, price) in [("t1", "Tart", 1999), ("b2", "Bun", 250)] {
+            let mut record = Record::new();
+            record.insert("id".to_string(), CborValue::Text(id.into()));
+            record.insert("name".to_string(), CborValue::Text(name.into()));
+            record.insert("price".to_string(), CborValue::Integer(price.into()));
+            shell = shell.with_record(id, record);
+        }
+        let backend = shell.clone();
+        let vista = Vista::new("product", Box::new(shell));
+        let adapter = VantageTableAdapter::new(vista).await;
+
+        let names: Vec<String> = adapter
+            .column_info()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, vec!["name", "price"]);
+        let row = adapter.fetch_row(0).await.unwrap();
+        assert_eq!(row.len(), 2);
+        assert_eq!(row[0].as_string(), "Tart");
+
+        // After sorting, row 0 is "Bun" — the edit must land on its record
+        adapter.sort_by(1, true).await.unwrap();
+        adapter
+            .update_cell(0, 1, CellValue::Integer(300))
+            .await
+            .unwrap();
+        let probe = Vista::new("product", Box::new(backend.clone()));
+        let bun = probe.get_value("b2").await.unwrap().unwrap();
+        assert_eq!(bun["price"], CborValue::Integer(300.into()));
+        assert_eq!(adapter.fetch_row(0).await.unwrap()[1].as_string(), "300");
+
+        adapter.delete_row(1).await.unwrap();
+        assert!(probe.get_value("t1").await.unwrap().is_none());
+        assert_eq!(adapter.row_count().await.unwrap(), 1);
+
+        let shown = VantageTableAdapter::new(Vista::new("product", Box::new(backend)))
+            .await
+            .show_id();
+        assert_eq!(shown.column_info().await.unwrap()[0].name, "id");
+        assert_eq!(shown.fetch_row(0).await.unwrap()[0].as_string(), "b2");
+    }
+
+    /// Two fixed rows whose writes always fail.
+    struct ReadOnlyRows;
+
+    #[async_trait]
+    impl DataSet for ReadOnlyRows {
+        async fn row_count(&self) -> Result<usize> {
+            Ok(2)
+        }
+
+        async fn column_info(&self) -> Result<Vec<ColumnInfo>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rows(&self, start: usize, count: usize) -> Result<Vec<TableRow>> {
+            let mut rows = Vec::new();
+            for index in start..(start + count).min(2) {
+                rows.push(self.fetch_row(index).await?);
+            }
+            Ok(rows)
+        }
+
+        async fn fetch_row(&self, index: usize) -> Result<TableRow> {
+            Ok(vec![CellValue::String(format!("row {}", index))])
+        }
+
+        async fn update_cell(&self, _row: usize, _col: usize, _value: CellValue) -> Result<()> {
+            tokio::task::yield_now().await;
+            Err(TableStoreError::FetchError(
+                "backend is read-only".to_string(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn optimistic_update_reverts_on_failed_write() {
+        let store = TableStore::new(ReadOnlyRows);
+        store.prefetch_range(0, 2).await.unwrap();
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let write = store.update_cell_optimistic(1, 0, CellValue::String("edited".into()), |err| {
+            tx.send(err.to_string()).unwrap();
+        });
+
+        // The cache shows the edit before the backend has answered
+        assert_eq!(store.cell_value(1, 0).await.unwrap().as_string(), "edited");
+
+        write.await.unwrap();
+        assert_eq!(rx.await.unwrap(), "Data fetch failed: backend is read-only");
+        assert_eq!(store.cell_value(1, 0).await.unwrap().as_string(), "row 1");
+        assert_eq!(store.cell_value(0, 0).await.unwrap().as_string(), "row 0");
+    }
+
+    #[tokio::test]
+    async fn failed_optimistic_update_keeps_newer_value() {
+        let store = TableStore::new(ReadOnlyRows);
+        store.prefetch_range(0, 2).await.unwrap();
+
+        let write = store.update_cell_optimistic(1, 0, CellValue::String("edited".into()), |_| {});
+        // A refresh lands while the write is in flight
+        store
+            .cached_rows
+            .write()
+            .unwrap()
+            .insert(1, vec![CellValue::String("refreshed".into())]);
+
+        write.await.unwrap();
+        assert_eq!(
+            store.cell_value(1, 0).await.unwrap().as_string(),
+            "refreshed"
+        );
+    }
+
+    /// Rows whose row count fetches each take a permit from `gate`,
+    /// counting them and recording the ranges of row fetches.
+    struct GatedRows {
+        gate: Arc<tokio::sync::Semaphore>,
+        fetches: Arc<std::sync::atomic::AtomicUsize>,
+        ranges: Arc<Mutex<Vec<(usize, usize)>>>,
+    }
+
+    impl Default for GatedRows {
+        fn default() -> Self {
+            Self {
+                gate: Arc::new(tokio::sync::Semaphore::new(0)),
+                fetches: Arc::default(),
+                ranges: Arc::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DataSet for GatedRows {
+        async fn row_count(&self) -> Result<usize> {
+            self.fetches
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.gate.acquire().await.unwrap().forget();
+            Ok(3)
+        }
+
+        async fn column_info(&self) -> Result<Vec<ColumnInfo>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rows(&self, start: usize, count: usize) -> Result<Vec<TableRow>> {
+            self.ranges.lock().unwrap().push((start, count));
+            Ok((start..start + count)
+                .map(|index| vec![CellValue::Integer(index as i64)])
+                .collect())
+        }
+
+        async fn fetch_row(&self, index: usize) -> Result<TableRow> {
+            Ok(vec![CellValue::Integer(index as i64)])
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_during_fetch_queues_one_follow_up() {
+        let rows = GatedRows::default();
+        let (gate, fetches) = (rows.gate.clone(), rows.fetches.clone());
+        let store = TableStore::new(rows);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let (first, second) = (tx.clone(), tx.clone());
+        store.refresh_in_background(move || first.send("first").unwrap());
+        store.refresh_in_background(move || second.send("second").unwrap());
+        store.refresh_in_background(move || tx.send("third").unwrap());
+
+        // The later calls wait for a fetch of their own, which they share
+        gate.add_permits(1);
+        assert_eq!(rx.recv().await, Some("first"));
+        assert!(rx.try_recv().is_err());
+        gate.add_permits(1);
+        assert_eq!(rx.recv().await, Some("second"));
+        assert_eq!(rx.recv().await, Some("third"));
+        assert_eq!(rx.recv().await, None);
+        assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        assert_eq!(store.row_count().await.unwrap(), 3);
+        assert_eq!(store.cell_value(2, 0).await.unwrap().as_string(), "2");
+        assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn refresh_rereads_only_cached_ranges() {
+        let rows = GatedRows::default();
+        rows.gate.add_permits(1);
+        let ranges = rows.ranges.clone();
+        let store = TableStore::new(rows);
+        store.get_row(0).await.unwrap();
+        store.get_row(2).await.unwrap();
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        store.refresh_in_background(move || tx.send(()).unwrap());
+        rx.await.unwrap();
+        assert_eq!(*ranges.lock().unwrap(), [(0, 1), (2, 1)]);
+        assert_eq!(store.cell_value(2, 0).await.unwrap().as_string(), "2");
+    }
+
+    /// Rows whose first row count fetch panics.
+    #[derive(Default)]
+    struct PanickyRows {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl DataSet for PanickyRows {
+        async fn row_count(&self) -> Result<usize> {
+            if self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                panic!("backend exploded");
+            }
+            Ok(1)
+        }
+
+        async fn column_info(&self) -> Result<Vec<ColumnInfo>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rows(&self, start: usize, count: usize) -> Result<Vec<TableRow>> {
+            Ok(vec![vec![CellValue::Integer(start as i64)]; count])
+        }
+
+        async fn fetch_row(&self, _index: usize) -> Result<TableRow> {
+            Ok(vec![CellValue::Integer(1)])
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_recovers_after_panicked_task() {
+        let store = TableStore::new(PanickyRows::default());
+        store.refresh_in_background(|| {});
+        for _ in 0..100 {
+            if store.pending_refresh.0.lock().unwrap().is_none() {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert!(store.pending_refresh.0.lock().unwrap().is_none());
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        store.refresh_in_background(move || tx.send(()).unwrap());
+        rx.await.unwrap();
+        assert_eq!(store.row_count().await.unwrap(), 1);
+    }
+}
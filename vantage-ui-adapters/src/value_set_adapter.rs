@@ -0,0 +1,275 @@
+//! Bridge from any dataset [`ReadableValueSet`] to the UI [`DataSet`] trait.
+//!
+//! [`VantageTableAdapter`](crate::VantageTableAdapter) needs a `Vista`;
+//! [`DataSetValueAdapter`] only needs something that can list records, so an
+//! `ImTable`, a `Table` or a custom value set can feed the UI adapters
+//! directly. Like the Vista adapter it reads all rows up front and serves
+//! the UI from that cache.
+//!
+//! ```rust,ignore
+//! let users = ImTable::<EmptyEntity>::new(&data_source, "users");
+//! let store = TableStore::new(DataSetValueAdapter::new(users).await?);
+//! ```
+
+use async_trait::async_trait;
+use ciborium::Value as CborValue;
+use std::sync::RwLock;
+use vantage_dataset::prelude::ReadableValueSet;
+use vantage_types::Record;
+
+use crate::{json_to_cell, CellValue, ColumnInfo, DataSet, Result, TableRow, TableStoreError};
+
+/// Conversion from a storage value into a display cell.
+pub trait ToCellValue {
+    fn to_cell_value(&self) -> CellValue;
+}
+
+impl ToCellValue for serde_json::Value {
+    fn to_cell_value(&self) -> CellValue {
+        json_to_cell(self)
+    }
+}
+
+impl ToCellValue for CborValue {
+    // The presentation dialect renders tagged values (record ids,
+    // datetimes) as their display text instead of nulling them.
+    fn to_cell_value(&self) -> CellValue {
+        json_to_cell(&vantage_types::cbor_to_json(
+            &vantage_types::PresentationDialect,
+            self.clone(),
+        ))
+    }
+}
+
+/// Build a row with one cell per column; missing fields become `Null`.
+pub(crate) fn record_to_row<V: ToCellValue>(
+    column_names: &[String],
+    record: &Record<V>,
+) -> TableRow {
+    column_names
+        .iter()
+        .map(|name| match record.get(name) {
+            Some(value) => value.to_cell_value(),
+            None => CellValue::Null,
+        })
+        .collect()
+}
+
+/// Exposes a [`ReadableValueSet`] through the UI [`DataSet`] interface.
+///
+/// Columns are the record fields in the order they are first seen. Each
+/// column's `data_type` comes from its first non-null value.
+pub struct DataSetValueAdapter<S: ReadableValueSet> {
+    source: S,
+    cached_columns: Vec<ColumnInfo>,
+    cached_data: RwLock<Vec<(S::Id, TableRow)>>,
+}
+
+impl<S> DataSetValueAdapter<S>
+where
+    S: ReadableValueSet + Send + Sync,
+    S::Value: ToCellValue,
+{
+    /// Read every record of `source` and cache it as rows.
+    pub async fn new(source: S) -> Result<Self> {
+        let records = source
+            .list_values()
+            .await
+            .map_err(|e| TableStoreError::FetchError(e.to_string()))?;
+
+        let mut column_names: Vec<String> = Vec::new();
+        for record in records.values() {
+            for key in record.keys() {
+                if !column_names.contains(key) {
+                    column_names.push(key.clone());
+                }
+            }
+        }
+
+        let cached_data: Vec<(S::Id, TableRow)> = records
+            .iter()
+            .map(|(id, record)| (id.clone(), record_to_row(&column_names, record)))
+            .collect();
+
+        let cached_columns = column_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| ColumnInfo {
+                name: name.clone(),
+                data_type: cached_data
+                    .iter()
+                    .map(|(_, row)| &row[i])
+                    .find(|cell| !matches!(cell, CellValue::Null))
+                    .map(cell_type)
+                    .unwrap_or("String")
+                    .to_string(),
+                sortable: true,
+                editable: false,
+                formatter: None,
+            })
+            .collect();
+
+        Ok(Self {
+            source,
+            cached_columns,
+            cached_data: RwLock::new(cached_data),
+        })
+    }
+
+    /// The wrapped value set.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+fn cell_type(cell: &CellValue) -> &'static str {
+    match cell {
+        CellValue::Integer(_) => "Integer",
+        CellValue::Float(_) => "Float",
+        CellValue::Boolean(_) => "Boolean",
+        CellValue::String(_) | CellValue::Null => "String",
+    }
+}
+
+#[async_trait]
+impl<S> DataSet for DataSetValueAdapter<S>
+where
+    S: ReadableValueSet + Send + Sync,
+    S::Id: From<String> + PartialEq,
+    S::Value: ToCellValue,
+{
+    async fn row_count(&self) -> Result<usize> {
+        Ok(self.cached_data.read().unwrap().len())
+    }
+
+    async fn column_info(&self) -> Result<Vec<ColumnInfo>> {
+        Ok(self.cached_columns.clone())
+    }
+
+    async fn fetch_rows(&self, start: usize, count: usize) -> Result<Vec<TableRow>> {
+        let data = self.cached_data.read().unwrap();
+        if start >= data.len() {
+            return Ok(vec![]);
+        }
+        let end = (start + count).min(data.len());
+        Ok(data[start..end]
+            .iter()
+            .map(|(_, row)| row.clone())
+            .collect())
+    }
+
+    async fn fetch_row(&self, index: usize) -> Result<TableRow> {
+        self.cached_data
+            .read()
+            .unwrap()
+            .get(index)
+            .map(|(_, row)| row.clone())
+            .ok_or(TableStoreError::IndexError)
+    }
+
+    async fn sort_by(&self, column: usize, ascending: bool) -> Result<()> {
+        if column >= self.cached_columns.len() {
+            return Err(TableStoreError::IndexError);
+        }
+        let mut data = self.cached_data.write().unwrap();
+        data.sort_by(|(_, a), (_, b)| {
+            let ordering = a[column].sort_cmp(&b[column]);
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        Ok(())
+    }
+
+    async fn reload_row(&self, id: &str) -> Result<(usize, TableRow)> {
+        let id = S::Id::from(id.to_string());
+        let record = self
+            .source
+            .get_value(id.clone())
+            .await
+            .map_err(|e| TableStoreError::FetchError(e.to_string()))?
+            .ok_or_else(|| TableStoreError::FetchError("Record no longer exists".to_string()))?;
+        let column_names: Vec<String> =
+            self.cached_columns.iter().map(|c| c.name.clone()).collect();
+
+        let mut data = self.cached_data.write().unwrap();
+        let index = data
+            .iter()
+            .position(|(row_id, _)| *row_id == id)
+            .ok_or(TableStoreError::IndexError)?;
+        data[index].1 = record_to_row(&column_names, &record);
+        Ok((index, data[index].1.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TableStore;
+    use serde_json::json;
+    use vantage_dataset::im::{ImDataSource, ImTable};
+    use vantage_dataset::prelude::WritableValueSet;
+    use vantage_types::EmptyEntity;
+
+    async fn users(ds: &ImDataSource) -> ImTable<EmptyEntity> {
+        let table = ImTable::<EmptyEntity>::new(ds, "users");
+        for (id, name, age) in [("a", "Carol", 41), ("b", "Alice", 30), ("c", "Bob", 25)] {
+            table
+                .replace_value(
+                    id.to_string(),
+                    &Record::from(json!({"name": name, "age": age})),
+                )
+                .await
+                .unwrap();
+        }
+        table
+    }
+
+    #[tokio::test]
+    async fn exposes_value_set_as_dataset() {
+        let adapter = DataSetValueAdapter::new(users(&ImDataSource::new()).await)
+            .await
+            .unwrap();
+
+        assert_eq!(adapter.row_count().await.unwrap(), 3);
+        let columns = adapter.column_info().await.unwrap();
+        let described: Vec<(&str, &str)> = columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.data_type.as_str()))
+            .collect();
+        assert_eq!(described, vec![("age", "Integer"), ("name", "String")]);
+
+        let row = adapter.fetch_row(0).await.unwrap();
+        assert_eq!(row[0].as_string(), "41");
+        assert_eq!(row[1].as_string(), "Carol");
+        assert_eq!(adapter.fetch_rows(2, 10).await.unwrap().len(), 1);
+        assert!(adapter.fetch_row(3).await.is_err());
+
+        adapter.sort_by(0, true).await.unwrap();
+        let names: Vec<String> = adapter
+            .fetch_rows(0, 3)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row[1].as_string())
+            .collect();
+        assert_eq!(names, vec!["Bob", "Alice", "Carol"]);
+    }
+
+    #[tokio::test]
+    async fn feeds_table_store_and_reloads_rows() {
+        let ds = ImDataSource::new();
+        let store = TableStore::new(DataSetValueAdapter::new(users(&ds).await).await.unwrap());
+        assert_eq!(store.cell_value(1, 1).await.unwrap().as_string(), "Alice");
+
+        // Mutate through a second handle on the same data source
+        ImTable::<EmptyEntity>::new(&ds, "users")
+            .patch_value("b".to_string(), &Record::from(json!({"age": 31})))
+            .await
+            .unwrap();
+        assert_eq!(store.reload_row("b").await.unwrap(), 1);
+        assert_eq!(store.cell_value(1, 0).await.unwrap().as_string(), "31");
+    }
+}